@@ -15,6 +15,8 @@ import {
   pdaLinkNode,
   pdaSeedValueNode,
   accountValueNode,
+  conditionalValueNode,
+  updateInstructionsVisitor,
 } from 'kinobi';
 
 // shim for __dirname
@@ -46,10 +48,38 @@ kinobi.update(
       account: 'escrowAtaPda',
       defaultValue: ataPdaDefault('tokenMint', 'authorityPda'),
     },
+    {
+      account: 'sourceEscrowAtaPda',
+      defaultValue: ataPdaDefault('tokenMint', 'sourceAuthorityPda'),
+    },
+    {
+      account: 'targetEscrowAtaPda',
+      defaultValue: ataPdaDefault('tokenMint', 'targetAuthorityPda'),
+    },
+    {
+      account: 'legacyEscrowAtaPda',
+      defaultValue: ataPdaDefault('tokenMint', 'legacyAuthorityPda'),
+    },
     {
       account: 'userAta',
       defaultValue: ataPdaDefault('tokenMint', 'user'),
     },
+    // the redeemed tokens go to the recipient ata when a recipient is set
+    {
+      account: 'userAta',
+      instruction: 'fusionFromV1',
+      defaultValue: conditionalValueNode({
+        condition: accountValueNode('recipient'),
+        ifFalse: ataPdaDefault('tokenMint', 'user'),
+      }),
+    },
+    {
+      account: 'recipientAta',
+      defaultValue: conditionalValueNode({
+        condition: accountValueNode('recipient'),
+        ifTrue: ataPdaDefault('tokenMint', 'recipient'),
+      }),
+    },
     {
       account: 'authorityAta',
       defaultValue: ataPdaDefault('tokenMint', 'authority'),
@@ -57,6 +87,49 @@ kinobi.update(
   ])
 );
 
+// The metadata pool and the vaulted asset record only exist in some modes, keep them unset by default
+// New assets can be signers (new keypair) or existing addresses (recycled or vaulted assets)
+kinobi.update(
+  updateInstructionsVisitor({
+    evolveV1: {
+      accounts: {
+        asset: { isSigner: 'either' },
+        metadataPool: { defaultValue: null },
+        sourceMetadataPool: { defaultValue: null },
+        vaultedAssetRecord: { defaultValue: null },
+      },
+    },
+    fusionFromV1: {
+      accounts: {
+        metadataPool: { defaultValue: null },
+      },
+    },
+    fusionIntoV1: {
+      accounts: {
+        asset: { isSigner: 'either' },
+        metadataPool: { defaultValue: null },
+        vaultedAssetRecord: { defaultValue: null },
+      },
+    },
+    fusionIntoCompressedV1: {
+      accounts: {
+        metadataPool: { defaultValue: null },
+      },
+    },
+    mergeAssetsV1: {
+      accounts: {
+        metadataPool: { defaultValue: null },
+      },
+    },
+    splitAssetV1: {
+      accounts: {
+        newAsset: { isSigner: 'either' },
+        metadataPool: { defaultValue: null },
+      },
+    },
+  })
+);
+
 // remove BaseAssetV1 and BaseCollectionV1 from the IDL
 kinobi.update(
   updateAccountsVisitor({
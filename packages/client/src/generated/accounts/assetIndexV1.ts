@@ -0,0 +1,172 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type AssetIndexV1 = Account<AssetIndexV1AccountData>;
+
+export type AssetIndexV1AccountData = {
+  discriminator: Uint8Array;
+  /** Asset minted with the mint index */
+  asset: PublicKey;
+  /** Slot of the mint */
+  slot: bigint;
+  /** User who minted the asset */
+  minter: PublicKey;
+  /** Metadata index assigned to the asset */
+  index: bigint;
+};
+
+export type AssetIndexV1AccountDataArgs = {
+  /** Asset minted with the mint index */
+  asset: PublicKey;
+  /** Slot of the mint */
+  slot: number | bigint;
+  /** User who minted the asset */
+  minter: PublicKey;
+  /** Metadata index assigned to the asset */
+  index: number | bigint;
+};
+
+export function getAssetIndexV1AccountDataSerializer(): Serializer<
+  AssetIndexV1AccountDataArgs,
+  AssetIndexV1AccountData
+> {
+  return mapSerializer<
+    AssetIndexV1AccountDataArgs,
+    any,
+    AssetIndexV1AccountData
+  >(
+    struct<AssetIndexV1AccountData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['asset', publicKeySerializer()],
+        ['slot', u64()],
+        ['minter', publicKeySerializer()],
+        ['index', u64()],
+      ],
+      { description: 'AssetIndexV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([80, 34, 100, 252, 142, 129, 90, 84]),
+    })
+  ) as Serializer<AssetIndexV1AccountDataArgs, AssetIndexV1AccountData>;
+}
+
+export function deserializeAssetIndexV1(rawAccount: RpcAccount): AssetIndexV1 {
+  return deserializeAccount(rawAccount, getAssetIndexV1AccountDataSerializer());
+}
+
+export async function fetchAssetIndexV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AssetIndexV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AssetIndexV1');
+  return deserializeAssetIndexV1(maybeAccount);
+}
+
+export async function safeFetchAssetIndexV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AssetIndexV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeAssetIndexV1(maybeAccount) : null;
+}
+
+export async function fetchAllAssetIndexV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AssetIndexV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AssetIndexV1');
+    return deserializeAssetIndexV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAssetIndexV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AssetIndexV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeAssetIndexV1(maybeAccount as RpcAccount));
+}
+
+export function getAssetIndexV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Uint8Array;
+      asset: PublicKey;
+      slot: number | bigint;
+      minter: PublicKey;
+      index: number | bigint;
+    }>({
+      discriminator: [0, bytes({ size: 8 })],
+      asset: [8, publicKeySerializer()],
+      slot: [40, u64()],
+      minter: [48, publicKeySerializer()],
+      index: [80, u64()],
+    })
+    .deserializeUsing<AssetIndexV1>((account) =>
+      deserializeAssetIndexV1(account)
+    )
+    .whereField(
+      'discriminator',
+      new Uint8Array([80, 34, 100, 252, 142, 129, 90, 84])
+    );
+}
+
+export function getAssetIndexV1Size(): number {
+  return 88;
+}
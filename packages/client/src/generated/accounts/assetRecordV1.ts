@@ -0,0 +1,186 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  option,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type AssetRecordV1 = Account<AssetRecordV1AccountData>;
+
+export type AssetRecordV1AccountData = {
+  discriminator: Uint8Array;
+  /** Tier of the asset, none for variable backed and plain assets */
+  tier: Option<number>;
+  /**
+   * How many spl tokens can be recovered from the asset,
+   * zero for plain assets backed by the escrow amount of the fusion
+   */
+
+  amount: bigint;
+  /** Metadata index of the asset */
+  index: bigint;
+  /** Mint index of the asset, key of its asset index account */
+  mintIndex: bigint;
+};
+
+export type AssetRecordV1AccountDataArgs = {
+  /** Tier of the asset, none for variable backed and plain assets */
+  tier: OptionOrNullable<number>;
+  /**
+   * How many spl tokens can be recovered from the asset,
+   * zero for plain assets backed by the escrow amount of the fusion
+   */
+
+  amount: number | bigint;
+  /** Metadata index of the asset */
+  index: number | bigint;
+  /** Mint index of the asset, key of its asset index account */
+  mintIndex: number | bigint;
+};
+
+export function getAssetRecordV1AccountDataSerializer(): Serializer<
+  AssetRecordV1AccountDataArgs,
+  AssetRecordV1AccountData
+> {
+  return mapSerializer<
+    AssetRecordV1AccountDataArgs,
+    any,
+    AssetRecordV1AccountData
+  >(
+    struct<AssetRecordV1AccountData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['tier', option(u8())],
+        ['amount', u64()],
+        ['index', u64()],
+        ['mintIndex', u64()],
+      ],
+      { description: 'AssetRecordV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([116, 88, 52, 130, 26, 215, 8, 212]),
+    })
+  ) as Serializer<AssetRecordV1AccountDataArgs, AssetRecordV1AccountData>;
+}
+
+export function deserializeAssetRecordV1(
+  rawAccount: RpcAccount
+): AssetRecordV1 {
+  return deserializeAccount(
+    rawAccount,
+    getAssetRecordV1AccountDataSerializer()
+  );
+}
+
+export async function fetchAssetRecordV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AssetRecordV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AssetRecordV1');
+  return deserializeAssetRecordV1(maybeAccount);
+}
+
+export async function safeFetchAssetRecordV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AssetRecordV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeAssetRecordV1(maybeAccount) : null;
+}
+
+export async function fetchAllAssetRecordV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AssetRecordV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AssetRecordV1');
+    return deserializeAssetRecordV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAssetRecordV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AssetRecordV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeAssetRecordV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getAssetRecordV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Uint8Array;
+      tier: OptionOrNullable<number>;
+      amount: number | bigint;
+      index: number | bigint;
+      mintIndex: number | bigint;
+    }>({
+      discriminator: [0, bytes({ size: 8 })],
+      tier: [8, option(u8())],
+      amount: [null, u64()],
+      index: [null, u64()],
+      mintIndex: [null, u64()],
+    })
+    .deserializeUsing<AssetRecordV1>((account) =>
+      deserializeAssetRecordV1(account)
+    )
+    .whereField(
+      'discriminator',
+      new Uint8Array([116, 88, 52, 130, 26, 215, 8, 212])
+    );
+}
@@ -9,6 +9,8 @@
 import {
   Account,
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   RpcAccount,
@@ -21,19 +23,35 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  array,
   bool,
   bytes,
   mapSerializer,
+  option,
   publicKey as publicKeySerializer,
   struct,
+  u32,
+  u64,
 } from '@metaplex-foundation/umi/serializers';
 import {
   AssetDataV1,
   AssetDataV1Args,
+  BackingDataV1,
+  BackingDataV1Args,
+  EvolutionDataV1,
+  EvolutionDataV1Args,
   FeeDataV1,
   FeeDataV1Args,
+  IngredientV1,
+  IngredientV1Args,
+  TierV1,
+  TierV1Args,
   getAssetDataV1Serializer,
+  getBackingDataV1Serializer,
+  getEvolutionDataV1Serializer,
   getFeeDataV1Serializer,
+  getIngredientV1Serializer,
+  getTierV1Serializer,
 } from '../types';
 
 export type FusionDataV1 = Account<FusionDataV1AccountData>;
@@ -52,6 +70,24 @@ export type FusionDataV1AccountData = {
   assetData: AssetDataV1;
   /** Token specific data */
   feeData: FeeDataV1;
+  /** Bubblegum merkle tree of compressed assets */
+  merkleTree: Option<PublicKey>;
+  /** Number of compressed assets currently in the merkle tree */
+  compressedSupply: number;
+  /** Number of assets locked in the vault */
+  vaultSize: number;
+  /** Optional link to a higher tier fusion */
+  evolution: Option<EvolutionDataV1>;
+  /** Additional tokens of the recipe, each with its own escrow */
+  ingredients: Array<IngredientV1>;
+  /** Optional denomination tiers, each with its own fees, supply and templates */
+  tiers: Array<TierV1>;
+  /** Optional variable backing, replaces the fixed escrow amount */
+  backing: Option<BackingDataV1>;
+  /** Number of assets minted by the first version of the program still waiting for a record */
+  legacySupply: number;
+  /** Next index of the fusion at the migration, legacy assets have a lower metadata index */
+  legacyNextIndex: bigint;
 };
 
 export type FusionDataV1AccountDataArgs = {
@@ -67,6 +103,24 @@ export type FusionDataV1AccountDataArgs = {
   assetData: AssetDataV1Args;
   /** Token specific data */
   feeData: FeeDataV1Args;
+  /** Bubblegum merkle tree of compressed assets */
+  merkleTree: OptionOrNullable<PublicKey>;
+  /** Number of compressed assets currently in the merkle tree */
+  compressedSupply: number;
+  /** Number of assets locked in the vault */
+  vaultSize: number;
+  /** Optional link to a higher tier fusion */
+  evolution: OptionOrNullable<EvolutionDataV1Args>;
+  /** Additional tokens of the recipe, each with its own escrow */
+  ingredients: Array<IngredientV1Args>;
+  /** Optional denomination tiers, each with its own fees, supply and templates */
+  tiers: Array<TierV1Args>;
+  /** Optional variable backing, replaces the fixed escrow amount */
+  backing: OptionOrNullable<BackingDataV1Args>;
+  /** Number of assets minted by the first version of the program still waiting for a record */
+  legacySupply: number;
+  /** Next index of the fusion at the migration, legacy assets have a lower metadata index */
+  legacyNextIndex: number | bigint;
 };
 
 export function getFusionDataV1AccountDataSerializer(): Serializer<
@@ -87,6 +141,15 @@ export function getFusionDataV1AccountDataSerializer(): Serializer<
         ['paused', bool()],
         ['assetData', getAssetDataV1Serializer()],
         ['feeData', getFeeDataV1Serializer()],
+        ['merkleTree', option(publicKeySerializer())],
+        ['compressedSupply', u32()],
+        ['vaultSize', u32()],
+        ['evolution', option(getEvolutionDataV1Serializer())],
+        ['ingredients', array(getIngredientV1Serializer())],
+        ['tiers', array(getTierV1Serializer())],
+        ['backing', option(getBackingDataV1Serializer())],
+        ['legacySupply', u32()],
+        ['legacyNextIndex', u64()],
       ],
       { description: 'FusionDataV1AccountData' }
     ),
@@ -171,6 +234,15 @@ export function getFusionDataV1GpaBuilder(
       paused: boolean;
       assetData: AssetDataV1Args;
       feeData: FeeDataV1Args;
+      merkleTree: OptionOrNullable<PublicKey>;
+      compressedSupply: number;
+      vaultSize: number;
+      evolution: OptionOrNullable<EvolutionDataV1Args>;
+      ingredients: Array<IngredientV1Args>;
+      tiers: Array<TierV1Args>;
+      backing: OptionOrNullable<BackingDataV1Args>;
+      legacySupply: number;
+      legacyNextIndex: number | bigint;
     }>({
       discriminator: [0, bytes({ size: 8 })],
       authority: [8, publicKeySerializer()],
@@ -179,6 +251,15 @@ export function getFusionDataV1GpaBuilder(
       paused: [104, bool()],
       assetData: [105, getAssetDataV1Serializer()],
       feeData: [null, getFeeDataV1Serializer()],
+      merkleTree: [null, option(publicKeySerializer())],
+      compressedSupply: [null, u32()],
+      vaultSize: [null, u32()],
+      evolution: [null, option(getEvolutionDataV1Serializer())],
+      ingredients: [null, array(getIngredientV1Serializer())],
+      tiers: [null, array(getTierV1Serializer())],
+      backing: [null, option(getBackingDataV1Serializer())],
+      legacySupply: [null, u32()],
+      legacyNextIndex: [null, u64()],
     })
     .deserializeUsing<FusionDataV1>((account) =>
       deserializeFusionDataV1(account)
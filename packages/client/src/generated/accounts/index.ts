@@ -6,4 +6,7 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './assetIndexV1';
+export * from './assetRecordV1';
 export * from './fusionDataV1';
+export * from './metadataPoolV1';
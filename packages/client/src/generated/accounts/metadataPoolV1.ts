@@ -0,0 +1,182 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type MetadataPoolV1 = Account<MetadataPoolV1AccountData>;
+
+export type MetadataPoolV1AccountData = {
+  discriminator: Uint8Array;
+  /** First metadata index of the pool */
+  startIndex: bigint;
+  /** Number of metadata indexes in the pool */
+  size: number;
+  /** Number of metadata indexes already assigned */
+  used: number;
+  /** Number of metadata indexes assigned since the pool was initialized, released ones included */
+  assigned: bigint;
+  /** Bitmap of assigned metadata indexes, bits past `size` are always set */
+  bitmap: Uint8Array;
+};
+
+export type MetadataPoolV1AccountDataArgs = {
+  /** First metadata index of the pool */
+  startIndex: number | bigint;
+  /** Number of metadata indexes in the pool */
+  size: number;
+  /** Number of metadata indexes already assigned */
+  used: number;
+  /** Number of metadata indexes assigned since the pool was initialized, released ones included */
+  assigned: number | bigint;
+  /** Bitmap of assigned metadata indexes, bits past `size` are always set */
+  bitmap: Uint8Array;
+};
+
+export function getMetadataPoolV1AccountDataSerializer(): Serializer<
+  MetadataPoolV1AccountDataArgs,
+  MetadataPoolV1AccountData
+> {
+  return mapSerializer<
+    MetadataPoolV1AccountDataArgs,
+    any,
+    MetadataPoolV1AccountData
+  >(
+    struct<MetadataPoolV1AccountData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['startIndex', u64()],
+        ['size', u32()],
+        ['used', u32()],
+        ['assigned', u64()],
+        ['bitmap', bytes({ size: u32() })],
+      ],
+      { description: 'MetadataPoolV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([220, 83, 160, 188, 70, 175, 248, 129]),
+    })
+  ) as Serializer<MetadataPoolV1AccountDataArgs, MetadataPoolV1AccountData>;
+}
+
+export function deserializeMetadataPoolV1(
+  rawAccount: RpcAccount
+): MetadataPoolV1 {
+  return deserializeAccount(
+    rawAccount,
+    getMetadataPoolV1AccountDataSerializer()
+  );
+}
+
+export async function fetchMetadataPoolV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MetadataPoolV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'MetadataPoolV1');
+  return deserializeMetadataPoolV1(maybeAccount);
+}
+
+export async function safeFetchMetadataPoolV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MetadataPoolV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeMetadataPoolV1(maybeAccount) : null;
+}
+
+export async function fetchAllMetadataPoolV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MetadataPoolV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'MetadataPoolV1');
+    return deserializeMetadataPoolV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllMetadataPoolV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MetadataPoolV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeMetadataPoolV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getMetadataPoolV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Uint8Array;
+      startIndex: number | bigint;
+      size: number;
+      used: number;
+      assigned: number | bigint;
+      bitmap: Uint8Array;
+    }>({
+      discriminator: [0, bytes({ size: 8 })],
+      startIndex: [8, u64()],
+      size: [16, u32()],
+      used: [20, u32()],
+      assigned: [24, u64()],
+      bitmap: [32, bytes({ size: u32() })],
+    })
+    .deserializeUsing<MetadataPoolV1>((account) =>
+      deserializeMetadataPoolV1(account)
+    )
+    .whereField(
+      'discriminator',
+      new Uint8Array([220, 83, 160, 188, 70, 175, 248, 129])
+    );
+}
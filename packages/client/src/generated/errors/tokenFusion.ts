@@ -421,6 +421,415 @@ export class InvalidProtocolFeeWalletError extends ProgramError {
 codeToErrorMap.set(0x178d, InvalidProtocolFeeWalletError);
 nameToErrorMap.set('InvalidProtocolFeeWallet', InvalidProtocolFeeWalletError);
 
+/** InvalidAssetAddress: Invalid asset address */
+export class InvalidAssetAddressError extends ProgramError {
+  override readonly name: string = 'InvalidAssetAddress';
+
+  readonly code: number = 0x178e; // 6030
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid asset address', program, cause);
+  }
+}
+codeToErrorMap.set(0x178e, InvalidAssetAddressError);
+nameToErrorMap.set('InvalidAssetAddress', InvalidAssetAddressError);
+
+/** AssetMustBeSigner: Asset account must be a signer */
+export class AssetMustBeSignerError extends ProgramError {
+  override readonly name: string = 'AssetMustBeSigner';
+
+  readonly code: number = 0x178f; // 6031
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset account must be a signer', program, cause);
+  }
+}
+codeToErrorMap.set(0x178f, AssetMustBeSignerError);
+nameToErrorMap.set('AssetMustBeSigner', AssetMustBeSignerError);
+
+/** InvalidRoyalties: Invalid royalties */
+export class InvalidRoyaltiesError extends ProgramError {
+  override readonly name: string = 'InvalidRoyalties';
+
+  readonly code: number = 0x1790; // 6032
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid royalties', program, cause);
+  }
+}
+codeToErrorMap.set(0x1790, InvalidRoyaltiesError);
+nameToErrorMap.set('InvalidRoyalties', InvalidRoyaltiesError);
+
+/** MissingMetadataPool: Missing metadata pool account */
+export class MissingMetadataPoolError extends ProgramError {
+  override readonly name: string = 'MissingMetadataPool';
+
+  readonly code: number = 0x1791; // 6033
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing metadata pool account', program, cause);
+  }
+}
+codeToErrorMap.set(0x1791, MissingMetadataPoolError);
+nameToErrorMap.set('MissingMetadataPool', MissingMetadataPoolError);
+
+/** MissingSlotHashesSysvar: Missing slot hashes sysvar account */
+export class MissingSlotHashesSysvarError extends ProgramError {
+  override readonly name: string = 'MissingSlotHashesSysvar';
+
+  readonly code: number = 0x1792; // 6034
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing slot hashes sysvar account', program, cause);
+  }
+}
+codeToErrorMap.set(0x1792, MissingSlotHashesSysvarError);
+nameToErrorMap.set('MissingSlotHashesSysvar', MissingSlotHashesSysvarError);
+
+/** InvalidMetadataPoolSize: Invalid metadata pool size */
+export class InvalidMetadataPoolSizeError extends ProgramError {
+  override readonly name: string = 'InvalidMetadataPoolSize';
+
+  readonly code: number = 0x1793; // 6035
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid metadata pool size', program, cause);
+  }
+}
+codeToErrorMap.set(0x1793, InvalidMetadataPoolSizeError);
+nameToErrorMap.set('InvalidMetadataPoolSize', InvalidMetadataPoolSizeError);
+
+/** InvalidRemainingAccounts: Remaining accounts do not match the instruction arguments */
+export class InvalidRemainingAccountsError extends ProgramError {
+  override readonly name: string = 'InvalidRemainingAccounts';
+
+  readonly code: number = 0x1794; // 6036
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Remaining accounts do not match the instruction arguments',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1794, InvalidRemainingAccountsError);
+nameToErrorMap.set('InvalidRemainingAccounts', InvalidRemainingAccountsError);
+
+/** InvalidTemplate: Invalid asset name or uri template */
+export class InvalidTemplateError extends ProgramError {
+  override readonly name: string = 'InvalidTemplate';
+
+  readonly code: number = 0x1795; // 6037
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid asset name or uri template', program, cause);
+  }
+}
+codeToErrorMap.set(0x1795, InvalidTemplateError);
+nameToErrorMap.set('InvalidTemplate', InvalidTemplateError);
+
+/** InvalidHashSalt: Hash salt does not match the commitment */
+export class InvalidHashSaltError extends ProgramError {
+  override readonly name: string = 'InvalidHashSalt';
+
+  readonly code: number = 0x1796; // 6038
+
+  constructor(program: Program, cause?: Error) {
+    super('Hash salt does not match the commitment', program, cause);
+  }
+}
+codeToErrorMap.set(0x1796, InvalidHashSaltError);
+nameToErrorMap.set('InvalidHashSalt', InvalidHashSaltError);
+
+/** HashSaltNotRevealed: Hash salt is not revealed */
+export class HashSaltNotRevealedError extends ProgramError {
+  override readonly name: string = 'HashSaltNotRevealed';
+
+  readonly code: number = 0x1797; // 6039
+
+  constructor(program: Program, cause?: Error) {
+    super('Hash salt is not revealed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1797, HashSaltNotRevealedError);
+nameToErrorMap.set('HashSaltNotRevealed', HashSaltNotRevealedError);
+
+/** InvalidOutputMode: Instruction does not match the asset output mode */
+export class InvalidOutputModeError extends ProgramError {
+  override readonly name: string = 'InvalidOutputMode';
+
+  readonly code: number = 0x1798; // 6040
+
+  constructor(program: Program, cause?: Error) {
+    super('Instruction does not match the asset output mode', program, cause);
+  }
+}
+codeToErrorMap.set(0x1798, InvalidOutputModeError);
+nameToErrorMap.set('InvalidOutputMode', InvalidOutputModeError);
+
+/** InvalidMerkleTree: Merkle tree does not match the fusion merkle tree */
+export class InvalidMerkleTreeError extends ProgramError {
+  override readonly name: string = 'InvalidMerkleTree';
+
+  readonly code: number = 0x1799; // 6041
+
+  constructor(program: Program, cause?: Error) {
+    super('Merkle tree does not match the fusion merkle tree', program, cause);
+  }
+}
+codeToErrorMap.set(0x1799, InvalidMerkleTreeError);
+nameToErrorMap.set('InvalidMerkleTree', InvalidMerkleTreeError);
+
+/** AssetNotInVault: Asset is not in the vault */
+export class AssetNotInVaultError extends ProgramError {
+  override readonly name: string = 'AssetNotInVault';
+
+  readonly code: number = 0x179a; // 6042
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset is not in the vault', program, cause);
+  }
+}
+codeToErrorMap.set(0x179a, AssetNotInVaultError);
+nameToErrorMap.set('AssetNotInVault', AssetNotInVaultError);
+
+/** InvalidEvolution: Invalid evolution link */
+export class InvalidEvolutionError extends ProgramError {
+  override readonly name: string = 'InvalidEvolution';
+
+  readonly code: number = 0x179b; // 6043
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid evolution link', program, cause);
+  }
+}
+codeToErrorMap.set(0x179b, InvalidEvolutionError);
+nameToErrorMap.set('InvalidEvolution', InvalidEvolutionError);
+
+/** InsufficientBacking: Escrow amount does not cover the backing of the new asset */
+export class InsufficientBackingError extends ProgramError {
+  override readonly name: string = 'InsufficientBacking';
+
+  readonly code: number = 0x179c; // 6044
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Escrow amount does not cover the backing of the new asset',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x179c, InsufficientBackingError);
+nameToErrorMap.set('InsufficientBacking', InsufficientBackingError);
+
+/** InvalidIngredients: Invalid ingredients */
+export class InvalidIngredientsError extends ProgramError {
+  override readonly name: string = 'InvalidIngredients';
+
+  readonly code: number = 0x179d; // 6045
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid ingredients', program, cause);
+  }
+}
+codeToErrorMap.set(0x179d, InvalidIngredientsError);
+nameToErrorMap.set('InvalidIngredients', InvalidIngredientsError);
+
+/** InvalidTier: Invalid tier */
+export class InvalidTierError extends ProgramError {
+  override readonly name: string = 'InvalidTier';
+
+  readonly code: number = 0x179e; // 6046
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid tier', program, cause);
+  }
+}
+codeToErrorMap.set(0x179e, InvalidTierError);
+nameToErrorMap.set('InvalidTier', InvalidTierError);
+
+/** InvalidAssetRecord: Asset record does not match the asset */
+export class InvalidAssetRecordError extends ProgramError {
+  override readonly name: string = 'InvalidAssetRecord';
+
+  readonly code: number = 0x179f; // 6047
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset record does not match the asset', program, cause);
+  }
+}
+codeToErrorMap.set(0x179f, InvalidAssetRecordError);
+nameToErrorMap.set('InvalidAssetRecord', InvalidAssetRecordError);
+
+/** InvalidBacking: Invalid variable backing */
+export class InvalidBackingError extends ProgramError {
+  override readonly name: string = 'InvalidBacking';
+
+  readonly code: number = 0x17a0; // 6048
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid variable backing', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a0, InvalidBackingError);
+nameToErrorMap.set('InvalidBacking', InvalidBackingError);
+
+/** InvalidRecipient: Recipient and recipient ata must be set together */
+export class InvalidRecipientError extends ProgramError {
+  override readonly name: string = 'InvalidRecipient';
+
+  readonly code: number = 0x17a1; // 6049
+
+  constructor(program: Program, cause?: Error) {
+    super('Recipient and recipient ata must be set together', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a1, InvalidRecipientError);
+nameToErrorMap.set('InvalidRecipient', InvalidRecipientError);
+
+/** InvalidTokenAuthority: User is neither the owner nor the delegate of the token account */
+export class InvalidTokenAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidTokenAuthority';
+
+  readonly code: number = 0x17a2; // 6050
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'User is neither the owner nor the delegate of the token account',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a2, InvalidTokenAuthorityError);
+nameToErrorMap.set('InvalidTokenAuthority', InvalidTokenAuthorityError);
+
+/** InvalidAssetAuthority: User is neither the owner nor a delegate of the asset */
+export class InvalidAssetAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidAssetAuthority';
+
+  readonly code: number = 0x17a3; // 6051
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'User is neither the owner nor a delegate of the asset',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a3, InvalidAssetAuthorityError);
+nameToErrorMap.set('InvalidAssetAuthority', InvalidAssetAuthorityError);
+
+/** InvalidAssetIndex: Asset index does not match the mint index */
+export class InvalidAssetIndexError extends ProgramError {
+  override readonly name: string = 'InvalidAssetIndex';
+
+  readonly code: number = 0x17a4; // 6052
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset index does not match the mint index', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a4, InvalidAssetIndexError);
+nameToErrorMap.set('InvalidAssetIndex', InvalidAssetIndexError);
+
+/** InvalidMetadataMode: Recycled metadata mode requires core assets redeemed by burning */
+export class InvalidMetadataModeError extends ProgramError {
+  override readonly name: string = 'InvalidMetadataMode';
+
+  readonly code: number = 0x17a5; // 6053
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Recycled metadata mode requires core assets redeemed by burning',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a5, InvalidMetadataModeError);
+nameToErrorMap.set('InvalidMetadataMode', InvalidMetadataModeError);
+
+/** MissingCreators: Royalties require at least one creator */
+export class MissingCreatorsError extends ProgramError {
+  override readonly name: string = 'MissingCreators';
+
+  readonly code: number = 0x17a6; // 6054
+
+  constructor(program: Program, cause?: Error) {
+    super('Royalties require at least one creator', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a6, MissingCreatorsError);
+nameToErrorMap.set('MissingCreators', MissingCreatorsError);
+
+/** MaxSupplyLocked: Max supply can not change once the metadata pool is initialized */
+export class MaxSupplyLockedError extends ProgramError {
+  override readonly name: string = 'MaxSupplyLocked';
+
+  readonly code: number = 0x17a7; // 6055
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Max supply can not change once the metadata pool is initialized',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a7, MaxSupplyLockedError);
+nameToErrorMap.set('MaxSupplyLocked', MaxSupplyLockedError);
+
+/** InvalidLegacyFusion: Legacy fusion data does not match the accounts */
+export class InvalidLegacyFusionError extends ProgramError {
+  override readonly name: string = 'InvalidLegacyFusion';
+
+  readonly code: number = 0x17a8; // 6056
+
+  constructor(program: Program, cause?: Error) {
+    super('Legacy fusion data does not match the accounts', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a8, InvalidLegacyFusionError);
+nameToErrorMap.set('InvalidLegacyFusion', InvalidLegacyFusionError);
+
+/** MetadataModeLocked: Metadata mode can not change once assets were minted with the metadata pool */
+export class MetadataModeLockedError extends ProgramError {
+  override readonly name: string = 'MetadataModeLocked';
+
+  readonly code: number = 0x17a9; // 6057
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Metadata mode can not change once assets were minted with the metadata pool',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a9, MetadataModeLockedError);
+nameToErrorMap.set('MetadataModeLocked', MetadataModeLockedError);
+
+/** AssetModeLocked: Asset modes can not change while assets are vaulted or compressed */
+export class AssetModeLockedError extends ProgramError {
+  override readonly name: string = 'AssetModeLocked';
+
+  readonly code: number = 0x17aa; // 6058
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Asset modes can not change while assets are vaulted or compressed',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17aa, AssetModeLockedError);
+nameToErrorMap.set('AssetModeLocked', AssetModeLockedError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
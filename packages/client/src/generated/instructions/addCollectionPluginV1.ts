@@ -0,0 +1,208 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  CollectionPluginV1,
+  CollectionPluginV1Args,
+  getCollectionPluginV1Serializer,
+} from '../types';
+
+// Accounts.
+export type AddCollectionPluginV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /**
+   * Authority PDA, update delegate of the collection.
+   *
+   */
+
+  authorityPda?: PublicKey | Pda;
+  /** Authority and payer of the transaction. */
+  authority?: Signer;
+  /**
+   * Collection account
+   *
+   */
+
+  collection: PublicKey | Pda;
+  /** MPL Core program. */
+  coreProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+};
+
+// Data.
+export type AddCollectionPluginV1InstructionData = {
+  discriminator: Uint8Array;
+  plugin: CollectionPluginV1;
+};
+
+export type AddCollectionPluginV1InstructionDataArgs = {
+  plugin: CollectionPluginV1Args;
+};
+
+export function getAddCollectionPluginV1InstructionDataSerializer(): Serializer<
+  AddCollectionPluginV1InstructionDataArgs,
+  AddCollectionPluginV1InstructionData
+> {
+  return mapSerializer<
+    AddCollectionPluginV1InstructionDataArgs,
+    any,
+    AddCollectionPluginV1InstructionData
+  >(
+    struct<AddCollectionPluginV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['plugin', getCollectionPluginV1Serializer()],
+      ],
+      { description: 'AddCollectionPluginV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([23, 142, 170, 213, 93, 218, 179, 2]),
+    })
+  ) as Serializer<
+    AddCollectionPluginV1InstructionDataArgs,
+    AddCollectionPluginV1InstructionData
+  >;
+}
+
+// Args.
+export type AddCollectionPluginV1InstructionArgs =
+  AddCollectionPluginV1InstructionDataArgs;
+
+// Instruction.
+export function addCollectionPluginV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: AddCollectionPluginV1InstructionAccounts &
+    AddCollectionPluginV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    coreProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.coreProgram ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddCollectionPluginV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.coreProgram.value) {
+    resolvedAccounts.coreProgram.value = context.programs.getPublicKey(
+      'coreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.coreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddCollectionPluginV1InstructionDataSerializer().serialize(
+    resolvedArgs as AddCollectionPluginV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -19,6 +19,7 @@ import {
   Serializer,
   bytes,
   mapSerializer,
+  publicKey as publicKeySerializer,
   struct,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -31,7 +32,7 @@ import {
 // Accounts.
 export type DestroyV1InstructionAccounts = {
   /** Fusion data account. */
-  fusionData?: PublicKey | Pda;
+  fusionData: PublicKey | Pda;
   /**
    * Authority PDA account.
    *
@@ -164,18 +165,14 @@ export function destroyV1(
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
-  if (!resolvedAccounts.fusionData.value) {
-    resolvedAccounts.fusionData.value = context.eddsa.findPda(programId, [
-      bytes().serialize(
-        new Uint8Array([102, 117, 115, 105, 111, 110, 95, 100, 97, 116, 97])
-      ),
-    ]);
-  }
   if (!resolvedAccounts.authorityPda.value) {
     resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
       bytes().serialize(
         new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
       ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
     ]);
   }
   if (!resolvedAccounts.authority.value) {
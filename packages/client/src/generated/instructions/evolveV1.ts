@@ -0,0 +1,382 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type EvolveV1InstructionAccounts = {
+  /** Source fusion data account. */
+  sourceFusionData: PublicKey | Pda;
+  /** Source authority pda. */
+  sourceAuthorityPda?: PublicKey | Pda;
+  /** Source collection account. */
+  sourceCollection: PublicKey | Pda;
+  /** Source token escrow pda ata account. */
+  sourceEscrowAtaPda?: PublicKey | Pda;
+  /** Target fusion data account. */
+  targetFusionData: PublicKey | Pda;
+  /** Target authority pda. */
+  targetAuthorityPda?: PublicKey | Pda;
+  /** Target collection account. */
+  targetCollection: PublicKey | Pda;
+  /** Target token escrow pda ata account. */
+  targetEscrowAtaPda?: PublicKey | Pda;
+  /** Owner of the source assets, transaction and rent payer. */
+  user: Signer;
+  /**
+   * Account of the target Asset. Will be initialized with the new asset.
+   * Either a signer keypair or the asset PDA, depending on the address mode,
+   * or any asset locked in the target vault while the vault is not empty.
+   */
+
+  asset: PublicKey | Pda | Signer;
+  /** Mint account of the token, shared by both fusions. */
+  tokenMint: PublicKey | Pda;
+  /** User ata account. */
+  userAta?: PublicKey | Pda;
+  /** Protocol fee account. */
+  feeAccount?: PublicKey | Pda;
+  /** Token program. */
+  tokenProgram?: PublicKey | Pda;
+  /** Associated Token program. */
+  associatedTokenProgram?: PublicKey | Pda;
+  /** MPL Core program. */
+  coreProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+  /** Target metadata pool account, required in random and recycled metadata modes. */
+  metadataPool?: PublicKey | Pda;
+  /** Slot hashes sysvar, required in random metadata mode. */
+  slotHashes?: PublicKey | Pda;
+  /**
+   * Target asset record account, proves that the asset was minted by the program.
+   * Required when minting.
+   */
+
+  assetRecord?: PublicKey | Pda;
+  /** Target asset index account of the next index of the target fusion, required when minting. */
+  assetIndex?: PublicKey | Pda;
+  /** Source metadata pool account, required in recycled metadata mode. */
+  sourceMetadataPool?: PublicKey | Pda;
+  /**
+   * Record of the vaulted target asset, kept since it was minted.
+   * Required when handing out a vaulted asset.
+   */
+
+  vaultedAssetRecord?: PublicKey | Pda;
+};
+
+// Data.
+export type EvolveV1InstructionData = { discriminator: Uint8Array };
+
+export type EvolveV1InstructionDataArgs = {};
+
+export function getEvolveV1InstructionDataSerializer(): Serializer<
+  EvolveV1InstructionDataArgs,
+  EvolveV1InstructionData
+> {
+  return mapSerializer<
+    EvolveV1InstructionDataArgs,
+    any,
+    EvolveV1InstructionData
+  >(
+    struct<EvolveV1InstructionData>([['discriminator', bytes({ size: 8 })]], {
+      description: 'EvolveV1InstructionData',
+    }),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([189, 34, 87, 201, 131, 48, 105, 159]),
+    })
+  ) as Serializer<EvolveV1InstructionDataArgs, EvolveV1InstructionData>;
+}
+
+// Instruction.
+export function evolveV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: EvolveV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    sourceFusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.sourceFusionData ?? null,
+    },
+    sourceAuthorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.sourceAuthorityPda ?? null,
+    },
+    sourceCollection: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.sourceCollection ?? null,
+    },
+    sourceEscrowAtaPda: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.sourceEscrowAtaPda ?? null,
+    },
+    targetFusionData: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.targetFusionData ?? null,
+    },
+    targetAuthorityPda: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.targetAuthorityPda ?? null,
+    },
+    targetCollection: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.targetCollection ?? null,
+    },
+    targetEscrowAtaPda: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.targetEscrowAtaPda ?? null,
+    },
+    user: { index: 8, isWritable: true as boolean, value: input.user ?? null },
+    asset: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    tokenMint: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.tokenMint ?? null,
+    },
+    userAta: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.userAta ?? null,
+    },
+    feeAccount: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.feeAccount ?? null,
+    },
+    tokenProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    coreProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.coreProgram ?? null,
+    },
+    systemProgram: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    metadataPool: {
+      index: 18,
+      isWritable: true as boolean,
+      value: input.metadataPool ?? null,
+    },
+    slotHashes: {
+      index: 19,
+      isWritable: false as boolean,
+      value: input.slotHashes ?? null,
+    },
+    assetRecord: {
+      index: 20,
+      isWritable: true as boolean,
+      value: input.assetRecord ?? null,
+    },
+    assetIndex: {
+      index: 21,
+      isWritable: true as boolean,
+      value: input.assetIndex ?? null,
+    },
+    sourceMetadataPool: {
+      index: 22,
+      isWritable: true as boolean,
+      value: input.sourceMetadataPool ?? null,
+    },
+    vaultedAssetRecord: {
+      index: 23,
+      isWritable: false as boolean,
+      value: input.vaultedAssetRecord ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.sourceAuthorityPda.value) {
+    resolvedAccounts.sourceAuthorityPda.value = context.eddsa.findPda(
+      programId,
+      [
+        bytes().serialize(
+          new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+        ),
+        publicKeySerializer().serialize(
+          expectPublicKey(resolvedAccounts.sourceFusionData.value)
+        ),
+      ]
+    );
+  }
+  if (!resolvedAccounts.sourceEscrowAtaPda.value) {
+    resolvedAccounts.sourceEscrowAtaPda.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+        owner: expectPublicKey(resolvedAccounts.sourceAuthorityPda.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.targetAuthorityPda.value) {
+    resolvedAccounts.targetAuthorityPda.value = context.eddsa.findPda(
+      programId,
+      [
+        bytes().serialize(
+          new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+        ),
+        publicKeySerializer().serialize(
+          expectPublicKey(resolvedAccounts.targetFusionData.value)
+        ),
+      ]
+    );
+  }
+  if (!resolvedAccounts.targetEscrowAtaPda.value) {
+    resolvedAccounts.targetEscrowAtaPda.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+        owner: expectPublicKey(resolvedAccounts.targetAuthorityPda.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.userAta.value) {
+    resolvedAccounts.userAta.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+      owner: expectPublicKey(resolvedAccounts.user.value),
+    });
+  }
+  if (!resolvedAccounts.feeAccount.value) {
+    resolvedAccounts.feeAccount.value = publicKey(
+      'CRumnxQ9i84X7pbmgCdSSMW6WJ7njUad3LgK3kFo11zG'
+    );
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'tokenProgram',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'associatedTokenProgram',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.coreProgram.value) {
+    resolvedAccounts.coreProgram.value = context.programs.getPublicKey(
+      'coreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.coreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.slotHashes.value) {
+    resolvedAccounts.slotHashes.value = context.programs.getPublicKey(
+      'slotHashes',
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+    resolvedAccounts.slotHashes.isWritable = false;
+  }
+  if (!resolvedAccounts.assetRecord.value) {
+    resolvedAccounts.assetRecord.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          97, 115, 115, 101, 116, 95, 114, 101, 99, 111, 114, 100,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.targetFusionData.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.asset.value)
+      ),
+    ]);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getEvolveV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,304 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  CompressedLeafV1,
+  CompressedLeafV1Args,
+  getCompressedLeafV1Serializer,
+} from '../types';
+
+// Accounts.
+export type FusionFromCompressedV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Authority pda. */
+  authorityPda?: PublicKey | Pda;
+  /** Leaf owner, transaction and rent payer. */
+  user: Signer;
+  /** Leaf delegate, optional, defaults to the leaf owner. */
+  leafDelegate?: PublicKey | Pda;
+  /** Mint account of the token. */
+  tokenMint: PublicKey | Pda;
+  /** Token escrow pda ata account. */
+  escrowAtaPda?: PublicKey | Pda;
+  /** User ata account, will be initialized if needed. */
+  userAta?: PublicKey | Pda;
+  /** Protocol fee account. */
+  feeAccount?: PublicKey | Pda;
+  /** Merkle tree account. */
+  merkleTree: PublicKey | Pda;
+  /** Bubblegum tree config account. */
+  treeConfig: PublicKey | Pda;
+  /** Token program. */
+  tokenProgram?: PublicKey | Pda;
+  /** Associated Token program. */
+  associatedTokenProgram?: PublicKey | Pda;
+  /** Bubblegum program. */
+  bubblegumProgram?: PublicKey | Pda;
+  /** SPL Account Compression program. */
+  compressionProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type FusionFromCompressedV1InstructionData = {
+  discriminator: Uint8Array;
+  leaf: CompressedLeafV1;
+};
+
+export type FusionFromCompressedV1InstructionDataArgs = {
+  leaf: CompressedLeafV1Args;
+};
+
+export function getFusionFromCompressedV1InstructionDataSerializer(): Serializer<
+  FusionFromCompressedV1InstructionDataArgs,
+  FusionFromCompressedV1InstructionData
+> {
+  return mapSerializer<
+    FusionFromCompressedV1InstructionDataArgs,
+    any,
+    FusionFromCompressedV1InstructionData
+  >(
+    struct<FusionFromCompressedV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['leaf', getCompressedLeafV1Serializer()],
+      ],
+      { description: 'FusionFromCompressedV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([247, 159, 134, 124, 148, 176, 109, 63]),
+    })
+  ) as Serializer<
+    FusionFromCompressedV1InstructionDataArgs,
+    FusionFromCompressedV1InstructionData
+  >;
+}
+
+// Args.
+export type FusionFromCompressedV1InstructionArgs =
+  FusionFromCompressedV1InstructionDataArgs;
+
+// Instruction.
+export function fusionFromCompressedV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: FusionFromCompressedV1InstructionAccounts &
+    FusionFromCompressedV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    user: { index: 2, isWritable: true as boolean, value: input.user ?? null },
+    leafDelegate: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.leafDelegate ?? null,
+    },
+    tokenMint: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.tokenMint ?? null,
+    },
+    escrowAtaPda: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.escrowAtaPda ?? null,
+    },
+    userAta: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.userAta ?? null,
+    },
+    feeAccount: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.feeAccount ?? null,
+    },
+    merkleTree: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeConfig: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.treeConfig ?? null,
+    },
+    tokenProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    bubblegumProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.bubblegumProgram ?? null,
+    },
+    compressionProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: FusionFromCompressedV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.escrowAtaPda.value) {
+    resolvedAccounts.escrowAtaPda.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+      owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+    });
+  }
+  if (!resolvedAccounts.userAta.value) {
+    resolvedAccounts.userAta.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+      owner: expectPublicKey(resolvedAccounts.user.value),
+    });
+  }
+  if (!resolvedAccounts.feeAccount.value) {
+    resolvedAccounts.feeAccount.value = publicKey(
+      'CRumnxQ9i84X7pbmgCdSSMW6WJ7njUad3LgK3kFo11zG'
+    );
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'tokenProgram',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'associatedTokenProgram',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.bubblegumProgram.value) {
+    resolvedAccounts.bubblegumProgram.value = context.programs.getPublicKey(
+      'bubblegumProgram',
+      'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+    );
+    resolvedAccounts.bubblegumProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'compressionProgram',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'logWrapper',
+      'noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getFusionFromCompressedV1InstructionDataSerializer().serialize(
+    resolvedArgs as FusionFromCompressedV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
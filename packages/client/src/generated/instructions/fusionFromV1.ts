@@ -20,6 +20,7 @@ import {
   Serializer,
   bytes,
   mapSerializer,
+  publicKey as publicKeySerializer,
   struct,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -32,11 +33,17 @@ import {
 // Accounts.
 export type FusionFromV1InstructionAccounts = {
   /** Fusion data account. */
-  fusionData?: PublicKey | Pda;
+  fusionData: PublicKey | Pda;
   /** Authority pda. */
   authorityPda?: PublicKey | Pda;
-  /** Asset owner, transaction and rent payer. */
+  /**
+   * Asset owner, or its burn delegate in burn redeem mode
+   * or its transfer delegate in vault redeem mode, receives the rent of the asset index.
+   */
+
   user: Signer;
+  /** Transaction, rent and fees payer. */
+  payer?: Signer;
   /** Account of the asset. */
   asset: PublicKey | Pda;
   /** Collection account. */
@@ -45,7 +52,7 @@ export type FusionFromV1InstructionAccounts = {
   tokenMint: PublicKey | Pda;
   /** Token escrow pda ata account. */
   escrowAtaPda?: PublicKey | Pda;
-  /** User ata account, will be initialized if needed. */
+  /** User ata account, required without a recipient, will be initialized if needed. */
   userAta?: PublicKey | Pda;
   /** Protocol fee account. */
   feeAccount?: PublicKey | Pda;
@@ -59,6 +66,24 @@ export type FusionFromV1InstructionAccounts = {
   systemProgram?: PublicKey | Pda;
   /** The SPL Noop program. */
   logWrapper?: PublicKey | Pda;
+  /** Asset record account, proves that the asset was minted by the program. */
+  assetRecord?: PublicKey | Pda;
+  /**
+   * Asset index account (seeds `[b"asset_index", fusion_data, mint_index]`), required in burn
+   * redeem mode, closed with the asset.
+   */
+
+  assetIndex?: PublicKey | Pda;
+  /**
+   * Recipient of the redeemed tokens, optional. The tokens go to the user ata when not set.
+   * Required with the asset owner when the user is a delegate.
+   */
+
+  recipient?: PublicKey | Pda;
+  /** Recipient ata account, required with the recipient, will be initialized if needed. */
+  recipientAta?: PublicKey | Pda;
+  /** Metadata pool account, required in recycled metadata mode. */
+  metadataPool?: PublicKey | Pda;
 };
 
 // Data.
@@ -88,7 +113,7 @@ export function getFusionFromV1InstructionDataSerializer(): Serializer<
 
 // Instruction.
 export function fusionFromV1(
-  context: Pick<Context, 'eddsa' | 'programs'>,
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
   input: FusionFromV1InstructionAccounts
 ): TransactionBuilder {
   // Program ID.
@@ -101,7 +126,7 @@ export function fusionFromV1(
   const resolvedAccounts = {
     fusionData: {
       index: 0,
-      isWritable: false as boolean,
+      isWritable: true as boolean,
       value: input.fusionData ?? null,
     },
     authorityPda: {
@@ -110,78 +135,107 @@ export function fusionFromV1(
       value: input.authorityPda ?? null,
     },
     user: { index: 2, isWritable: true as boolean, value: input.user ?? null },
-    asset: {
+    payer: {
       index: 3,
       isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    asset: {
+      index: 4,
+      isWritable: true as boolean,
       value: input.asset ?? null,
     },
     collection: {
-      index: 4,
+      index: 5,
       isWritable: true as boolean,
       value: input.collection ?? null,
     },
     tokenMint: {
-      index: 5,
+      index: 6,
       isWritable: false as boolean,
       value: input.tokenMint ?? null,
     },
     escrowAtaPda: {
-      index: 6,
+      index: 7,
       isWritable: true as boolean,
       value: input.escrowAtaPda ?? null,
     },
     userAta: {
-      index: 7,
+      index: 8,
       isWritable: true as boolean,
       value: input.userAta ?? null,
     },
     feeAccount: {
-      index: 8,
+      index: 9,
       isWritable: true as boolean,
       value: input.feeAccount ?? null,
     },
     tokenProgram: {
-      index: 9,
+      index: 10,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     associatedTokenProgram: {
-      index: 10,
+      index: 11,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
     coreProgram: {
-      index: 11,
+      index: 12,
       isWritable: false as boolean,
       value: input.coreProgram ?? null,
     },
     systemProgram: {
-      index: 12,
+      index: 13,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     logWrapper: {
-      index: 13,
+      index: 14,
       isWritable: false as boolean,
       value: input.logWrapper ?? null,
     },
+    assetRecord: {
+      index: 15,
+      isWritable: true as boolean,
+      value: input.assetRecord ?? null,
+    },
+    assetIndex: {
+      index: 16,
+      isWritable: true as boolean,
+      value: input.assetIndex ?? null,
+    },
+    recipient: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.recipient ?? null,
+    },
+    recipientAta: {
+      index: 18,
+      isWritable: true as boolean,
+      value: input.recipientAta ?? null,
+    },
+    metadataPool: {
+      index: 19,
+      isWritable: true as boolean,
+      value: input.metadataPool ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
-  if (!resolvedAccounts.fusionData.value) {
-    resolvedAccounts.fusionData.value = context.eddsa.findPda(programId, [
-      bytes().serialize(
-        new Uint8Array([102, 117, 115, 105, 111, 110, 95, 100, 97, 116, 97])
-      ),
-    ]);
-  }
   if (!resolvedAccounts.authorityPda.value) {
     resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
       bytes().serialize(
         new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
       ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
     ]);
   }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
   if (!resolvedAccounts.escrowAtaPda.value) {
     resolvedAccounts.escrowAtaPda.value = findAssociatedTokenPda(context, {
       mint: expectPublicKey(resolvedAccounts.tokenMint.value),
@@ -189,10 +243,12 @@ export function fusionFromV1(
     });
   }
   if (!resolvedAccounts.userAta.value) {
-    resolvedAccounts.userAta.value = findAssociatedTokenPda(context, {
-      mint: expectPublicKey(resolvedAccounts.tokenMint.value),
-      owner: expectPublicKey(resolvedAccounts.user.value),
-    });
+    if (!resolvedAccounts.recipient.value) {
+      resolvedAccounts.userAta.value = findAssociatedTokenPda(context, {
+        mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+        owner: expectPublicKey(resolvedAccounts.user.value),
+      });
+    }
   }
   if (!resolvedAccounts.feeAccount.value) {
     resolvedAccounts.feeAccount.value = publicKey(
@@ -228,6 +284,29 @@ export function fusionFromV1(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
+  if (!resolvedAccounts.assetRecord.value) {
+    resolvedAccounts.assetRecord.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          97, 115, 115, 101, 116, 95, 114, 101, 99, 111, 114, 100,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.asset.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.recipientAta.value) {
+    if (resolvedAccounts.recipient.value) {
+      resolvedAccounts.recipientAta.value = findAssociatedTokenPda(context, {
+        mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+        owner: expectPublicKey(resolvedAccounts.recipient.value),
+      });
+    }
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
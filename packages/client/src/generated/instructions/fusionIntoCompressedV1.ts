@@ -0,0 +1,319 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type FusionIntoCompressedV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Authority pda. */
+  authorityPda?: PublicKey | Pda;
+  /** Leaf owner, transaction and rent payer. */
+  user: Signer;
+  /** Collection account, used for the max supply check. */
+  collection: PublicKey | Pda;
+  /** Mint account of the token. */
+  tokenMint: PublicKey | Pda;
+  /** Token escrow pda ata account. */
+  escrowAtaPda?: PublicKey | Pda;
+  /** User token account, owned by the user or delegated to the user. */
+  userAta?: PublicKey | Pda;
+  /** Fee recipient account, optional. */
+  feeRecipient?: PublicKey | Pda;
+  /** Fee recipient ata account, optional. */
+  feeRecipientAta?: PublicKey | Pda;
+  /** Protocol fee account. */
+  feeAccount?: PublicKey | Pda;
+  /** Merkle tree account. */
+  merkleTree: PublicKey | Pda;
+  /** Bubblegum tree config account. */
+  treeConfig: PublicKey | Pda;
+  /** Token program. */
+  tokenProgram?: PublicKey | Pda;
+  /** Associated Token program. */
+  associatedTokenProgram?: PublicKey | Pda;
+  /** Bubblegum program. */
+  bubblegumProgram?: PublicKey | Pda;
+  /** SPL Account Compression program. */
+  compressionProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /** Metadata pool account, required in random metadata mode. */
+  metadataPool?: PublicKey | Pda;
+  /** Slot hashes sysvar, required in random metadata mode. */
+  slotHashes?: PublicKey | Pda;
+};
+
+// Data.
+export type FusionIntoCompressedV1InstructionData = {
+  discriminator: Uint8Array;
+};
+
+export type FusionIntoCompressedV1InstructionDataArgs = {};
+
+export function getFusionIntoCompressedV1InstructionDataSerializer(): Serializer<
+  FusionIntoCompressedV1InstructionDataArgs,
+  FusionIntoCompressedV1InstructionData
+> {
+  return mapSerializer<
+    FusionIntoCompressedV1InstructionDataArgs,
+    any,
+    FusionIntoCompressedV1InstructionData
+  >(
+    struct<FusionIntoCompressedV1InstructionData>(
+      [['discriminator', bytes({ size: 8 })]],
+      { description: 'FusionIntoCompressedV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([35, 0, 199, 66, 137, 24, 14, 225]),
+    })
+  ) as Serializer<
+    FusionIntoCompressedV1InstructionDataArgs,
+    FusionIntoCompressedV1InstructionData
+  >;
+}
+
+// Instruction.
+export function fusionIntoCompressedV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: FusionIntoCompressedV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    user: { index: 2, isWritable: true as boolean, value: input.user ?? null },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    tokenMint: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.tokenMint ?? null,
+    },
+    escrowAtaPda: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.escrowAtaPda ?? null,
+    },
+    userAta: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.userAta ?? null,
+    },
+    feeRecipient: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.feeRecipient ?? null,
+    },
+    feeRecipientAta: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.feeRecipientAta ?? null,
+    },
+    feeAccount: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.feeAccount ?? null,
+    },
+    merkleTree: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeConfig: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    tokenProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    bubblegumProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.bubblegumProgram ?? null,
+    },
+    compressionProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    metadataPool: {
+      index: 18,
+      isWritable: true as boolean,
+      value: input.metadataPool ?? null,
+    },
+    slotHashes: {
+      index: 19,
+      isWritable: false as boolean,
+      value: input.slotHashes ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.escrowAtaPda.value) {
+    resolvedAccounts.escrowAtaPda.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+      owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+    });
+  }
+  if (!resolvedAccounts.userAta.value) {
+    resolvedAccounts.userAta.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+      owner: expectPublicKey(resolvedAccounts.user.value),
+    });
+  }
+  if (!resolvedAccounts.feeAccount.value) {
+    resolvedAccounts.feeAccount.value = publicKey(
+      'CRumnxQ9i84X7pbmgCdSSMW6WJ7njUad3LgK3kFo11zG'
+    );
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'tokenProgram',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'associatedTokenProgram',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.bubblegumProgram.value) {
+    resolvedAccounts.bubblegumProgram.value = context.programs.getPublicKey(
+      'bubblegumProgram',
+      'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+    );
+    resolvedAccounts.bubblegumProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'compressionProgram',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'logWrapper',
+      'noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.slotHashes.value) {
+    resolvedAccounts.slotHashes.value = context.programs.getPublicKey(
+      'slotHashes',
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+    resolvedAccounts.slotHashes.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getFusionIntoCompressedV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -9,6 +9,8 @@
 import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -20,7 +22,11 @@ import {
   Serializer,
   bytes,
   mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
   struct,
+  u64,
+  u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
   ResolvedAccount,
@@ -32,20 +38,28 @@ import {
 // Accounts.
 export type FusionIntoV1InstructionAccounts = {
   /** Fusion data account. */
-  fusionData?: PublicKey | Pda;
+  fusionData: PublicKey | Pda;
   /** Authority pda. */
   authorityPda?: PublicKey | Pda;
-  /** Asset owner, transaction and rent payer. */
+  /** Asset owner unless a recipient is set, token owner. */
   user: Signer;
-  /** Account of the Asset. Will be initialized with the new asset. */
-  asset: Signer;
+  /** Transaction, rent and sol fees payer. */
+  payer?: Signer;
+  /**
+   * Account of the Asset. Will be initialized with the new asset.
+   * Either a signer keypair or the asset PDA, depending on the address mode,
+   * or any asset locked in the vault while the vault is not empty.
+   * vaulted assets must have a record
+   */
+
+  asset: PublicKey | Pda | Signer;
   /** Collection account. */
   collection: PublicKey | Pda;
   /** Mint account of the token. */
   tokenMint: PublicKey | Pda;
   /** Token escrow pda ata account. */
   escrowAtaPda?: PublicKey | Pda;
-  /** User ata account. */
+  /** User token account, owned by the user or delegated to the user. */
   userAta?: PublicKey | Pda;
   /** Fee recipient account, optional. */
   feeRecipient?: PublicKey | Pda;
@@ -63,12 +77,39 @@ export type FusionIntoV1InstructionAccounts = {
   systemProgram?: PublicKey | Pda;
   /** The SPL Noop program. */
   logWrapper?: PublicKey | Pda;
+  /** Metadata pool account, required in random and recycled metadata modes. */
+  metadataPool?: PublicKey | Pda;
+  /** Slot hashes sysvar, required in random metadata mode. */
+  slotHashes?: PublicKey | Pda;
+  /**
+   * Asset record account, proves that the asset was minted by the program.
+   * Required when minting.
+   */
+
+  assetRecord?: PublicKey | Pda;
+  /** Asset recipient, optional. The Asset is owned by the user when not set. */
+  recipient?: PublicKey | Pda;
+  /** Asset index account of the next index of the fusion, required when minting. */
+  assetIndex?: PublicKey | Pda;
+  /**
+   * Record of the vaulted asset, kept since it was minted.
+   * Required when handing out a vaulted asset.
+   */
+
+  vaultedAssetRecord?: PublicKey | Pda;
 };
 
 // Data.
-export type FusionIntoV1InstructionData = { discriminator: Uint8Array };
+export type FusionIntoV1InstructionData = {
+  discriminator: Uint8Array;
+  tier: Option<number>;
+  amount: Option<bigint>;
+};
 
-export type FusionIntoV1InstructionDataArgs = {};
+export type FusionIntoV1InstructionDataArgs = {
+  tier: OptionOrNullable<number>;
+  amount: OptionOrNullable<number | bigint>;
+};
 
 export function getFusionIntoV1InstructionDataSerializer(): Serializer<
   FusionIntoV1InstructionDataArgs,
@@ -80,7 +121,11 @@ export function getFusionIntoV1InstructionDataSerializer(): Serializer<
     FusionIntoV1InstructionData
   >(
     struct<FusionIntoV1InstructionData>(
-      [['discriminator', bytes({ size: 8 })]],
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['tier', option(u8())],
+        ['amount', option(u64())],
+      ],
       { description: 'FusionIntoV1InstructionData' }
     ),
     (value) => ({
@@ -90,10 +135,13 @@ export function getFusionIntoV1InstructionDataSerializer(): Serializer<
   ) as Serializer<FusionIntoV1InstructionDataArgs, FusionIntoV1InstructionData>;
 }
 
+// Args.
+export type FusionIntoV1InstructionArgs = FusionIntoV1InstructionDataArgs;
+
 // Instruction.
 export function fusionIntoV1(
-  context: Pick<Context, 'eddsa' | 'programs'>,
-  input: FusionIntoV1InstructionAccounts
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: FusionIntoV1InstructionAccounts & FusionIntoV1InstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -113,89 +161,126 @@ export function fusionIntoV1(
       isWritable: false as boolean,
       value: input.authorityPda ?? null,
     },
-    user: { index: 2, isWritable: true as boolean, value: input.user ?? null },
-    asset: {
+    user: { index: 2, isWritable: false as boolean, value: input.user ?? null },
+    payer: {
       index: 3,
       isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    asset: {
+      index: 4,
+      isWritable: true as boolean,
       value: input.asset ?? null,
     },
     collection: {
-      index: 4,
+      index: 5,
       isWritable: true as boolean,
       value: input.collection ?? null,
     },
     tokenMint: {
-      index: 5,
+      index: 6,
       isWritable: true as boolean,
       value: input.tokenMint ?? null,
     },
     escrowAtaPda: {
-      index: 6,
+      index: 7,
       isWritable: true as boolean,
       value: input.escrowAtaPda ?? null,
     },
     userAta: {
-      index: 7,
+      index: 8,
       isWritable: true as boolean,
       value: input.userAta ?? null,
     },
     feeRecipient: {
-      index: 8,
+      index: 9,
       isWritable: true as boolean,
       value: input.feeRecipient ?? null,
     },
     feeRecipientAta: {
-      index: 9,
+      index: 10,
       isWritable: true as boolean,
       value: input.feeRecipientAta ?? null,
     },
     feeAccount: {
-      index: 10,
+      index: 11,
       isWritable: true as boolean,
       value: input.feeAccount ?? null,
     },
     tokenProgram: {
-      index: 11,
+      index: 12,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     associatedTokenProgram: {
-      index: 12,
+      index: 13,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
     coreProgram: {
-      index: 13,
+      index: 14,
       isWritable: false as boolean,
       value: input.coreProgram ?? null,
     },
     systemProgram: {
-      index: 14,
+      index: 15,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     logWrapper: {
-      index: 15,
+      index: 16,
       isWritable: false as boolean,
       value: input.logWrapper ?? null,
     },
+    metadataPool: {
+      index: 17,
+      isWritable: true as boolean,
+      value: input.metadataPool ?? null,
+    },
+    slotHashes: {
+      index: 18,
+      isWritable: false as boolean,
+      value: input.slotHashes ?? null,
+    },
+    assetRecord: {
+      index: 19,
+      isWritable: true as boolean,
+      value: input.assetRecord ?? null,
+    },
+    recipient: {
+      index: 20,
+      isWritable: false as boolean,
+      value: input.recipient ?? null,
+    },
+    assetIndex: {
+      index: 21,
+      isWritable: true as boolean,
+      value: input.assetIndex ?? null,
+    },
+    vaultedAssetRecord: {
+      index: 22,
+      isWritable: false as boolean,
+      value: input.vaultedAssetRecord ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
+  // Arguments.
+  const resolvedArgs: FusionIntoV1InstructionArgs = { ...input };
+
   // Default values.
-  if (!resolvedAccounts.fusionData.value) {
-    resolvedAccounts.fusionData.value = context.eddsa.findPda(programId, [
-      bytes().serialize(
-        new Uint8Array([102, 117, 115, 105, 111, 110, 95, 100, 97, 116, 97])
-      ),
-    ]);
-  }
   if (!resolvedAccounts.authorityPda.value) {
     resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
       bytes().serialize(
         new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
       ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
     ]);
   }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
   if (!resolvedAccounts.escrowAtaPda.value) {
     resolvedAccounts.escrowAtaPda.value = findAssociatedTokenPda(context, {
       mint: expectPublicKey(resolvedAccounts.tokenMint.value),
@@ -242,6 +327,28 @@ export function fusionIntoV1(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
+  if (!resolvedAccounts.slotHashes.value) {
+    resolvedAccounts.slotHashes.value = context.programs.getPublicKey(
+      'slotHashes',
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+    resolvedAccounts.slotHashes.isWritable = false;
+  }
+  if (!resolvedAccounts.assetRecord.value) {
+    resolvedAccounts.assetRecord.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          97, 115, 115, 101, 116, 95, 114, 101, 99, 111, 114, 100,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.asset.value)
+      ),
+    ]);
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -256,7 +363,9 @@ export function fusionIntoV1(
   );
 
   // Data.
-  const data = getFusionIntoV1InstructionDataSerializer().serialize({});
+  const data = getFusionIntoV1InstructionDataSerializer().serialize(
+    resolvedArgs as FusionIntoV1InstructionDataArgs
+  );
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
@@ -6,10 +6,30 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './addCollectionPluginV1';
 export * from './destroyV1';
+export * from './evolveV1';
+export * from './fusionFromCompressedV1';
 export * from './fusionFromV1';
+export * from './fusionIntoCompressedV1';
 export * from './fusionIntoV1';
+export * from './initMetadataPoolV1';
+export * from './initTreeV1';
 export * from './initV1';
+export * from './mergeAssetsV1';
+export * from './migrateV1';
+export * from './recordAssetsV1';
+export * from './removeCollectionPluginV1';
+export * from './revealV1';
 export * from './setAuthorityV1';
+export * from './setBackingV1';
+export * from './setEvolutionV1';
+export * from './setIngredientsV1';
 export * from './setPauseV1';
+export * from './setTiersV1';
+export * from './splitAssetV1';
+export * from './topUpV1';
+export * from './updateAssetsV1';
+export * from './updateCollectionPluginV1';
 export * from './updateV1';
+export * from './withdrawPartialV1';
@@ -0,0 +1,151 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitMetadataPoolV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Metadata pool account. */
+  metadataPool?: PublicKey | Pda;
+  /** Authority and payer of the transaction. */
+  authority?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type InitMetadataPoolV1InstructionData = { discriminator: Uint8Array };
+
+export type InitMetadataPoolV1InstructionDataArgs = {};
+
+export function getInitMetadataPoolV1InstructionDataSerializer(): Serializer<
+  InitMetadataPoolV1InstructionDataArgs,
+  InitMetadataPoolV1InstructionData
+> {
+  return mapSerializer<
+    InitMetadataPoolV1InstructionDataArgs,
+    any,
+    InitMetadataPoolV1InstructionData
+  >(
+    struct<InitMetadataPoolV1InstructionData>(
+      [['discriminator', bytes({ size: 8 })]],
+      { description: 'InitMetadataPoolV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([155, 17, 145, 91, 30, 132, 144, 173]),
+    })
+  ) as Serializer<
+    InitMetadataPoolV1InstructionDataArgs,
+    InitMetadataPoolV1InstructionData
+  >;
+}
+
+// Instruction.
+export function initMetadataPoolV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: InitMetadataPoolV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.fusionData ?? null,
+    },
+    metadataPool: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.metadataPool ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.metadataPool.value) {
+    resolvedAccounts.metadataPool.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          109, 101, 116, 97, 100, 97, 116, 97, 95, 112, 111, 111, 108,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitMetadataPoolV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,222 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitTreeV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Authority pda. */
+  authorityPda?: PublicKey | Pda;
+  /** Authority and payer of the transaction. */
+  authority?: Signer;
+  /** Merkle tree account, allocated by the client and owned by the compression program. */
+  merkleTree: PublicKey | Pda;
+  /** Bubblegum tree config account. */
+  treeConfig: PublicKey | Pda;
+  /** Bubblegum program. */
+  bubblegumProgram?: PublicKey | Pda;
+  /** SPL Account Compression program. */
+  compressionProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type InitTreeV1InstructionData = {
+  discriminator: Uint8Array;
+  maxDepth: number;
+  maxBufferSize: number;
+};
+
+export type InitTreeV1InstructionDataArgs = {
+  maxDepth: number;
+  maxBufferSize: number;
+};
+
+export function getInitTreeV1InstructionDataSerializer(): Serializer<
+  InitTreeV1InstructionDataArgs,
+  InitTreeV1InstructionData
+> {
+  return mapSerializer<
+    InitTreeV1InstructionDataArgs,
+    any,
+    InitTreeV1InstructionData
+  >(
+    struct<InitTreeV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['maxDepth', u32()],
+        ['maxBufferSize', u32()],
+      ],
+      { description: 'InitTreeV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([112, 85, 0, 37, 35, 67, 251, 153]),
+    })
+  ) as Serializer<InitTreeV1InstructionDataArgs, InitTreeV1InstructionData>;
+}
+
+// Args.
+export type InitTreeV1InstructionArgs = InitTreeV1InstructionDataArgs;
+
+// Instruction.
+export function initTreeV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: InitTreeV1InstructionAccounts & InitTreeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeConfig: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    bubblegumProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.bubblegumProgram ?? null,
+    },
+    compressionProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitTreeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.bubblegumProgram.value) {
+    resolvedAccounts.bubblegumProgram.value = context.programs.getPublicKey(
+      'bubblegumProgram',
+      'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+    );
+    resolvedAccounts.bubblegumProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'compressionProgram',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'logWrapper',
+      'noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitTreeV1InstructionDataSerializer().serialize(
+    resolvedArgs as InitTreeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -19,6 +19,7 @@ import {
   Serializer,
   bytes,
   mapSerializer,
+  publicKey as publicKeySerializer,
   struct,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -193,6 +194,9 @@ export function initV1(
       bytes().serialize(
         new Uint8Array([102, 117, 115, 105, 111, 110, 95, 100, 97, 116, 97])
       ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.collection.value)
+      ),
     ]);
   }
   if (!resolvedAccounts.authorityPda.value) {
@@ -200,6 +204,9 @@ export function initV1(
       bytes().serialize(
         new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
       ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
     ]);
   }
   if (!resolvedAccounts.authority.value) {
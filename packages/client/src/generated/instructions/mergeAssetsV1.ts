@@ -0,0 +1,258 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MergeAssetsV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Authority pda. */
+  authorityPda?: PublicKey | Pda;
+  /** Owner of both assets, transaction and rent payer. */
+  user: Signer;
+  /** Account of the asset receiving the merged backing. */
+  asset: PublicKey | Pda;
+  /** Asset record account. */
+  assetRecord?: PublicKey | Pda;
+  /** Account of the asset to burn. */
+  burnedAsset: PublicKey | Pda;
+  /** Record account of the asset to burn, will be closed. */
+  burnedAssetRecord?: PublicKey | Pda;
+  /** Index account of the asset to burn (seeds `[b"asset_index", fusion_data, mint_index]`). */
+  burnedAssetIndex: PublicKey | Pda;
+  /** Collection account. */
+  collection: PublicKey | Pda;
+  /** Protocol fee account. */
+  feeAccount?: PublicKey | Pda;
+  /** MPL Core program. */
+  coreProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+  /** Metadata pool account, required in recycled metadata mode. */
+  metadataPool?: PublicKey | Pda;
+};
+
+// Data.
+export type MergeAssetsV1InstructionData = { discriminator: Uint8Array };
+
+export type MergeAssetsV1InstructionDataArgs = {};
+
+export function getMergeAssetsV1InstructionDataSerializer(): Serializer<
+  MergeAssetsV1InstructionDataArgs,
+  MergeAssetsV1InstructionData
+> {
+  return mapSerializer<
+    MergeAssetsV1InstructionDataArgs,
+    any,
+    MergeAssetsV1InstructionData
+  >(
+    struct<MergeAssetsV1InstructionData>(
+      [['discriminator', bytes({ size: 8 })]],
+      { description: 'MergeAssetsV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([53, 3, 101, 34, 158, 69, 195, 213]),
+    })
+  ) as Serializer<
+    MergeAssetsV1InstructionDataArgs,
+    MergeAssetsV1InstructionData
+  >;
+}
+
+// Instruction.
+export function mergeAssetsV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: MergeAssetsV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    user: { index: 2, isWritable: true as boolean, value: input.user ?? null },
+    asset: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    assetRecord: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.assetRecord ?? null,
+    },
+    burnedAsset: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.burnedAsset ?? null,
+    },
+    burnedAssetRecord: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.burnedAssetRecord ?? null,
+    },
+    burnedAssetIndex: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.burnedAssetIndex ?? null,
+    },
+    collection: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    feeAccount: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.feeAccount ?? null,
+    },
+    coreProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.coreProgram ?? null,
+    },
+    systemProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    metadataPool: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.metadataPool ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.assetRecord.value) {
+    resolvedAccounts.assetRecord.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          97, 115, 115, 101, 116, 95, 114, 101, 99, 111, 114, 100,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.asset.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.burnedAssetRecord.value) {
+    resolvedAccounts.burnedAssetRecord.value = context.eddsa.findPda(
+      programId,
+      [
+        bytes().serialize(
+          new Uint8Array([
+            97, 115, 115, 101, 116, 95, 114, 101, 99, 111, 114, 100,
+          ])
+        ),
+        publicKeySerializer().serialize(
+          expectPublicKey(resolvedAccounts.fusionData.value)
+        ),
+        publicKeySerializer().serialize(
+          expectPublicKey(resolvedAccounts.burnedAsset.value)
+        ),
+      ]
+    );
+  }
+  if (!resolvedAccounts.feeAccount.value) {
+    resolvedAccounts.feeAccount.value = publicKey(
+      'CRumnxQ9i84X7pbmgCdSSMW6WJ7njUad3LgK3kFo11zG'
+    );
+  }
+  if (!resolvedAccounts.coreProgram.value) {
+    resolvedAccounts.coreProgram.value = context.programs.getPublicKey(
+      'coreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.coreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMergeAssetsV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,292 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateV1InstructionAccounts = {
+  /**
+   * Legacy fusion data account.
+   *
+   */
+
+  legacyFusionData?: PublicKey | Pda;
+  /**
+   * Legacy authority pda, owner of the legacy escrow.
+   *
+   */
+
+  legacyAuthorityPda?: PublicKey | Pda;
+  /** Fusion data account of the collection. */
+  fusionData?: PublicKey | Pda;
+  /**
+   * Authority pda of the collection fusion.
+   *
+   */
+
+  authorityPda?: PublicKey | Pda;
+  /** Authority of the legacy fusion and of the collection, payer of the transaction. */
+  authority?: Signer;
+  /** Mint account of the token. */
+  tokenMint: PublicKey | Pda;
+  /** Legacy token escrow pda ata account. */
+  legacyEscrowAtaPda?: PublicKey | Pda;
+  /** Token escrow pda ata account. */
+  escrowAtaPda?: PublicKey | Pda;
+  /** Collection account. */
+  collection: PublicKey | Pda;
+  /** Token program. */
+  tokenProgram?: PublicKey | Pda;
+  /** Associated Token program. */
+  associatedTokenProgram?: PublicKey | Pda;
+  /** MPL Core program. */
+  coreProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateV1InstructionData = { discriminator: Uint8Array };
+
+export type MigrateV1InstructionDataArgs = {};
+
+export function getMigrateV1InstructionDataSerializer(): Serializer<
+  MigrateV1InstructionDataArgs,
+  MigrateV1InstructionData
+> {
+  return mapSerializer<
+    MigrateV1InstructionDataArgs,
+    any,
+    MigrateV1InstructionData
+  >(
+    struct<MigrateV1InstructionData>([['discriminator', bytes({ size: 8 })]], {
+      description: 'MigrateV1InstructionData',
+    }),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([254, 34, 200, 199, 67, 106, 166, 134]),
+    })
+  ) as Serializer<MigrateV1InstructionDataArgs, MigrateV1InstructionData>;
+}
+
+// Instruction.
+export function migrateV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: MigrateV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    legacyFusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.legacyFusionData ?? null,
+    },
+    legacyAuthorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.legacyAuthorityPda ?? null,
+    },
+    fusionData: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    tokenMint: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.tokenMint ?? null,
+    },
+    legacyEscrowAtaPda: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.legacyEscrowAtaPda ?? null,
+    },
+    escrowAtaPda: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.escrowAtaPda ?? null,
+    },
+    collection: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    tokenProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    coreProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.coreProgram ?? null,
+    },
+    systemProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.legacyFusionData.value) {
+    resolvedAccounts.legacyFusionData.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([102, 117, 115, 105, 111, 110, 95, 100, 97, 116, 97])
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.legacyAuthorityPda.value) {
+    resolvedAccounts.legacyAuthorityPda.value = context.eddsa.findPda(
+      programId,
+      [
+        bytes().serialize(
+          new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+        ),
+      ]
+    );
+  }
+  if (!resolvedAccounts.fusionData.value) {
+    resolvedAccounts.fusionData.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([102, 117, 115, 105, 111, 110, 95, 100, 97, 116, 97])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.collection.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.legacyEscrowAtaPda.value) {
+    resolvedAccounts.legacyEscrowAtaPda.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+        owner: expectPublicKey(resolvedAccounts.legacyAuthorityPda.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.escrowAtaPda.value) {
+    resolvedAccounts.escrowAtaPda.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+      owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+    });
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'tokenProgram',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'associatedTokenProgram',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.coreProgram.value) {
+    resolvedAccounts.coreProgram.value = context.programs.getPublicKey(
+      'coreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.coreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,130 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RecordAssetsV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Authority and payer of the transaction. */
+  authority?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RecordAssetsV1InstructionData = { discriminator: Uint8Array };
+
+export type RecordAssetsV1InstructionDataArgs = {};
+
+export function getRecordAssetsV1InstructionDataSerializer(): Serializer<
+  RecordAssetsV1InstructionDataArgs,
+  RecordAssetsV1InstructionData
+> {
+  return mapSerializer<
+    RecordAssetsV1InstructionDataArgs,
+    any,
+    RecordAssetsV1InstructionData
+  >(
+    struct<RecordAssetsV1InstructionData>(
+      [['discriminator', bytes({ size: 8 })]],
+      { description: 'RecordAssetsV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([4, 168, 247, 101, 11, 174, 9, 129]),
+    })
+  ) as Serializer<
+    RecordAssetsV1InstructionDataArgs,
+    RecordAssetsV1InstructionData
+  >;
+}
+
+// Instruction.
+export function recordAssetsV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: RecordAssetsV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRecordAssetsV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
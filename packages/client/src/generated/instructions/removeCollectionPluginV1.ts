@@ -0,0 +1,208 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  CollectionPluginTypeV1,
+  CollectionPluginTypeV1Args,
+  getCollectionPluginTypeV1Serializer,
+} from '../types';
+
+// Accounts.
+export type RemoveCollectionPluginV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /**
+   * Authority PDA, update delegate of the collection.
+   *
+   */
+
+  authorityPda?: PublicKey | Pda;
+  /** Authority and payer of the transaction. */
+  authority?: Signer;
+  /**
+   * Collection account
+   *
+   */
+
+  collection: PublicKey | Pda;
+  /** MPL Core program. */
+  coreProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+};
+
+// Data.
+export type RemoveCollectionPluginV1InstructionData = {
+  discriminator: Uint8Array;
+  pluginType: CollectionPluginTypeV1;
+};
+
+export type RemoveCollectionPluginV1InstructionDataArgs = {
+  pluginType: CollectionPluginTypeV1Args;
+};
+
+export function getRemoveCollectionPluginV1InstructionDataSerializer(): Serializer<
+  RemoveCollectionPluginV1InstructionDataArgs,
+  RemoveCollectionPluginV1InstructionData
+> {
+  return mapSerializer<
+    RemoveCollectionPluginV1InstructionDataArgs,
+    any,
+    RemoveCollectionPluginV1InstructionData
+  >(
+    struct<RemoveCollectionPluginV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['pluginType', getCollectionPluginTypeV1Serializer()],
+      ],
+      { description: 'RemoveCollectionPluginV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([111, 193, 200, 188, 159, 126, 242, 52]),
+    })
+  ) as Serializer<
+    RemoveCollectionPluginV1InstructionDataArgs,
+    RemoveCollectionPluginV1InstructionData
+  >;
+}
+
+// Args.
+export type RemoveCollectionPluginV1InstructionArgs =
+  RemoveCollectionPluginV1InstructionDataArgs;
+
+// Instruction.
+export function removeCollectionPluginV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: RemoveCollectionPluginV1InstructionAccounts &
+    RemoveCollectionPluginV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    coreProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.coreProgram ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RemoveCollectionPluginV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.coreProgram.value) {
+    resolvedAccounts.coreProgram.value = context.programs.getPublicKey(
+      'coreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.coreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRemoveCollectionPluginV1InstructionDataSerializer().serialize(
+    resolvedArgs as RemoveCollectionPluginV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
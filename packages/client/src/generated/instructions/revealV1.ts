@@ -0,0 +1,207 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RevealV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /**
+   * Authority PDA, update delegate of the collection.
+   *
+   */
+
+  authorityPda?: PublicKey | Pda;
+  /** Authority and payer of the transaction. */
+  authority?: Signer;
+  /**
+   * Collection account
+   *
+   */
+
+  collection: PublicKey | Pda;
+  /** MPL Core program. */
+  coreProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+};
+
+// Data.
+export type RevealV1InstructionData = {
+  discriminator: Uint8Array;
+  uriPrefix: Option<string>;
+  salt: Option<Array<number>>;
+};
+
+export type RevealV1InstructionDataArgs = {
+  uriPrefix: OptionOrNullable<string>;
+  salt: OptionOrNullable<Array<number>>;
+};
+
+export function getRevealV1InstructionDataSerializer(): Serializer<
+  RevealV1InstructionDataArgs,
+  RevealV1InstructionData
+> {
+  return mapSerializer<
+    RevealV1InstructionDataArgs,
+    any,
+    RevealV1InstructionData
+  >(
+    struct<RevealV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['uriPrefix', option(string())],
+        ['salt', option(array(u8(), { size: 32 }))],
+      ],
+      { description: 'RevealV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([139, 105, 51, 112, 190, 29, 99, 217]),
+    })
+  ) as Serializer<RevealV1InstructionDataArgs, RevealV1InstructionData>;
+}
+
+// Args.
+export type RevealV1InstructionArgs = RevealV1InstructionDataArgs;
+
+// Instruction.
+export function revealV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: RevealV1InstructionAccounts & RevealV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    coreProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.coreProgram ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RevealV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.coreProgram.value) {
+    resolvedAccounts.coreProgram.value = context.programs.getPublicKey(
+      'coreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.coreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRevealV1InstructionDataSerializer().serialize(
+    resolvedArgs as RevealV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
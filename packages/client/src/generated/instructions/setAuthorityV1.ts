@@ -30,7 +30,7 @@ import {
 // Accounts.
 export type SetAuthorityV1InstructionAccounts = {
   /** Fusion data account. */
-  fusionData?: PublicKey | Pda;
+  fusionData: PublicKey | Pda;
   /** Current authority */
   authority?: Signer;
 };
@@ -74,7 +74,7 @@ export type SetAuthorityV1InstructionArgs = SetAuthorityV1InstructionDataArgs;
 
 // Instruction.
 export function setAuthorityV1(
-  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  context: Pick<Context, 'identity' | 'programs'>,
   input: SetAuthorityV1InstructionAccounts & SetAuthorityV1InstructionArgs
 ): TransactionBuilder {
   // Program ID.
@@ -101,13 +101,6 @@ export function setAuthorityV1(
   const resolvedArgs: SetAuthorityV1InstructionArgs = { ...input };
 
   // Default values.
-  if (!resolvedAccounts.fusionData.value) {
-    resolvedAccounts.fusionData.value = context.eddsa.findPda(programId, [
-      bytes().serialize(
-        new Uint8Array([102, 117, 115, 105, 111, 110, 95, 100, 97, 116, 97])
-      ),
-    ]);
-  }
   if (!resolvedAccounts.authority.value) {
     resolvedAccounts.authority.value = context.identity;
   }
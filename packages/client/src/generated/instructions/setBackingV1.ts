@@ -0,0 +1,137 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  option,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  BackingDataV1,
+  BackingDataV1Args,
+  getBackingDataV1Serializer,
+} from '../types';
+
+// Accounts.
+export type SetBackingV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Current authority */
+  authority?: Signer;
+};
+
+// Data.
+export type SetBackingV1InstructionData = {
+  discriminator: Uint8Array;
+  backing: Option<BackingDataV1>;
+};
+
+export type SetBackingV1InstructionDataArgs = {
+  backing: OptionOrNullable<BackingDataV1Args>;
+};
+
+export function getSetBackingV1InstructionDataSerializer(): Serializer<
+  SetBackingV1InstructionDataArgs,
+  SetBackingV1InstructionData
+> {
+  return mapSerializer<
+    SetBackingV1InstructionDataArgs,
+    any,
+    SetBackingV1InstructionData
+  >(
+    struct<SetBackingV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['backing', option(getBackingDataV1Serializer())],
+      ],
+      { description: 'SetBackingV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([163, 193, 149, 234, 88, 235, 151, 151]),
+    })
+  ) as Serializer<SetBackingV1InstructionDataArgs, SetBackingV1InstructionData>;
+}
+
+// Args.
+export type SetBackingV1InstructionArgs = SetBackingV1InstructionDataArgs;
+
+// Instruction.
+export function setBackingV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetBackingV1InstructionAccounts & SetBackingV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetBackingV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetBackingV1InstructionDataSerializer().serialize(
+    resolvedArgs as SetBackingV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
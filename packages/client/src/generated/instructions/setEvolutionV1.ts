@@ -0,0 +1,154 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  option,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  EvolutionDataV1,
+  EvolutionDataV1Args,
+  getEvolutionDataV1Serializer,
+} from '../types';
+
+// Accounts.
+export type SetEvolutionV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Current authority */
+  authority?: Signer;
+  /** Target fusion data account, required when setting the link. */
+  targetFusionData?: PublicKey | Pda;
+  /** Target fusion authority, required when setting the link. */
+  targetAuthority?: Signer;
+};
+
+// Data.
+export type SetEvolutionV1InstructionData = {
+  discriminator: Uint8Array;
+  evolution: Option<EvolutionDataV1>;
+};
+
+export type SetEvolutionV1InstructionDataArgs = {
+  evolution: OptionOrNullable<EvolutionDataV1Args>;
+};
+
+export function getSetEvolutionV1InstructionDataSerializer(): Serializer<
+  SetEvolutionV1InstructionDataArgs,
+  SetEvolutionV1InstructionData
+> {
+  return mapSerializer<
+    SetEvolutionV1InstructionDataArgs,
+    any,
+    SetEvolutionV1InstructionData
+  >(
+    struct<SetEvolutionV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['evolution', option(getEvolutionDataV1Serializer())],
+      ],
+      { description: 'SetEvolutionV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([204, 77, 218, 42, 156, 88, 188, 148]),
+    })
+  ) as Serializer<
+    SetEvolutionV1InstructionDataArgs,
+    SetEvolutionV1InstructionData
+  >;
+}
+
+// Args.
+export type SetEvolutionV1InstructionArgs = SetEvolutionV1InstructionDataArgs;
+
+// Instruction.
+export function setEvolutionV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetEvolutionV1InstructionAccounts & SetEvolutionV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    targetFusionData: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.targetFusionData ?? null,
+    },
+    targetAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.targetAuthority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetEvolutionV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetEvolutionV1InstructionDataSerializer().serialize(
+    resolvedArgs as SetEvolutionV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -30,7 +30,7 @@ import {
 // Accounts.
 export type SetPauseV1InstructionAccounts = {
   /** Fusion data account. */
-  fusionData?: PublicKey | Pda;
+  fusionData: PublicKey | Pda;
   /** Current authority */
   authority?: Signer;
 };
@@ -71,7 +71,7 @@ export type SetPauseV1InstructionArgs = SetPauseV1InstructionDataArgs;
 
 // Instruction.
 export function setPauseV1(
-  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  context: Pick<Context, 'identity' | 'programs'>,
   input: SetPauseV1InstructionAccounts & SetPauseV1InstructionArgs
 ): TransactionBuilder {
   // Program ID.
@@ -98,13 +98,6 @@ export function setPauseV1(
   const resolvedArgs: SetPauseV1InstructionArgs = { ...input };
 
   // Default values.
-  if (!resolvedAccounts.fusionData.value) {
-    resolvedAccounts.fusionData.value = context.eddsa.findPda(programId, [
-      bytes().serialize(
-        new Uint8Array([102, 117, 115, 105, 111, 110, 95, 100, 97, 116, 97])
-      ),
-    ]);
-  }
   if (!resolvedAccounts.authority.value) {
     resolvedAccounts.authority.value = context.identity;
   }
@@ -0,0 +1,129 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import { TierV1, TierV1Args, getTierV1Serializer } from '../types';
+
+// Accounts.
+export type SetTiersV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Current authority */
+  authority?: Signer;
+};
+
+// Data.
+export type SetTiersV1InstructionData = {
+  discriminator: Uint8Array;
+  tiers: Array<TierV1>;
+};
+
+export type SetTiersV1InstructionDataArgs = { tiers: Array<TierV1Args> };
+
+export function getSetTiersV1InstructionDataSerializer(): Serializer<
+  SetTiersV1InstructionDataArgs,
+  SetTiersV1InstructionData
+> {
+  return mapSerializer<
+    SetTiersV1InstructionDataArgs,
+    any,
+    SetTiersV1InstructionData
+  >(
+    struct<SetTiersV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['tiers', array(getTierV1Serializer())],
+      ],
+      { description: 'SetTiersV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([191, 237, 74, 152, 204, 183, 140, 95]),
+    })
+  ) as Serializer<SetTiersV1InstructionDataArgs, SetTiersV1InstructionData>;
+}
+
+// Args.
+export type SetTiersV1InstructionArgs = SetTiersV1InstructionDataArgs;
+
+// Instruction.
+export function setTiersV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetTiersV1InstructionAccounts & SetTiersV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetTiersV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetTiersV1InstructionDataSerializer().serialize(
+    resolvedArgs as SetTiersV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,285 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SplitAssetV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Authority pda. */
+  authorityPda?: PublicKey | Pda;
+  /** Asset owner, transaction and rent payer. */
+  user: Signer;
+  /** Account of the asset to split. */
+  asset: PublicKey | Pda;
+  /** Asset record account. */
+  assetRecord?: PublicKey | Pda;
+  /**
+   * Account of the new Asset. Will be initialized with the split backing.
+   * Either a signer keypair or the asset PDA, depending on the address mode.
+   */
+
+  newAsset: PublicKey | Pda | Signer;
+  /** Record account of the new asset. */
+  newAssetRecord?: PublicKey | Pda;
+  /** Index account of the new asset, keyed on the next index of the fusion. */
+  newAssetIndex: PublicKey | Pda;
+  /** Collection account. */
+  collection: PublicKey | Pda;
+  /** Protocol fee account. */
+  feeAccount?: PublicKey | Pda;
+  /** MPL Core program. */
+  coreProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+  /** Metadata pool account, required in random and recycled metadata modes. */
+  metadataPool?: PublicKey | Pda;
+  /** Slot hashes sysvar, required in random metadata mode. */
+  slotHashes?: PublicKey | Pda;
+};
+
+// Data.
+export type SplitAssetV1InstructionData = {
+  discriminator: Uint8Array;
+  amount: bigint;
+};
+
+export type SplitAssetV1InstructionDataArgs = { amount: number | bigint };
+
+export function getSplitAssetV1InstructionDataSerializer(): Serializer<
+  SplitAssetV1InstructionDataArgs,
+  SplitAssetV1InstructionData
+> {
+  return mapSerializer<
+    SplitAssetV1InstructionDataArgs,
+    any,
+    SplitAssetV1InstructionData
+  >(
+    struct<SplitAssetV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['amount', u64()],
+      ],
+      { description: 'SplitAssetV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([226, 173, 109, 132, 247, 220, 64, 145]),
+    })
+  ) as Serializer<SplitAssetV1InstructionDataArgs, SplitAssetV1InstructionData>;
+}
+
+// Args.
+export type SplitAssetV1InstructionArgs = SplitAssetV1InstructionDataArgs;
+
+// Instruction.
+export function splitAssetV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: SplitAssetV1InstructionAccounts & SplitAssetV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    user: { index: 2, isWritable: true as boolean, value: input.user ?? null },
+    asset: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    assetRecord: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.assetRecord ?? null,
+    },
+    newAsset: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.newAsset ?? null,
+    },
+    newAssetRecord: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.newAssetRecord ?? null,
+    },
+    newAssetIndex: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.newAssetIndex ?? null,
+    },
+    collection: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    feeAccount: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.feeAccount ?? null,
+    },
+    coreProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.coreProgram ?? null,
+    },
+    systemProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    metadataPool: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.metadataPool ?? null,
+    },
+    slotHashes: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.slotHashes ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SplitAssetV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.assetRecord.value) {
+    resolvedAccounts.assetRecord.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          97, 115, 115, 101, 116, 95, 114, 101, 99, 111, 114, 100,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.asset.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.newAssetRecord.value) {
+    resolvedAccounts.newAssetRecord.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          97, 115, 115, 101, 116, 95, 114, 101, 99, 111, 114, 100,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.newAsset.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.feeAccount.value) {
+    resolvedAccounts.feeAccount.value = publicKey(
+      'CRumnxQ9i84X7pbmgCdSSMW6WJ7njUad3LgK3kFo11zG'
+    );
+  }
+  if (!resolvedAccounts.coreProgram.value) {
+    resolvedAccounts.coreProgram.value = context.programs.getPublicKey(
+      'coreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.coreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.slotHashes.value) {
+    resolvedAccounts.slotHashes.value = context.programs.getPublicKey(
+      'slotHashes',
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+    resolvedAccounts.slotHashes.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSplitAssetV1InstructionDataSerializer().serialize(
+    resolvedArgs as SplitAssetV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
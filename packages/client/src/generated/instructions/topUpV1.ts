@@ -0,0 +1,297 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TopUpV1InstructionAccounts = {
+  /** Fusion data account. */
+  fusionData: PublicKey | Pda;
+  /** Authority pda. */
+  authorityPda?: PublicKey | Pda;
+  /** Asset owner, transaction and rent payer. */
+  user: Signer;
+  /** Account of the asset. */
+  asset: PublicKey | Pda;
+  /** Asset record account. */
+  assetRecord?: PublicKey | Pda;
+  /** Collection account. */
+  collection: PublicKey | Pda;
+  /** Mint account of the token. */
+  tokenMint: PublicKey | Pda;
+  /** Token escrow pda ata account. */
+  escrowAtaPda?: PublicKey | Pda;
+  /** User ata account. */
+  userAta?: PublicKey | Pda;
+  /** Fee recipient account, optional. */
+  feeRecipient?: PublicKey | Pda;
+  /** Fee recipient ata account, optional. */
+  feeRecipientAta?: PublicKey | Pda;
+  /** Protocol fee account. */
+  feeAccount?: PublicKey | Pda;
+  /** Token program. */
+  tokenProgram?: PublicKey | Pda;
+  /** Associated Token program. */
+  associatedTokenProgram?: PublicKey | Pda;
+  /** MPL Core program. */
+  coreProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop program. */
+  logWrapper?: PublicKey | Pda;
+};
+
+// Data.
+export type TopUpV1InstructionData = {
+  discriminator: Uint8Array;
+  amount: bigint;
+};
+
+export type TopUpV1InstructionDataArgs = { amount: number | bigint };
+
+export function getTopUpV1InstructionDataSerializer(): Serializer<
+  TopUpV1InstructionDataArgs,
+  TopUpV1InstructionData
+> {
+  return mapSerializer<TopUpV1InstructionDataArgs, any, TopUpV1InstructionData>(
+    struct<TopUpV1InstructionData>(
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['amount', u64()],
+      ],
+      { description: 'TopUpV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([76, 133, 137, 67, 11, 210, 180, 123]),
+    })
+  ) as Serializer<TopUpV1InstructionDataArgs, TopUpV1InstructionData>;
+}
+
+// Args.
+export type TopUpV1InstructionArgs = TopUpV1InstructionDataArgs;
+
+// Instruction.
+export function topUpV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: TopUpV1InstructionAccounts & TopUpV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'tokenFusion',
+    'STFyNpLRuUnxko7TPNqNR1g1EapVj4AnXkAFy2TGbj3'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    fusionData: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.fusionData ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authorityPda ?? null,
+    },
+    user: { index: 2, isWritable: true as boolean, value: input.user ?? null },
+    asset: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    assetRecord: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.assetRecord ?? null,
+    },
+    collection: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    tokenMint: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.tokenMint ?? null,
+    },
+    escrowAtaPda: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.escrowAtaPda ?? null,
+    },
+    userAta: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.userAta ?? null,
+    },
+    feeRecipient: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.feeRecipient ?? null,
+    },
+    feeRecipientAta: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.feeRecipientAta ?? null,
+    },
+    feeAccount: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.feeAccount ?? null,
+    },
+    tokenProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    coreProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.coreProgram ?? null,
+    },
+    systemProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: TopUpV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([97, 117, 116, 104, 111, 114, 105, 116, 121])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.assetRecord.value) {
+    resolvedAccounts.assetRecord.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          97, 115, 115, 101, 116, 95, 114, 101, 99, 111, 114, 100,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.asset.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.escrowAtaPda.value) {
+    resolvedAccounts.escrowAtaPda.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+      owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+    });
+  }
+  if (!resolvedAccounts.userAta.value) {
+    resolvedAccounts.userAta.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.tokenMint.value),
+      owner: expectPublicKey(resolvedAccounts.user.value),
+    });
+  }
+  if (!resolvedAccounts.feeAccount.value) {
+    resolvedAccounts.feeAccount.value = publicKey(
+      'CRumnxQ9i84X7pbmgCdSSMW6WJ7njUad3LgK3kFo11zG'
+    );
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'tokenProgram',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'associatedTokenProgram',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.coreProgram.value) {
+    resolvedAccounts.coreProgram.value = context.programs.getPublicKey(
+      'coreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.coreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getTopUpV1InstructionDataSerializer().serialize(
+    resolvedArgs as TopUpV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -18,11 +18,13 @@ import {
   Serializer,
   bytes,
   mapSerializer,
+  publicKey as publicKeySerializer,
   struct,
 } from '@metaplex-foundation/umi/serializers';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
+  expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
 import {
@@ -37,9 +39,11 @@ import {
 // Accounts.
 export type UpdateV1InstructionAccounts = {
   /** Fusion data account. */
-  fusionData?: PublicKey | Pda;
+  fusionData: PublicKey | Pda;
   /** Current authority */
   authority?: Signer;
+  /** Metadata pool account, may be uninitialized. */
+  metadataPool?: PublicKey | Pda;
 };
 
 // Data.
@@ -104,22 +108,32 @@ export function updateV1(
       isWritable: true as boolean,
       value: input.authority ?? null,
     },
+    metadataPool: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.metadataPool ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
   const resolvedArgs: UpdateV1InstructionArgs = { ...input };
 
   // Default values.
-  if (!resolvedAccounts.fusionData.value) {
-    resolvedAccounts.fusionData.value = context.eddsa.findPda(programId, [
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.metadataPool.value) {
+    resolvedAccounts.metadataPool.value = context.eddsa.findPda(programId, [
       bytes().serialize(
-        new Uint8Array([102, 117, 115, 105, 111, 110, 95, 100, 97, 116, 97])
+        new Uint8Array([
+          109, 101, 116, 97, 100, 97, 116, 97, 95, 112, 111, 111, 108,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.fusionData.value)
       ),
     ]);
   }
-  if (!resolvedAccounts.authority.value) {
-    resolvedAccounts.authority.value = context.identity;
-  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
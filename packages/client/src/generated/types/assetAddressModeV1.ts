@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** Asset Address Mode Enum */
+export enum AssetAddressModeV1 {
+  Keypair,
+  Pda,
+}
+
+export type AssetAddressModeV1Args = AssetAddressModeV1;
+
+export function getAssetAddressModeV1Serializer(): Serializer<
+  AssetAddressModeV1Args,
+  AssetAddressModeV1
+> {
+  return scalarEnum<AssetAddressModeV1>(AssetAddressModeV1, {
+    description: 'AssetAddressModeV1',
+  }) as Serializer<AssetAddressModeV1Args, AssetAddressModeV1>;
+}
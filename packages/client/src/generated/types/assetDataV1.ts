@@ -9,12 +9,35 @@
 import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  array,
+  bool,
   option,
   string,
   struct,
   u32,
   u64,
+  u8,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  AssetAddressModeV1,
+  AssetAddressModeV1Args,
+  AssetHashModeV1,
+  AssetHashModeV1Args,
+  AssetOutputModeV1,
+  AssetOutputModeV1Args,
+  AssetRedeemModeV1,
+  AssetRedeemModeV1Args,
+  MetadataModeV1,
+  MetadataModeV1Args,
+  PluginDataV1,
+  PluginDataV1Args,
+  getAssetAddressModeV1Serializer,
+  getAssetHashModeV1Serializer,
+  getAssetOutputModeV1Serializer,
+  getAssetRedeemModeV1Serializer,
+  getMetadataModeV1Serializer,
+  getPluginDataV1Serializer,
+} from '.';
 
 /** Asset Data Struct */
 export type AssetDataV1 = {
@@ -22,12 +45,43 @@ export type AssetDataV1 = {
   maxSupply: Option<number>;
   /** Asset next index to mint */
   nextIndex: bigint;
-  /** Asset name prefix, i.e. `Token #`, max 10 symbols */
+  /** Asset name prefix, i.e. `Token #`, max 32 symbols */
   namePrefix: string;
   /** Asset URI prefix, i.e. `https://stf.org/metadata/` with trailing `/`, max 200 symbols */
   uriPrefix: string;
   /** Asset URI suffix, i.e. `.json`, max 5 symbols */
   uriSuffix: string;
+  /** How the address of a new asset is derived */
+  addressMode: AssetAddressModeV1;
+  /** Plugins applied to every minted asset */
+  plugins: PluginDataV1;
+  /** How the metadata index of a new asset is assigned */
+  metadataMode: MetadataModeV1;
+  /** Optional placeholder URI for all minted assets until revealed, max 200 symbols */
+  placeholderUri: Option<string>;
+  /**
+   * Optional asset name template, i.e. `Token #{index:05}`, overrides the name prefix,
+   * supports `{index}`, `{index:0N}`, `{hash}`, `{collection}` and `{bracket}` placeholders,
+   * max 64 symbols
+   */
+
+  nameTemplate: Option<string>;
+  /**
+   * Optional asset URI template, i.e. `https://stf.org/{collection}/{index}.json?h={hash}`,
+   * overrides the uri prefix and suffix, supports the same placeholders, max 200 symbols
+   */
+
+  uriTemplate: Option<string>;
+  /** How the asset hash in uris is computed */
+  hashMode: AssetHashModeV1;
+  /** Use the full hash instead of the short 8 symbols one */
+  fullHash: boolean;
+  /** Salt revealed by the authority, must match the commitment of the salted hash mode */
+  hashSalt: Option<Array<number>>;
+  /** Whether assets are minted as Core assets or as compressed leaves */
+  outputMode: AssetOutputModeV1;
+  /** What happens to an asset fused back into tokens */
+  redeemMode: AssetRedeemModeV1;
 };
 
 export type AssetDataV1Args = {
@@ -35,12 +89,43 @@ export type AssetDataV1Args = {
   maxSupply: OptionOrNullable<number>;
   /** Asset next index to mint */
   nextIndex: number | bigint;
-  /** Asset name prefix, i.e. `Token #`, max 10 symbols */
+  /** Asset name prefix, i.e. `Token #`, max 32 symbols */
   namePrefix: string;
   /** Asset URI prefix, i.e. `https://stf.org/metadata/` with trailing `/`, max 200 symbols */
   uriPrefix: string;
   /** Asset URI suffix, i.e. `.json`, max 5 symbols */
   uriSuffix: string;
+  /** How the address of a new asset is derived */
+  addressMode: AssetAddressModeV1Args;
+  /** Plugins applied to every minted asset */
+  plugins: PluginDataV1Args;
+  /** How the metadata index of a new asset is assigned */
+  metadataMode: MetadataModeV1Args;
+  /** Optional placeholder URI for all minted assets until revealed, max 200 symbols */
+  placeholderUri: OptionOrNullable<string>;
+  /**
+   * Optional asset name template, i.e. `Token #{index:05}`, overrides the name prefix,
+   * supports `{index}`, `{index:0N}`, `{hash}`, `{collection}` and `{bracket}` placeholders,
+   * max 64 symbols
+   */
+
+  nameTemplate: OptionOrNullable<string>;
+  /**
+   * Optional asset URI template, i.e. `https://stf.org/{collection}/{index}.json?h={hash}`,
+   * overrides the uri prefix and suffix, supports the same placeholders, max 200 symbols
+   */
+
+  uriTemplate: OptionOrNullable<string>;
+  /** How the asset hash in uris is computed */
+  hashMode: AssetHashModeV1Args;
+  /** Use the full hash instead of the short 8 symbols one */
+  fullHash: boolean;
+  /** Salt revealed by the authority, must match the commitment of the salted hash mode */
+  hashSalt: OptionOrNullable<Array<number>>;
+  /** Whether assets are minted as Core assets or as compressed leaves */
+  outputMode: AssetOutputModeV1Args;
+  /** What happens to an asset fused back into tokens */
+  redeemMode: AssetRedeemModeV1Args;
 };
 
 export function getAssetDataV1Serializer(): Serializer<
@@ -54,6 +139,17 @@ export function getAssetDataV1Serializer(): Serializer<
       ['namePrefix', string()],
       ['uriPrefix', string()],
       ['uriSuffix', string()],
+      ['addressMode', getAssetAddressModeV1Serializer()],
+      ['plugins', getPluginDataV1Serializer()],
+      ['metadataMode', getMetadataModeV1Serializer()],
+      ['placeholderUri', option(string())],
+      ['nameTemplate', option(string())],
+      ['uriTemplate', option(string())],
+      ['hashMode', getAssetHashModeV1Serializer()],
+      ['fullHash', bool()],
+      ['hashSalt', option(array(u8(), { size: 32 }))],
+      ['outputMode', getAssetOutputModeV1Serializer()],
+      ['redeemMode', getAssetRedeemModeV1Serializer()],
     ],
     { description: 'AssetDataV1' }
   ) as Serializer<AssetDataV1Args, AssetDataV1>;
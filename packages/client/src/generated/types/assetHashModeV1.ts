@@ -0,0 +1,76 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  array,
+  dataEnum,
+  struct,
+  u8,
+  unit,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Asset Hash Mode Enum */
+export type AssetHashModeV1 =
+  | { __kind: 'Legacy' }
+  | { __kind: 'None' }
+  | { __kind: 'Public' }
+  | { __kind: 'Salted'; commitment: Array<number> };
+
+export type AssetHashModeV1Args = AssetHashModeV1;
+
+export function getAssetHashModeV1Serializer(): Serializer<
+  AssetHashModeV1Args,
+  AssetHashModeV1
+> {
+  return dataEnum<AssetHashModeV1>(
+    [
+      ['Legacy', unit()],
+      ['None', unit()],
+      ['Public', unit()],
+      [
+        'Salted',
+        struct<GetDataEnumKindContent<AssetHashModeV1, 'Salted'>>([
+          ['commitment', array(u8(), { size: 32 })],
+        ]),
+      ],
+    ],
+    { description: 'AssetHashModeV1' }
+  ) as Serializer<AssetHashModeV1Args, AssetHashModeV1>;
+}
+
+// Data Enum Helpers.
+export function assetHashModeV1(
+  kind: 'Legacy'
+): GetDataEnumKind<AssetHashModeV1Args, 'Legacy'>;
+export function assetHashModeV1(
+  kind: 'None'
+): GetDataEnumKind<AssetHashModeV1Args, 'None'>;
+export function assetHashModeV1(
+  kind: 'Public'
+): GetDataEnumKind<AssetHashModeV1Args, 'Public'>;
+export function assetHashModeV1(
+  kind: 'Salted',
+  data: GetDataEnumKindContent<AssetHashModeV1Args, 'Salted'>
+): GetDataEnumKind<AssetHashModeV1Args, 'Salted'>;
+export function assetHashModeV1<K extends AssetHashModeV1Args['__kind']>(
+  kind: K,
+  data?: any
+): Extract<AssetHashModeV1Args, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isAssetHashModeV1<K extends AssetHashModeV1['__kind']>(
+  kind: K,
+  value: AssetHashModeV1
+): value is AssetHashModeV1 & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** Asset Output Mode Enum */
+export enum AssetOutputModeV1 {
+  Core,
+  Compressed,
+}
+
+export type AssetOutputModeV1Args = AssetOutputModeV1;
+
+export function getAssetOutputModeV1Serializer(): Serializer<
+  AssetOutputModeV1Args,
+  AssetOutputModeV1
+> {
+  return scalarEnum<AssetOutputModeV1>(AssetOutputModeV1, {
+    description: 'AssetOutputModeV1',
+  }) as Serializer<AssetOutputModeV1Args, AssetOutputModeV1>;
+}
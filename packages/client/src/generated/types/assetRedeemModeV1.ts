@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** Asset Redeem Mode Enum */
+export enum AssetRedeemModeV1 {
+  Burn,
+  Vault,
+}
+
+export type AssetRedeemModeV1Args = AssetRedeemModeV1;
+
+export function getAssetRedeemModeV1Serializer(): Serializer<
+  AssetRedeemModeV1Args,
+  AssetRedeemModeV1
+> {
+  return scalarEnum<AssetRedeemModeV1>(AssetRedeemModeV1, {
+    description: 'AssetRedeemModeV1',
+  }) as Serializer<AssetRedeemModeV1Args, AssetRedeemModeV1>;
+}
@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Asset Update Struct */
+export type AssetUpdateV1 = {
+  /** Explicit asset name, overrides the asset data scheme */
+  name: Option<string>;
+  /** Explicit asset uri, overrides the asset data scheme */
+  uri: Option<string>;
+};
+
+export type AssetUpdateV1Args = {
+  /** Explicit asset name, overrides the asset data scheme */
+  name: OptionOrNullable<string>;
+  /** Explicit asset uri, overrides the asset data scheme */
+  uri: OptionOrNullable<string>;
+};
+
+export function getAssetUpdateV1Serializer(): Serializer<
+  AssetUpdateV1Args,
+  AssetUpdateV1
+> {
+  return struct<AssetUpdateV1>(
+    [
+      ['name', option(string())],
+      ['uri', option(string())],
+    ],
+    { description: 'AssetUpdateV1' }
+  ) as Serializer<AssetUpdateV1Args, AssetUpdateV1>;
+}
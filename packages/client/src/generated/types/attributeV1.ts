@@ -0,0 +1,36 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Attribute Struct */
+export type AttributeV1 = {
+  /** Attribute key */
+  key: string;
+  /** Attribute value */
+  value: string;
+};
+
+export type AttributeV1Args = AttributeV1;
+
+export function getAttributeV1Serializer(): Serializer<
+  AttributeV1Args,
+  AttributeV1
+> {
+  return struct<AttributeV1>(
+    [
+      ['key', string()],
+      ['value', string()],
+    ],
+    { description: 'AttributeV1' }
+  ) as Serializer<AttributeV1Args, AttributeV1>;
+}
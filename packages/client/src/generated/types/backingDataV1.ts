@@ -0,0 +1,66 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Backing Data Struct, variable amount fused into a single asset */
+export type BackingDataV1 = {
+  /** Min amount of spl tokens backing an asset */
+  minAmount: bigint;
+  /** Max amount of spl tokens backing an asset */
+  maxAmount: bigint;
+  /**
+   * Ascending lower bounds of the amount brackets past the first one,
+   * i.e. `[1000, 10000]` for the `0`, `1` and `2` brackets, max 8 bounds
+   */
+
+  brackets: Array<bigint>;
+  /** Min amount of spl tokens left backing an asset after a partial withdrawal */
+  minRemaining: bigint;
+  /** Number of variable backed assets currently in circulation, managed by the program */
+  supply: number;
+};
+
+export type BackingDataV1Args = {
+  /** Min amount of spl tokens backing an asset */
+  minAmount: number | bigint;
+  /** Max amount of spl tokens backing an asset */
+  maxAmount: number | bigint;
+  /**
+   * Ascending lower bounds of the amount brackets past the first one,
+   * i.e. `[1000, 10000]` for the `0`, `1` and `2` brackets, max 8 bounds
+   */
+
+  brackets: Array<number | bigint>;
+  /** Min amount of spl tokens left backing an asset after a partial withdrawal */
+  minRemaining: number | bigint;
+  /** Number of variable backed assets currently in circulation, managed by the program */
+  supply: number;
+};
+
+export function getBackingDataV1Serializer(): Serializer<
+  BackingDataV1Args,
+  BackingDataV1
+> {
+  return struct<BackingDataV1>(
+    [
+      ['minAmount', u64()],
+      ['maxAmount', u64()],
+      ['brackets', array(u64())],
+      ['minRemaining', u64()],
+      ['supply', u32()],
+    ],
+    { description: 'BackingDataV1' }
+  ) as Serializer<BackingDataV1Args, BackingDataV1>;
+}
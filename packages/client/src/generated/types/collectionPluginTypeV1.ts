@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** Collection Plugin Type Enum */
+export enum CollectionPluginTypeV1 {
+  Royalties,
+  Attributes,
+}
+
+export type CollectionPluginTypeV1Args = CollectionPluginTypeV1;
+
+export function getCollectionPluginTypeV1Serializer(): Serializer<
+  CollectionPluginTypeV1Args,
+  CollectionPluginTypeV1
+> {
+  return scalarEnum<CollectionPluginTypeV1>(CollectionPluginTypeV1, {
+    description: 'CollectionPluginTypeV1',
+  }) as Serializer<CollectionPluginTypeV1Args, CollectionPluginTypeV1>;
+}
@@ -0,0 +1,81 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  array,
+  dataEnum,
+  struct,
+  tuple,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  AttributeV1,
+  AttributeV1Args,
+  RoyaltiesV1,
+  RoyaltiesV1Args,
+  getAttributeV1Serializer,
+  getRoyaltiesV1Serializer,
+} from '.';
+
+/** Collection Plugin Enum, plugins managed by the fusion authority on the collection */
+export type CollectionPluginV1 =
+  | { __kind: 'Royalties'; fields: [RoyaltiesV1] }
+  | { __kind: 'Attributes'; fields: [Array<AttributeV1>] };
+
+export type CollectionPluginV1Args =
+  | { __kind: 'Royalties'; fields: [RoyaltiesV1Args] }
+  | { __kind: 'Attributes'; fields: [Array<AttributeV1Args>] };
+
+export function getCollectionPluginV1Serializer(): Serializer<
+  CollectionPluginV1Args,
+  CollectionPluginV1
+> {
+  return dataEnum<CollectionPluginV1>(
+    [
+      [
+        'Royalties',
+        struct<GetDataEnumKindContent<CollectionPluginV1, 'Royalties'>>([
+          ['fields', tuple([getRoyaltiesV1Serializer()])],
+        ]),
+      ],
+      [
+        'Attributes',
+        struct<GetDataEnumKindContent<CollectionPluginV1, 'Attributes'>>([
+          ['fields', tuple([array(getAttributeV1Serializer())])],
+        ]),
+      ],
+    ],
+    { description: 'CollectionPluginV1' }
+  ) as Serializer<CollectionPluginV1Args, CollectionPluginV1>;
+}
+
+// Data Enum Helpers.
+export function collectionPluginV1(
+  kind: 'Royalties',
+  data: GetDataEnumKindContent<CollectionPluginV1Args, 'Royalties'>['fields']
+): GetDataEnumKind<CollectionPluginV1Args, 'Royalties'>;
+export function collectionPluginV1(
+  kind: 'Attributes',
+  data: GetDataEnumKindContent<CollectionPluginV1Args, 'Attributes'>['fields']
+): GetDataEnumKind<CollectionPluginV1Args, 'Attributes'>;
+export function collectionPluginV1<K extends CollectionPluginV1Args['__kind']>(
+  kind: K,
+  data?: any
+): Extract<CollectionPluginV1Args, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isCollectionPluginV1<K extends CollectionPluginV1['__kind']>(
+  kind: K,
+  value: CollectionPluginV1
+): value is CollectionPluginV1 & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,59 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Compressed leaf to burn, as returned by the DAS asset proof */
+export type CompressedLeafV1 = {
+  /** Current root of the merkle tree */
+  root: Array<number>;
+  /** Hash of the leaf metadata */
+  dataHash: Array<number>;
+  /** Hash of the leaf creators */
+  creatorHash: Array<number>;
+  /** Leaf nonce */
+  nonce: bigint;
+  /** Leaf index in the merkle tree */
+  index: number;
+};
+
+export type CompressedLeafV1Args = {
+  /** Current root of the merkle tree */
+  root: Array<number>;
+  /** Hash of the leaf metadata */
+  dataHash: Array<number>;
+  /** Hash of the leaf creators */
+  creatorHash: Array<number>;
+  /** Leaf nonce */
+  nonce: number | bigint;
+  /** Leaf index in the merkle tree */
+  index: number;
+};
+
+export function getCompressedLeafV1Serializer(): Serializer<
+  CompressedLeafV1Args,
+  CompressedLeafV1
+> {
+  return struct<CompressedLeafV1>(
+    [
+      ['root', array(u8(), { size: 32 })],
+      ['dataHash', array(u8(), { size: 32 })],
+      ['creatorHash', array(u8(), { size: 32 })],
+      ['nonce', u64()],
+      ['index', u32()],
+    ],
+    { description: 'CompressedLeafV1' }
+  ) as Serializer<CompressedLeafV1Args, CompressedLeafV1>;
+}
@@ -0,0 +1,35 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Royalties Creator Struct */
+export type CreatorV1 = {
+  /** Creator address */
+  address: PublicKey;
+  /** Creator share of the royalties in percents */
+  percentage: number;
+};
+
+export type CreatorV1Args = CreatorV1;
+
+export function getCreatorV1Serializer(): Serializer<CreatorV1Args, CreatorV1> {
+  return struct<CreatorV1>(
+    [
+      ['address', publicKeySerializer()],
+      ['percentage', u8()],
+    ],
+    { description: 'CreatorV1' }
+  ) as Serializer<CreatorV1Args, CreatorV1>;
+}
@@ -0,0 +1,49 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Evolution Data Struct */
+export type EvolutionDataV1 = {
+  /** Fusion data account of the higher tier collection */
+  target: PublicKey;
+  /** How many assets are burned to evolve into one asset of the target collection */
+  burnCount: number;
+  /** Max SPL amount taken from the user to cover the target escrow amount */
+  tokenAmount: bigint;
+};
+
+export type EvolutionDataV1Args = {
+  /** Fusion data account of the higher tier collection */
+  target: PublicKey;
+  /** How many assets are burned to evolve into one asset of the target collection */
+  burnCount: number;
+  /** Max SPL amount taken from the user to cover the target escrow amount */
+  tokenAmount: number | bigint;
+};
+
+export function getEvolutionDataV1Serializer(): Serializer<
+  EvolutionDataV1Args,
+  EvolutionDataV1
+> {
+  return struct<EvolutionDataV1>(
+    [
+      ['target', publicKeySerializer()],
+      ['burnCount', u8()],
+      ['tokenAmount', u64()],
+    ],
+    { description: 'EvolutionDataV1' }
+  ) as Serializer<EvolutionDataV1Args, EvolutionDataV1>;
+}
@@ -6,7 +6,24 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './assetAddressModeV1';
 export * from './assetDataV1';
+export * from './assetHashModeV1';
+export * from './assetOutputModeV1';
+export * from './assetRedeemModeV1';
+export * from './assetUpdateV1';
+export * from './attributeV1';
+export * from './backingDataV1';
+export * from './collectionPluginTypeV1';
+export * from './collectionPluginV1';
+export * from './compressedLeafV1';
+export * from './creatorV1';
+export * from './evolutionDataV1';
 export * from './feeDataV1';
+export * from './ingredientV1';
 export * from './key';
+export * from './metadataModeV1';
+export * from './pluginDataV1';
+export * from './royaltiesV1';
+export * from './tierV1';
 export * from './updateAuthority';
@@ -0,0 +1,53 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Ingredient Struct, an additional token of the fusion recipe */
+export type IngredientV1 = {
+  /** Token mint */
+  mint: PublicKey;
+  /** How many spl tokens can be recovered from the asset */
+  escrowAmount: bigint;
+  /** SPL fee, when fusing SPL into asset, take this SPL amount from user */
+  feeAmount: bigint;
+  /** SPL fee burned, when fusing SPL into asset, burn this SPL amount */
+  burnAmount: bigint;
+};
+
+export type IngredientV1Args = {
+  /** Token mint */
+  mint: PublicKey;
+  /** How many spl tokens can be recovered from the asset */
+  escrowAmount: number | bigint;
+  /** SPL fee, when fusing SPL into asset, take this SPL amount from user */
+  feeAmount: number | bigint;
+  /** SPL fee burned, when fusing SPL into asset, burn this SPL amount */
+  burnAmount: number | bigint;
+};
+
+export function getIngredientV1Serializer(): Serializer<
+  IngredientV1Args,
+  IngredientV1
+> {
+  return struct<IngredientV1>(
+    [
+      ['mint', publicKeySerializer()],
+      ['escrowAmount', u64()],
+      ['feeAmount', u64()],
+      ['burnAmount', u64()],
+    ],
+    { description: 'IngredientV1' }
+  ) as Serializer<IngredientV1Args, IngredientV1>;
+}
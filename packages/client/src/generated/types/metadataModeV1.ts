@@ -0,0 +1,27 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** Metadata Mode Enum */
+export enum MetadataModeV1 {
+  Sequential,
+  Random,
+  Recycled,
+}
+
+export type MetadataModeV1Args = MetadataModeV1;
+
+export function getMetadataModeV1Serializer(): Serializer<
+  MetadataModeV1Args,
+  MetadataModeV1
+> {
+  return scalarEnum<MetadataModeV1>(MetadataModeV1, {
+    description: 'MetadataModeV1',
+  }) as Serializer<MetadataModeV1Args, MetadataModeV1>;
+}
@@ -0,0 +1,54 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  option,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { RoyaltiesV1, RoyaltiesV1Args, getRoyaltiesV1Serializer } from '.';
+
+/** Plugin Data Struct, plugins applied to every minted asset */
+export type PluginDataV1 = {
+  /** Optional Royalties plugin */
+  royalties: Option<RoyaltiesV1>;
+  /** Attributes plugin with asset index, backing amount and mint timestamp */
+  attributes: boolean;
+  /** PermanentBurnDelegate plugin with the authority pda as delegate */
+  permanentBurnDelegate: boolean;
+  /** FreezeDelegate plugin with the authority pda as delegate */
+  freezeDelegate: boolean;
+};
+
+export type PluginDataV1Args = {
+  /** Optional Royalties plugin */
+  royalties: OptionOrNullable<RoyaltiesV1Args>;
+  /** Attributes plugin with asset index, backing amount and mint timestamp */
+  attributes: boolean;
+  /** PermanentBurnDelegate plugin with the authority pda as delegate */
+  permanentBurnDelegate: boolean;
+  /** FreezeDelegate plugin with the authority pda as delegate */
+  freezeDelegate: boolean;
+};
+
+export function getPluginDataV1Serializer(): Serializer<
+  PluginDataV1Args,
+  PluginDataV1
+> {
+  return struct<PluginDataV1>(
+    [
+      ['royalties', option(getRoyaltiesV1Serializer())],
+      ['attributes', bool()],
+      ['permanentBurnDelegate', bool()],
+      ['freezeDelegate', bool()],
+    ],
+    { description: 'PluginDataV1' }
+  ) as Serializer<PluginDataV1Args, PluginDataV1>;
+}
@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+import { CreatorV1, CreatorV1Args, getCreatorV1Serializer } from '.';
+
+/** Royalties Struct */
+export type RoyaltiesV1 = {
+  /** Royalties in basis points, i.e. `500` for 5% */
+  basisPoints: number;
+  /** Royalties creators, 1 to 4 creators with percentages adding up to 100 */
+  creators: Array<CreatorV1>;
+};
+
+export type RoyaltiesV1Args = {
+  /** Royalties in basis points, i.e. `500` for 5% */
+  basisPoints: number;
+  /** Royalties creators, 1 to 4 creators with percentages adding up to 100 */
+  creators: Array<CreatorV1Args>;
+};
+
+export function getRoyaltiesV1Serializer(): Serializer<
+  RoyaltiesV1Args,
+  RoyaltiesV1
+> {
+  return struct<RoyaltiesV1>(
+    [
+      ['basisPoints', u16()],
+      ['creators', array(getCreatorV1Serializer())],
+    ],
+    { description: 'RoyaltiesV1' }
+  ) as Serializer<RoyaltiesV1Args, RoyaltiesV1>;
+}
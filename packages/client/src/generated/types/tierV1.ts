@@ -0,0 +1,57 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  string,
+  struct,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+import { FeeDataV1, FeeDataV1Args, getFeeDataV1Serializer } from '.';
+
+/** Tier Struct, a denomination of the assets in the collection */
+export type TierV1 = {
+  /** Escrow amount and fees of the tier, replace the fusion fee data */
+  feeData: FeeDataV1;
+  /** Optional tier mint limit */
+  maxSupply: Option<number>;
+  /** Number of assets of the tier currently in circulation, managed by the program */
+  supply: number;
+  /** Optional asset name template of the tier, overrides the asset data name */
+  nameTemplate: Option<string>;
+  /** Optional asset URI template of the tier, overrides the asset data uri */
+  uriTemplate: Option<string>;
+};
+
+export type TierV1Args = {
+  /** Escrow amount and fees of the tier, replace the fusion fee data */
+  feeData: FeeDataV1Args;
+  /** Optional tier mint limit */
+  maxSupply: OptionOrNullable<number>;
+  /** Number of assets of the tier currently in circulation, managed by the program */
+  supply: number;
+  /** Optional asset name template of the tier, overrides the asset data name */
+  nameTemplate: OptionOrNullable<string>;
+  /** Optional asset URI template of the tier, overrides the asset data uri */
+  uriTemplate: OptionOrNullable<string>;
+};
+
+export function getTierV1Serializer(): Serializer<TierV1Args, TierV1> {
+  return struct<TierV1>(
+    [
+      ['feeData', getFeeDataV1Serializer()],
+      ['maxSupply', option(u32())],
+      ['supply', u32()],
+      ['nameTemplate', option(string())],
+      ['uriTemplate', option(string())],
+    ],
+    { description: 'TierV1' }
+  ) as Serializer<TierV1Args, TierV1>;
+}
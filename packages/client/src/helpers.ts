@@ -1,23 +1,86 @@
 import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
 import { Context, Pda, PublicKey } from '@metaplex-foundation/umi';
-import { string } from '@metaplex-foundation/umi/serializers';
+import { publicKey as publicKeySerializer, string, u64 } from '@metaplex-foundation/umi/serializers';
 
 import { getTokenFusionProgramId } from './generated';
 
-export function findFusionDataPda(context: Pick<Context, 'eddsa' | 'programs'>): Pda {
+export function findFusionDataPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  collection: PublicKey
+): Pda {
+  const programId = getTokenFusionProgramId(context);
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('fusion_data'),
+    publicKeySerializer().serialize(collection),
+  ]);
+}
+export function findFusionAuthorityPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  fusionData: PublicKey
+): Pda {
+  const programId = getTokenFusionProgramId(context);
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('authority'),
+    publicKeySerializer().serialize(fusionData),
+  ]);
+}
+
+export function findMetadataPoolPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  fusionData: PublicKey
+): Pda {
+  const programId = getTokenFusionProgramId(context);
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('metadata_pool'),
+    publicKeySerializer().serialize(fusionData),
+  ]);
+}
+
+export function findAssetPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  fusionData: PublicKey,
+  index: number | bigint
+): Pda {
+  const programId = getTokenFusionProgramId(context);
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('asset'),
+    publicKeySerializer().serialize(fusionData),
+    u64().serialize(index),
+  ]);
+}
+
+export function findAssetRecordPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  fusionData: PublicKey,
+  asset: PublicKey
+): Pda {
   const programId = getTokenFusionProgramId(context);
-  return context.eddsa.findPda(programId, [string({ size: 'variable' }).serialize('fusion_data')]);
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('asset_record'),
+    publicKeySerializer().serialize(fusionData),
+    publicKeySerializer().serialize(asset),
+  ]);
 }
-export function findFusionAuthorityPda(context: Pick<Context, 'eddsa' | 'programs'>): Pda {
+
+export function findAssetIndexPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  fusionData: PublicKey,
+  mintIndex: number | bigint
+): Pda {
   const programId = getTokenFusionProgramId(context);
-  return context.eddsa.findPda(programId, [string({ size: 'variable' }).serialize('authority')]);
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('asset_index'),
+    publicKeySerializer().serialize(fusionData),
+    u64().serialize(mintIndex),
+  ]);
 }
 
 export function findEscrowAtaPda(
   context: Pick<Context, 'eddsa' | 'programs'>,
+  fusionData: PublicKey,
   mint: PublicKey
 ): Pda {
-  const [authorityPda] = findFusionAuthorityPda(context);
+  const [authorityPda] = findFusionAuthorityPda(context, fusionData);
 
   return findAssociatedTokenPda(context, {
     mint,
@@ -7,6 +7,7 @@ declare global {
       NEXT_PUBLIC_SOLANA_MAINNET_RPC: string;
       NEXT_PUBLIC_APP_URL: string;
       NEXT_PUBLIC_APP_METADATA_URI: string;
+      NEXT_PUBLIC_FUSION_COLLECTION: string;
     }
   }
 }
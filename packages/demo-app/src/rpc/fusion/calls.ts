@@ -1,19 +1,40 @@
-import { PublicKey, Umi, generateSigner, transactionBuilder, unwrapOption } from '@metaplex-foundation/umi';
+import {
+  Option,
+  PublicKey,
+  Umi,
+  generateSigner,
+  none,
+  transactionBuilder,
+  unwrapOption,
+} from '@metaplex-foundation/umi';
 import { findAssociatedTokenPda, setComputeUnitPrice } from '@metaplex-foundation/mpl-toolbox';
 
-import { fusionIntoV1, fusionFromV1, FusionDataV1 } from '@stf/token-fusion';
+import {
+  fusionIntoV1,
+  fusionFromV1,
+  fetchAssetRecordV1,
+  findAssetIndexPda,
+  findAssetRecordPda,
+  findMetadataPoolPda,
+  FusionDataV1,
+  MetadataModeV1,
+} from '@stf/token-fusion';
 import { ClusterSettings } from '@/config';
 
 type IntoOpt = {
   data: FusionDataV1;
+  // required when the fusion has tiers
+  tier?: Option<number>;
+  // required when the fusion has a variable backing
+  amount?: Option<bigint>;
 };
 
-type FromOpt = IntoOpt & {
+type FromOpt = Omit<IntoOpt, 'tier' | 'amount'> & {
   asset: PublicKey;
 };
 
 // Spl Token -> Asset
-export const fusionInto = (umi: Umi, { data }: IntoOpt) => {
+export const fusionInto = (umi: Umi, { data, tier = none(), amount = none() }: IntoOpt) => {
   const asset = generateSigner(umi);
 
   const { priority, commitment } = ClusterSettings;
@@ -29,14 +50,25 @@ export const fusionInto = (umi: Umi, { data }: IntoOpt) => {
     ? findAssociatedTokenPda(umi, { mint: data.tokenMint, owner: feeRecipient })
     : [undefined];
 
+  // the metadata pool is only used by the random and recycled metadata modes
+  const [metadataPool] =
+    data.assetData.metadataMode === MetadataModeV1.Sequential
+      ? [undefined]
+      : findMetadataPoolPda(umi, data.publicKey);
+
   tb = tb.add(
     fusionIntoV1(umi, {
+      fusionData: data.publicKey,
       user: umi.identity,
       asset: asset,
       tokenMint: data.tokenMint,
       collection: data.collection,
       feeRecipient,
       feeRecipientAta,
+      metadataPool,
+      tier,
+      amount,
+      assetIndex: findAssetIndexPda(umi, data.publicKey, data.assetData.nextIndex),
     })
   );
 
@@ -44,7 +76,7 @@ export const fusionInto = (umi: Umi, { data }: IntoOpt) => {
 };
 
 // Asset -> Spl Token
-export const fusionFrom = (umi: Umi, { data, asset }: FromOpt) => {
+export const fusionFrom = async (umi: Umi, { data, asset }: FromOpt) => {
   const { priority, commitment } = ClusterSettings;
 
   let tb = transactionBuilder();
@@ -53,12 +85,20 @@ export const fusionFrom = (umi: Umi, { data, asset }: FromOpt) => {
     tb = tb.add(setComputeUnitPrice(umi, { microLamports: priority }));
   }
 
+  // the record keeps the mint index of the asset, key of its index account
+  const [assetRecord] = findAssetRecordPda(umi, data.publicKey, asset);
+  const record = await fetchAssetRecordV1(umi, assetRecord);
+  const [assetIndex] = findAssetIndexPda(umi, data.publicKey, record.mintIndex);
+
   tb = tb.add(
     fusionFromV1(umi, {
+      fusionData: data.publicKey,
       user: umi.identity,
       asset: asset,
       tokenMint: data.tokenMint,
       collection: data.collection,
+      assetRecord,
+      assetIndex,
     })
   );
 
@@ -5,6 +5,7 @@ import { PublicKey } from '@metaplex-foundation/umi';
 import { AssetV1, fetchCollectionV1 } from '@metaplex-foundation/mpl-core';
 import { fetchFusionDataV1, findFusionDataPda } from '@stf/token-fusion';
 
+import { FUSION_COLLECTION } from '@/config';
 import { useUmi } from '@/providers/useUmi';
 import { AssetMetadata } from '@/types';
 
@@ -14,7 +15,7 @@ export const useFusionData = () => {
   return useQuery({
     queryKey: ['fusionData'],
     queryFn: () => {
-      const [dataPda] = findFusionDataPda(umi);
+      const [dataPda] = findFusionDataPda(umi, FUSION_COLLECTION);
       return fetchFusionDataV1(umi, dataPda);
     },
   });
@@ -7,11 +7,51 @@ pub const EMPTY_STR: &str = "";
 pub const DATA_SEED: &str = "fusion_data";
 // Seed used to derive the authority PDA address.
 pub const AUTHORITY_SEED: &str = "authority";
+// Seed used to derive the asset PDA address.
+pub const ASSET_SEED: &str = "asset";
+// Seed used to derive the metadata pool PDA address.
+pub const METADATA_POOL_SEED: &str = "metadata_pool";
+// Seed used to derive the asset record PDA address.
+pub const RECORD_SEED: &str = "asset_record";
+// Seed used to derive the asset index PDA address.
+pub const INDEX_SEED: &str = "asset_index";
 
 // max strings length for assets
-pub const ASSET_NAME_PREFIX_MAX: usize = 10;
+pub const ASSET_NAME_PREFIX_MAX: usize = 32;
 pub const ASSET_URI_PREFIX_MAX: usize = 200;
 pub const ASSET_URI_SUFFIX_MAX: usize = 5;
+pub const ASSET_NAME_TEMPLATE_MAX: usize = 64;
+pub const ASSET_URI_TEMPLATE_MAX: usize = 200;
+
+// max length of the rendered asset name and uri
+pub const ASSET_NAME_MAX: usize = 64;
+pub const ASSET_URI_MAX: usize = 256;
+
+// length of the short and the full asset hash in uris
+pub const ASSET_HASH_SHORT_LEN: usize = 8;
+pub const ASSET_HASH_FULL_LEN: usize = 44;
+
+// max metadata indexes in the pool, the bitmap has to fit into a single account allocation
+pub const METADATA_POOL_MAX_SIZE: u32 = 80_000;
+
+// max creators in the royalties plugin
+pub const ROYALTIES_CREATORS_MAX: usize = 4;
+pub const MAX_BASIS_POINTS: u16 = 10000;
+
+// max additional ingredient tokens of a fusion
+pub const INGREDIENTS_MAX: usize = 4;
+
+// max denomination tiers of a fusion
+pub const TIERS_MAX: usize = 4;
+
+// max amount brackets of the variable backing
+pub const BRACKETS_MAX: usize = 8;
+
+// asset attribute keys
+pub const ATTRIBUTE_INDEX: &str = "index";
+pub const ATTRIBUTE_BACKING: &str = "backing";
+pub const ATTRIBUTE_MINTED_AT: &str = "minted_at";
+pub const ATTRIBUTE_TIER: &str = "tier";
 
 pub const SET: u8 = 1;
 pub const UNSET: u8 = 0;
@@ -91,4 +91,91 @@ pub enum FusionError {
 
     #[msg("Invalid protocol fee wallet")]
     InvalidProtocolFeeWallet,
+
+    #[msg("Invalid asset address")]
+    InvalidAssetAddress,
+
+    #[msg("Asset account must be a signer")]
+    AssetMustBeSigner,
+
+    #[msg("Invalid royalties")]
+    InvalidRoyalties,
+
+    #[msg("Missing metadata pool account")]
+    MissingMetadataPool,
+
+    #[msg("Missing slot hashes sysvar account")]
+    MissingSlotHashesSysvar,
+
+    #[msg("Invalid metadata pool size")]
+    InvalidMetadataPoolSize,
+
+    #[msg("Remaining accounts do not match the instruction arguments")]
+    InvalidRemainingAccounts,
+
+    #[msg("Invalid asset name or uri template")]
+    InvalidTemplate,
+
+    #[msg("Hash salt does not match the commitment")]
+    InvalidHashSalt,
+
+    #[msg("Hash salt is not revealed")]
+    HashSaltNotRevealed,
+
+    #[msg("Instruction does not match the asset output mode")]
+    InvalidOutputMode,
+
+    #[msg("Merkle tree does not match the fusion merkle tree")]
+    InvalidMerkleTree,
+
+    #[msg("Asset is not in the vault")]
+    AssetNotInVault,
+
+    #[msg("Invalid evolution link")]
+    InvalidEvolution,
+
+    #[msg("Escrow amount does not cover the backing of the new asset")]
+    InsufficientBacking,
+
+    #[msg("Invalid ingredients")]
+    InvalidIngredients,
+
+    #[msg("Invalid tier")]
+    InvalidTier,
+
+    #[msg("Asset record does not match the asset")]
+    InvalidAssetRecord,
+
+    #[msg("Invalid variable backing")]
+    InvalidBacking,
+
+    #[msg("Recipient and recipient ata must be set together")]
+    InvalidRecipient,
+
+    #[msg("User is neither the owner nor the delegate of the token account")]
+    InvalidTokenAuthority,
+
+    #[msg("User is neither the owner nor a delegate of the asset")]
+    InvalidAssetAuthority,
+
+    #[msg("Asset index does not match the mint index")]
+    InvalidAssetIndex,
+
+    #[msg("Recycled metadata mode requires core assets redeemed by burning")]
+    InvalidMetadataMode,
+
+    #[msg("Royalties require at least one creator")]
+    MissingCreators,
+
+    #[msg("Max supply can not change once the metadata pool is initialized")]
+    MaxSupplyLocked,
+
+    #[msg("Legacy fusion data does not match the accounts")]
+    InvalidLegacyFusion,
+
+    #[msg("Metadata mode can not change once assets were minted with the metadata pool")]
+    MetadataModeLocked,
+
+    #[msg("Asset modes can not change while assets are vaulted or compressed")]
+    AssetModeLocked,
 }
@@ -8,8 +8,9 @@ use mpl_core::ID as CORE_PROGRAM_ID;
 
 use crate::{
     constants::{AUTHORITY_SEED, DATA_SEED},
+    errors::FusionError,
     utils::{
-        revoke_collection_authority_v1, CollectionPluginAuthorityV1Accounts,
+        cmp_pubkeys, revoke_collection_authority_v1, CollectionPluginAuthorityV1Accounts,
         RevokeCollectionPluginAuthorityV1Args,
     },
     FusionDataV1,
@@ -23,7 +24,9 @@ pub(crate) struct SplTokenAccounts<'info> {
     pub spl_token_program: AccountInfo<'info>,
 }
 
-pub fn handler_destroy_v1(ctx: Context<DestroyV1Ctx>) -> Result<()> {
+pub fn handler_destroy_v1<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DestroyV1Ctx<'info>>,
+) -> Result<()> {
     let token_accounts = SplTokenAccounts {
         authority: ctx.accounts.authority.to_account_info(),
         authority_pda: ctx.accounts.authority_pda.to_account_info(),
@@ -32,7 +35,52 @@ pub fn handler_destroy_v1(ctx: Context<DestroyV1Ctx>) -> Result<()> {
         spl_token_program: ctx.accounts.token_program.to_account_info(),
     };
 
-    process_transfer(token_accounts, ctx.bumps.authority_pda)?;
+    process_transfer(
+        token_accounts,
+        &ctx.accounts.fusion_data.key(),
+        ctx.bumps.authority_pda,
+    )?;
+
+    // ingredient escrows, `[escrow ata, authority token account]` per ingredient
+    let ingredients = &ctx.accounts.fusion_data.ingredients;
+    require!(
+        ctx.remaining_accounts.len() == ingredients.len() * 2,
+        FusionError::InvalidRemainingAccounts
+    );
+
+    for (ingredient, ingredient_accounts) in ingredients
+        .iter()
+        .zip(ctx.remaining_accounts.chunks_exact(2))
+    {
+        let from = Account::<TokenAccount>::try_from(&ingredient_accounts[0])?;
+        let to = Account::<TokenAccount>::try_from(&ingredient_accounts[1])?;
+
+        // escrow of the ingredient mint, owned by the authority pda
+        require!(
+            cmp_pubkeys(&from.mint, &ingredient.mint)
+                && cmp_pubkeys(&from.owner, &ctx.accounts.authority_pda.key()),
+            FusionError::InvalidIngredients
+        );
+        require!(
+            cmp_pubkeys(&to.mint, &ingredient.mint)
+                && cmp_pubkeys(&to.owner, &ctx.accounts.authority.key()),
+            FusionError::InvalidIngredients
+        );
+
+        let token_accounts = SplTokenAccounts {
+            authority: ctx.accounts.authority.to_account_info(),
+            authority_pda: ctx.accounts.authority_pda.to_account_info(),
+            from,
+            to,
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+        };
+
+        process_transfer(
+            token_accounts,
+            &ctx.accounts.fusion_data.key(),
+            ctx.bumps.authority_pda,
+        )?;
+    }
 
     // revoke program delegate
     let revoke_accounts = CollectionPluginAuthorityV1Accounts {
@@ -55,12 +103,20 @@ pub fn handler_destroy_v1(ctx: Context<DestroyV1Ctx>) -> Result<()> {
     revoke_collection_authority_v1(
         revoke_accounts,
         revoke_args,
-        [AUTHORITY_SEED.as_bytes(), &[ctx.bumps.authority_pda]],
+        [
+            AUTHORITY_SEED.as_bytes(),
+            ctx.accounts.fusion_data.key().as_ref(),
+            &[ctx.bumps.authority_pda],
+        ],
     )
 }
 
-pub(crate) fn process_transfer(accounts: SplTokenAccounts, bump: u8) -> Result<()> {
-    let authority_seeds = &[AUTHORITY_SEED.as_bytes(), &[bump]];
+pub(crate) fn process_transfer(
+    accounts: SplTokenAccounts,
+    fusion: &Pubkey,
+    bump: u8,
+) -> Result<()> {
+    let authority_seeds = &[AUTHORITY_SEED.as_bytes(), fusion.as_ref(), &[bump]];
     let signer_seeds = &[&authority_seeds[..]];
 
     // get current balance
@@ -104,13 +160,13 @@ pub(crate) fn process_transfer(accounts: SplTokenAccounts, bump: u8) -> Result<(
 #[derive(Accounts)]
 pub struct DestroyV1Ctx<'info> {
     /// Fusion data account.
-    #[account(mut, close = authority, has_one = authority, seeds = [DATA_SEED.as_bytes()], bump)]
+    #[account(mut, close = authority, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
     fusion_data: Account<'info, FusionDataV1>,
 
     /// Authority PDA account.
     ///
     /// CHECK: account checked in seeds constraint
-    #[account(seeds = [AUTHORITY_SEED.as_bytes()], bump)]
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
     authority_pda: UncheckedAccount<'info>,
 
     /// Authority and payer of the transaction.
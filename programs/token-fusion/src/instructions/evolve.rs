@@ -0,0 +1,404 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::slot_hashes};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount, Transfer},
+};
+use mpl_core::{accounts::BaseCollectionV1, ID as CORE_PROGRAM_ID};
+
+use crate::{
+    constants::{
+        AUTHORITY_SEED, DATA_SEED, INDEX_SEED, METADATA_POOL_SEED, PROTOCOL_FEE,
+        PROTOCOL_FEE_WALLET, RECORD_SEED,
+    },
+    errors::FusionError,
+    instructions::{
+        fusion_from::{
+            process_asset_index, process_asset_record, process_burn, process_release_index,
+            process_transfer, FusionFromAccountsV1, FusionFromTokenAccountsV1,
+        },
+        fusion_into::{
+            process_metadata_index, process_mint, process_unvault, FusionIntoAccountsV1,
+        },
+    },
+    utils::{cmp_pubkeys, find_asset_record_address, sol_transfer},
+    AssetIndexV1, AssetOutputModeV1, AssetRecordV1, FusionDataV1, MetadataPoolV1,
+};
+
+pub fn handler_evolve_v1<'info>(ctx: Context<'_, '_, '_, 'info, EvolveV1Ctx<'info>>) -> Result<()> {
+    let source = ctx.accounts.source_fusion_data.as_mut();
+    let target = ctx.accounts.target_fusion_data.as_mut();
+
+    // link is checked in account constraints
+    let evolution = source
+        .evolution
+        .clone()
+        .ok_or(FusionError::InvalidEvolution)?;
+
+    require!(
+        target.asset_data.output_mode == AssetOutputModeV1::Core,
+        FusionError::InvalidOutputMode
+    );
+
+    // only the backing of the primary token is rolled over
+    require!(
+        source.ingredients.is_empty() && target.ingredients.is_empty(),
+        FusionError::InvalidIngredients
+    );
+
+    // assets are evolved with the fusion escrow amount
+    require!(
+        !source.has_recorded_backing() && !target.has_recorded_backing(),
+        FusionError::InvalidAssetRecord
+    );
+
+    // assets to burn are passed as remaining accounts along with their records and indexes
+    require!(
+        ctx.remaining_accounts.len() == evolution.burn_count as usize * 3,
+        FusionError::InvalidRemainingAccounts
+    );
+
+    // (1) burn the source assets
+    let source_key = source.key();
+    let mut rollover_amount: u64 = 0;
+
+    for asset_accounts in ctx.remaining_accounts.chunks_exact(3) {
+        let (asset, asset_record, asset_index) =
+            (&asset_accounts[0], &asset_accounts[1], &asset_accounts[2]);
+
+        // only assets minted by the source fusion are evolved
+        let (asset_record_address, _) = find_asset_record_address(&source_key, asset.key);
+        if !cmp_pubkeys(asset_record.key, &asset_record_address) {
+            return err!(FusionError::InvalidAssetRecord);
+        }
+
+        let accounts = FusionFromAccountsV1 {
+            // payer
+            payer: ctx.accounts.user.to_account_info(),
+            // asset owner or delegate
+            authority: ctx.accounts.user.to_account_info(),
+            // program pda
+            authority_pda: ctx.accounts.source_authority_pda.to_account_info(),
+            // asset related accounts
+            asset: asset.to_account_info(),
+            collection: ctx.accounts.source_collection.to_account_info(),
+            // programs
+            core_program: ctx.accounts.core_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            log_wrapper: ctx
+                .accounts
+                .log_wrapper
+                .as_ref()
+                .map(|log_wrapper| log_wrapper.to_account_info()),
+        };
+
+        process_burn(source, &accounts)?;
+
+        let record = process_asset_record(
+            source,
+            asset_record.to_account_info(),
+            Some(ctx.accounts.user.to_account_info()),
+        )?;
+
+        process_asset_index(
+            source,
+            asset_index.to_account_info(),
+            asset.key,
+            record.mint_index,
+            ctx.accounts.user.to_account_info(),
+        )?;
+
+        process_release_index(
+            source,
+            ctx.accounts.source_metadata_pool.as_deref_mut(),
+            record.index,
+        )?;
+
+        rollover_amount = rollover_amount
+            .checked_add(record.backing_amount(source.fee_data.escrow_amount))
+            .ok_or(FusionError::NumericalOverflowError)?;
+    }
+
+    // (2) roll the backing of the burned assets over into the target escrow,
+    // the target asset is backed by exactly the target escrow amount
+    let escrow_amount = target.fee_data.escrow_amount;
+    let rolled_over_amount = rollover_amount.min(escrow_amount);
+    let user_amount = escrow_amount - rolled_over_amount;
+
+    require!(
+        user_amount <= evolution.token_amount,
+        FusionError::InsufficientBacking
+    );
+
+    let token_accounts = FusionFromTokenAccountsV1 {
+        // program pda
+        authority_pda: ctx.accounts.source_authority_pda.to_account_info(),
+        // token related accounts
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        from: ctx.accounts.source_escrow_ata_pda.to_account_info(),
+        to: ctx.accounts.target_escrow_ata_pda.to_account_info(),
+        // programs
+        token_program: ctx.accounts.token_program.to_account_info(),
+        // no additional ingredients
+        ingredients: vec![],
+    };
+
+    process_transfer(
+        source,
+        &token_accounts,
+        rolled_over_amount,
+        ctx.bumps.source_authority_pda,
+    )?;
+
+    // the rollover above the target escrow amount is refunded to the user
+    let refund_accounts = FusionFromTokenAccountsV1 {
+        to: ctx.accounts.user_ata.to_account_info(),
+        ..token_accounts
+    };
+
+    process_transfer(
+        source,
+        &refund_accounts,
+        rollover_amount - rolled_over_amount,
+        ctx.bumps.source_authority_pda,
+    )?;
+
+    // (3) the rest of the target escrow amount from the user
+    if user_amount > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_ata.to_account_info(),
+                to: ctx.accounts.target_escrow_ata_pda.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        );
+
+        anchor_spl::token::transfer(cpi_ctx, user_amount)?;
+        msg!("Escrow: {} SPL", user_amount);
+    }
+
+    // (4) mint the target asset
+    let accounts = FusionIntoAccountsV1 {
+        // payer
+        payer: ctx.accounts.user.to_account_info(),
+        // program pda
+        authority_pda: ctx.accounts.target_authority_pda.to_account_info(),
+        // asset related accounts
+        asset: ctx.accounts.asset.to_account_info(),
+        collection: ctx.accounts.target_collection.to_account_info(),
+        owner: ctx.accounts.user.to_account_info(),
+        // programs
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    // vaulted assets are handed out before any new asset is minted,
+    // they keep the record they were minted with
+    if target.vault_size > 0 {
+        require!(
+            ctx.accounts.asset_record.is_none() && ctx.accounts.vaulted_asset_record.is_some(),
+            FusionError::InvalidAssetRecord
+        );
+        require!(
+            ctx.accounts.asset_index.is_none(),
+            FusionError::InvalidAssetIndex
+        );
+        process_unvault(target, &accounts, ctx.bumps.target_authority_pda)?;
+    } else {
+        let index = process_metadata_index(
+            target,
+            ctx.accounts.metadata_pool.as_mut(),
+            ctx.accounts.slot_hashes.as_ref(),
+            &ctx.accounts.user.key(),
+        )?;
+
+        // plain record of the minted asset
+        let record = AssetRecordV1 {
+            tier: None,
+            amount: 0,
+            index,
+            mint_index: target.asset_data.next_index,
+        };
+
+        process_mint(
+            target,
+            &accounts,
+            index,
+            &record,
+            ctx.accounts
+                .asset_index
+                .as_deref_mut()
+                .ok_or(FusionError::InvalidAssetIndex)?,
+            &ctx.accounts.user.key(),
+            ctx.bumps.target_authority_pda,
+        )?;
+
+        ctx.accounts
+            .asset_record
+            .as_deref_mut()
+            .ok_or(FusionError::InvalidAssetRecord)?
+            .set_inner(record);
+    }
+
+    sol_transfer(
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.fee_account.to_account_info(),
+        PROTOCOL_FEE,
+    )?;
+
+    Ok(())
+}
+
+/// Evolve assets of a fusion into an asset of the linked higher tier fusion.
+#[derive(Accounts)]
+pub struct EvolveV1Ctx<'info> {
+    /// Source fusion data account.
+    #[account(
+        mut,
+        seeds = [DATA_SEED.as_bytes(), source_fusion_data.collection.as_ref()],
+        bump,
+        constraint = source_fusion_data
+            .evolution
+            .as_ref()
+            .map(|evolution| evolution.target) == Some(target_fusion_data.key()) @ FusionError::InvalidEvolution
+    )]
+    source_fusion_data: Box<Account<'info, FusionDataV1>>,
+
+    /// Source authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), source_fusion_data.key().as_ref()], bump)]
+    source_authority_pda: UncheckedAccount<'info>,
+
+    /// Source collection account.
+    #[account(mut, address = source_fusion_data.collection, owner = CORE_PROGRAM_ID)]
+    source_collection: Account<'info, BaseCollectionV1>,
+
+    /// Source token escrow pda ata account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = source_authority_pda
+    )]
+    source_escrow_ata_pda: Account<'info, TokenAccount>,
+
+    /// Target fusion data account.
+    #[account(mut, seeds = [DATA_SEED.as_bytes(), target_fusion_data.collection.as_ref()], bump)]
+    target_fusion_data: Box<Account<'info, FusionDataV1>>,
+
+    /// Target authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), target_fusion_data.key().as_ref()], bump)]
+    target_authority_pda: UncheckedAccount<'info>,
+
+    /// Target collection account.
+    #[account(mut, address = target_fusion_data.collection, owner = CORE_PROGRAM_ID)]
+    target_collection: Account<'info, BaseCollectionV1>,
+
+    /// Target token escrow pda ata account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = target_authority_pda
+    )]
+    target_escrow_ata_pda: Account<'info, TokenAccount>,
+
+    /// Owner of the source assets, transaction and rent payer.
+    #[account(mut)]
+    user: Signer<'info>,
+
+    /// Account of the target Asset. Will be initialized with the new asset.
+    /// Either a signer keypair or the asset PDA, depending on the address mode,
+    /// or any asset locked in the target vault while the vault is not empty.
+    /// CHECK: checked against the address mode in process_mint or in process_unvault
+    #[account(mut)]
+    asset: UncheckedAccount<'info>,
+
+    /// Mint account of the token, shared by both fusions.
+    #[account(
+        address = source_fusion_data.token_mint,
+        constraint = target_fusion_data.token_mint == token_mint.key() @ FusionError::TokenKeyMismatch
+    )]
+    token_mint: Account<'info, Mint>,
+
+    /// User ata account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = user
+    )]
+    user_ata: Account<'info, TokenAccount>,
+
+    /// Protocol fee account.
+    /// CHECK: checked by account constraint
+    #[account(
+        mut,
+        address = PROTOCOL_FEE_WALLET @ FusionError::InvalidProtocolFeeWallet
+    )]
+    fee_account: UncheckedAccount<'info>,
+
+    /// Token program.
+    token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    /// MPL Core program.
+    /// CHECK: checked by account constraint
+    #[account(address = CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// The SPL Noop program.
+    /// CHECK: Checked in mpl-core.
+    log_wrapper: Option<AccountInfo<'info>>,
+
+    /// Target metadata pool account, required in random and recycled metadata modes.
+    #[account(mut, seeds = [METADATA_POOL_SEED.as_bytes(), target_fusion_data.key().as_ref()], bump)]
+    metadata_pool: Option<Account<'info, MetadataPoolV1>>,
+
+    /// Slot hashes sysvar, required in random metadata mode.
+    /// CHECK: checked by account constraint
+    #[account(address = slot_hashes::ID)]
+    slot_hashes: Option<UncheckedAccount<'info>>,
+
+    /// Target asset record account, proves that the asset was minted by the program.
+    /// Required when minting.
+    #[account(
+        init,
+        payer = user,
+        space = 8 + AssetRecordV1::INIT_SPACE,
+        seeds = [RECORD_SEED.as_bytes(), target_fusion_data.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    asset_record: Option<Box<Account<'info, AssetRecordV1>>>,
+
+    /// Target asset index account of the next index of the target fusion, required when minting.
+    #[account(
+        init,
+        payer = user,
+        space = 8 + AssetIndexV1::INIT_SPACE,
+        seeds = [
+            INDEX_SEED.as_bytes(),
+            target_fusion_data.key().as_ref(),
+            &target_fusion_data.asset_data.next_index.to_le_bytes()
+        ],
+        bump
+    )]
+    asset_index: Option<Box<Account<'info, AssetIndexV1>>>,
+
+    /// Source metadata pool account, required in recycled metadata mode.
+    #[account(mut, seeds = [METADATA_POOL_SEED.as_bytes(), source_fusion_data.key().as_ref()], bump)]
+    source_metadata_pool: Option<Box<Account<'info, MetadataPoolV1>>>,
+
+    /// Record of the vaulted target asset, kept since it was minted.
+    /// Required when handing out a vaulted asset.
+    #[account(seeds = [RECORD_SEED.as_bytes(), target_fusion_data.key().as_ref(), asset.key().as_ref()], bump)]
+    vaulted_asset_record: Option<Box<Account<'info, AssetRecordV1>>>,
+}
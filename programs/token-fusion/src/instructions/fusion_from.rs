@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
     token::{Mint, Token, TokenAccount, Transfer},
 };
 use mpl_core::{
@@ -10,27 +10,41 @@ use mpl_core::{
 };
 
 use crate::{
-    constants::{AUTHORITY_SEED, DATA_SEED, PROTOCOL_FEE, PROTOCOL_FEE_WALLET},
+    constants::{
+        AUTHORITY_SEED, DATA_SEED, METADATA_POOL_SEED, PROTOCOL_FEE, PROTOCOL_FEE_WALLET,
+        RECORD_SEED,
+    },
     errors::FusionError,
     utils::*,
-    FusionDataV1,
+    AssetIndexV1, AssetRecordV1, AssetRedeemModeV1, FusionDataV1, MetadataModeV1, MetadataPoolV1,
 };
 
-/// Accounts for CPI calls
-pub(crate) struct FusionFromAccountsV1<'info> {
-    // payer
-    pub payer: AccountInfo<'info>,
+/// Token accounts for the escrow CPI calls
+pub(crate) struct FusionFromTokenAccountsV1<'info> {
     // program pda
     pub authority_pda: AccountInfo<'info>,
     //token related accounts
     pub token_mint: AccountInfo<'info>,
     pub from: AccountInfo<'info>,
     pub to: AccountInfo<'info>,
+    // programs
+    pub token_program: AccountInfo<'info>,
+    // additional ingredients accounts, `[escrow ata, destination token account]` per ingredient
+    pub ingredients: Vec<AccountInfo<'info>>,
+}
+
+/// Accounts for CPI calls
+pub(crate) struct FusionFromAccountsV1<'info> {
+    // payer
+    pub payer: AccountInfo<'info>,
+    // asset owner or delegate
+    pub authority: AccountInfo<'info>,
+    // program pda
+    pub authority_pda: AccountInfo<'info>,
     // asset related accounts
     pub asset: AccountInfo<'info>,
     pub collection: AccountInfo<'info>,
     // programs
-    pub token_program: AccountInfo<'info>,
     pub core_program: AccountInfo<'info>,
     pub system_program: AccountInfo<'info>,
     pub log_wrapper: Option<AccountInfo<'info>>,
@@ -40,20 +54,69 @@ pub fn handler_fusion_from_v1<'info>(
     ctx: Context<'_, '_, '_, 'info, FusionFromV1Ctx<'info>>,
 ) -> Result<()> {
     let fusion = &mut ctx.accounts.fusion_data;
-    let accounts = FusionFromAccountsV1 {
-        // payer
-        payer: ctx.accounts.user.to_account_info(),
+
+    // redeemed tokens go to the recipient ata if set, otherwise to the user ata
+    let to = match (
+        &ctx.accounts.recipient,
+        &ctx.accounts.recipient_ata,
+        &ctx.accounts.user_ata,
+    ) {
+        (Some(_), Some(recipient_ata), _) => recipient_ata.to_account_info(),
+        (None, None, Some(user_ata)) => user_ata.to_account_info(),
+        _ => return err!(FusionError::InvalidRecipient),
+    };
+
+    // delegates redeem on behalf of the owner, the tokens go to the owner
+    let owner = ctx.accounts.asset.owner;
+    if !cmp_pubkeys(&ctx.accounts.user.key(), &owner) {
+        require!(
+            cmp_pubkeys_opt(
+                ctx.accounts
+                    .recipient
+                    .as_ref()
+                    .map(|recipient| recipient.key),
+                Some(&owner)
+            ),
+            FusionError::InvalidRecipient
+        );
+
+        // every ingredient destination must be a token account of the owner
+        for ingredient_accounts in ctx.remaining_accounts.chunks(2) {
+            let destination = ingredient_accounts
+                .get(1)
+                .ok_or(FusionError::InvalidRemainingAccounts)?;
+            let destination =
+                TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])?;
+            require!(
+                cmp_pubkeys(&destination.owner, &owner),
+                FusionError::InvalidRecipient
+            );
+        }
+    }
+
+    let token_accounts = FusionFromTokenAccountsV1 {
         // program pda
         authority_pda: ctx.accounts.authority_pda.to_account_info(),
         // token related accounts
         token_mint: ctx.accounts.token_mint.to_account_info(),
         from: ctx.accounts.escrow_ata_pda.to_account_info(),
-        to: ctx.accounts.user_ata.to_account_info(),
+        to,
+        // programs
+        token_program: ctx.accounts.token_program.to_account_info(),
+        // additional ingredients
+        ingredients: ctx.remaining_accounts.to_vec(),
+    };
+    let accounts = FusionFromAccountsV1 {
+        // payer
+        payer: ctx.accounts.payer.to_account_info(),
+        // asset owner or delegate
+        authority: ctx.accounts.user.to_account_info(),
+        // program pda
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
         // asset related accounts
         asset: ctx.accounts.asset.to_account_info(),
         collection: ctx.accounts.collection.to_account_info(),
         // programs
-        token_program: ctx.accounts.token_program.to_account_info(),
         core_program: ctx.accounts.core_program.to_account_info(),
         system_program: ctx.accounts.system_program.to_account_info(),
         log_wrapper: ctx
@@ -63,9 +126,48 @@ pub fn handler_fusion_from_v1<'info>(
             .map(|log_wrapper| log_wrapper.to_account_info()),
     };
 
-    process_burn(fusion, &accounts)?;
+    match fusion.asset_data.redeem_mode {
+        AssetRedeemModeV1::Burn => process_burn(fusion, &accounts)?,
+        AssetRedeemModeV1::Vault => process_vault(fusion, &accounts)?,
+    }
+
+    // vaulted assets keep their record and index
+    let record = match fusion.asset_data.redeem_mode {
+        AssetRedeemModeV1::Burn => {
+            let record = process_asset_record(
+                fusion,
+                ctx.accounts.asset_record.to_account_info(),
+                Some(accounts.payer.to_account_info()),
+            )?;
+
+            process_asset_index(
+                fusion,
+                ctx.accounts
+                    .asset_index
+                    .as_ref()
+                    .ok_or(FusionError::InvalidAssetIndex)?
+                    .to_account_info(),
+                &accounts.asset.key(),
+                record.mint_index,
+                ctx.accounts.user.to_account_info(),
+            )?;
+
+            process_release_index(fusion, ctx.accounts.metadata_pool.as_mut(), record.index)?;
+
+            record
+        }
+        AssetRedeemModeV1::Vault => {
+            process_asset_record(fusion, ctx.accounts.asset_record.to_account_info(), None)?
+        }
+    };
+    let escrow_amount = record.backing_amount(fusion.fee_data.escrow_amount);
 
-    process_transfer(fusion, &accounts, ctx.bumps.authority_pda)?;
+    process_transfer(
+        fusion,
+        &token_accounts,
+        escrow_amount,
+        ctx.bumps.authority_pda,
+    )?;
 
     sol_transfer(
         accounts.payer.to_account_info(),
@@ -76,13 +178,16 @@ pub fn handler_fusion_from_v1<'info>(
     Ok(())
 }
 
-/// Transfers tokens from the escrow to the user token account.
+/// Transfers the given amount of tokens from the escrow to the destination token account,
+/// along with the escrowed amount of every additional ingredient.
 pub(crate) fn process_transfer(
     fusion: &mut Account<'_, FusionDataV1>,
-    accounts: &FusionFromAccountsV1,
+    accounts: &FusionFromTokenAccountsV1,
+    transfer_amount: u64,
     bump: u8,
 ) -> Result<()> {
-    let authority_seeds = &[AUTHORITY_SEED.as_bytes(), &[bump]];
+    let fusion_key = fusion.key();
+    let authority_seeds = &[AUTHORITY_SEED.as_bytes(), fusion_key.as_ref(), &[bump]];
     let signer_seeds = &[&authority_seeds[..]];
 
     // (1) sanity checks
@@ -95,9 +200,6 @@ pub(crate) fn process_transfer(
         return err!(FusionError::TokenKeyMismatch);
     }
 
-    // get transfer amount
-    let transfer_amount = fusion.fee_data.escrow_amount;
-
     // (2) transfer
     if transfer_amount > 0 {
         let cpi_ctx = CpiContext::new_with_signer(
@@ -113,16 +215,155 @@ pub(crate) fn process_transfer(
         anchor_spl::token::transfer(cpi_ctx, transfer_amount)?;
         msg!("Escrow: {} SPL", transfer_amount);
     }
+
+    // (3) transfer the additional ingredients
+    require!(
+        accounts.ingredients.len() == fusion.ingredients.len() * 2,
+        FusionError::InvalidRemainingAccounts
+    );
+
+    for (ingredient, ingredient_accounts) in fusion
+        .ingredients
+        .iter()
+        .zip(accounts.ingredients.chunks_exact(2))
+    {
+        let (from, to) = (&ingredient_accounts[0], &ingredient_accounts[1]);
+
+        // escrow must be the ata of the authority pda
+        let escrow_ata = get_associated_token_address_with_program_id(
+            &accounts.authority_pda.key(),
+            &ingredient.mint,
+            &accounts.token_program.key(),
+        );
+        if !cmp_pubkeys(from.key, &escrow_ata) {
+            return err!(FusionError::InvalidIngredients);
+        }
+
+        if ingredient.escrow_amount > 0 {
+            let cpi_ctx = CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: accounts.authority_pda.to_account_info(),
+                },
+                signer_seeds,
+            );
+
+            anchor_spl::token::transfer(cpi_ctx, ingredient.escrow_amount)?;
+            msg!(
+                "Escrow: {} SPL {}",
+                ingredient.escrow_amount,
+                ingredient.mint
+            );
+        }
+    }
+
     Ok(())
 }
 
-/// Burn Asset
-pub(crate) fn process_burn(
+/// Returns the record of the redeemed asset, closes it unless there is no destination.
+/// Assets without a record were not minted by the program and are rejected.
+pub(crate) fn process_asset_record<'info>(
     fusion: &mut Account<'_, FusionDataV1>,
-    accounts: &FusionFromAccountsV1,
+    asset_record: AccountInfo<'info>,
+    destination: Option<AccountInfo<'info>>,
+) -> Result<AssetRecordV1> {
+    // record must be initialized and owned by the program, the address is checked by the caller
+    if asset_record.data_is_empty() {
+        return err!(FusionError::InvalidAssetRecord);
+    }
+    if !cmp_pubkeys(asset_record.owner, &crate::ID) {
+        return err!(FusionError::IncorrectOwner);
+    }
+
+    let record = AssetRecordV1::try_deserialize(&mut &asset_record.try_borrow_data()?[..])?;
+
+    // decrease tier or variable backing supply, plain assets are not counted
+    if record.is_backed() {
+        let supply = match (record.tier, fusion.backing.as_mut()) {
+            (Some(tier), _) => {
+                &mut fusion
+                    .tiers
+                    .get_mut(tier as usize)
+                    .ok_or(FusionError::InvalidTier)?
+                    .supply
+            }
+            (None, Some(backing)) => &mut backing.supply,
+            (None, None) => return err!(FusionError::InvalidAssetRecord),
+        };
+        *supply = supply
+            .checked_sub(1)
+            .ok_or(FusionError::NumericalOverflowError)?;
+    }
+
+    msg!(
+        "Record: {:?} tier, {} SPL",
+        record.tier,
+        record.backing_amount(fusion.fee_data.escrow_amount)
+    );
+
+    if let Some(destination) = destination {
+        close_account(asset_record, destination)?;
+    }
+
+    Ok(record)
+}
+
+/// Closes the index of the burned asset with the given mint index.
+/// Assets minted before indexes were created in every metadata mode have no index,
+/// indexes of other assets are kept.
+pub(crate) fn process_asset_index<'info>(
+    fusion: &Account<'_, FusionDataV1>,
+    asset_index: AccountInfo<'info>,
+    asset: &Pubkey,
+    mint_index: u64,
+    destination: AccountInfo<'info>,
 ) -> Result<()> {
-    // (1) sanity checks
+    let (asset_index_address, _) = find_asset_index_address(&fusion.key(), mint_index);
+    if !cmp_pubkeys(asset_index.key, &asset_index_address) {
+        return err!(FusionError::InvalidAssetIndex);
+    }
 
+    if asset_index.data_is_empty() {
+        return Ok(());
+    }
+
+    // index must be owned by the program
+    if !cmp_pubkeys(asset_index.owner, &crate::ID) {
+        return err!(FusionError::IncorrectOwner);
+    }
+
+    let indexed_asset =
+        AssetIndexV1::try_deserialize(&mut &asset_index.try_borrow_data()?[..])?.asset;
+
+    if cmp_pubkeys(&indexed_asset, asset) {
+        close_account(asset_index, destination)?;
+    }
+
+    Ok(())
+}
+
+/// Releases the metadata index of a burned asset back to the pool in recycled metadata mode.
+pub(crate) fn process_release_index(
+    fusion: &FusionDataV1,
+    metadata_pool: Option<&mut Account<'_, MetadataPoolV1>>,
+    index: u64,
+) -> Result<()> {
+    if fusion.asset_data.metadata_mode != MetadataModeV1::Recycled {
+        return Ok(());
+    }
+
+    metadata_pool
+        .ok_or(FusionError::MissingMetadataPool)?
+        .release(index)
+}
+
+/// Checks that the asset to redeem belongs to the fusion collection.
+fn validate_redeemed_asset(
+    fusion: &FusionDataV1,
+    accounts: &FusionFromAccountsV1,
+) -> Result<BaseAssetV1> {
     // is not paused
     fusion.validate()?;
 
@@ -151,6 +392,17 @@ pub(crate) fn process_burn(
         _ => return err!(FusionError::MissingCollectionMint),
     }
 
+    Ok(asset_metadata)
+}
+
+/// Burn Asset
+pub(crate) fn process_burn(
+    fusion: &mut Account<'_, FusionDataV1>,
+    accounts: &FusionFromAccountsV1,
+) -> Result<()> {
+    // (1) sanity checks
+    let asset_metadata = validate_redeemed_asset(fusion, accounts)?;
+
     // (2) prepare an asset to burn
 
     // asset accounts
@@ -158,6 +410,7 @@ pub(crate) fn process_burn(
         asset: accounts.asset.to_account_info(),
         collection: Some(accounts.collection.to_account_info()),
         payer: accounts.payer.to_account_info(),
+        authority: Some(accounts.authority.to_account_info()),
         core_program: accounts.core_program.to_account_info(),
         system_program: accounts.system_program.to_account_info(),
         log_wrapper: accounts
@@ -172,26 +425,75 @@ pub(crate) fn process_burn(
     burn_asset_v1(accounts)
 }
 
+/// Lock Asset in the vault owned by the authority pda
+pub(crate) fn process_vault(
+    fusion: &mut Account<'_, FusionDataV1>,
+    accounts: &FusionFromAccountsV1,
+) -> Result<()> {
+    // (1) sanity checks
+    let asset_metadata = validate_redeemed_asset(fusion, accounts)?;
+
+    // (2) prepare an asset to lock
+
+    // new owner
+    let vault = accounts.authority_pda.to_account_info();
+
+    // asset accounts, the owner is the authority
+    let accounts = AssetV1Accounts {
+        asset: accounts.asset.to_account_info(),
+        collection: Some(accounts.collection.to_account_info()),
+        payer: accounts.payer.to_account_info(),
+        authority: Some(accounts.authority.to_account_info()),
+        core_program: accounts.core_program.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        log_wrapper: accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    msg!("Asset: {} vaulted", asset_metadata.name);
+
+    // increase vault size
+    fusion.vault_size = fusion
+        .vault_size
+        .checked_add(1)
+        .ok_or(FusionError::NumericalOverflowError)?;
+
+    // (3) locking
+    transfer_asset_v1(accounts, vault, &[])
+}
+
 /// Fusion tokens from an asset.
 #[derive(Accounts)]
 pub struct FusionFromV1Ctx<'info> {
     /// Fusion data account.
-    #[account(seeds = [DATA_SEED.as_bytes()], bump)]
+    #[account(mut, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
     fusion_data: Account<'info, FusionDataV1>,
 
     /// Authority pda.
     /// CHECK: account checked in seeds constraint
-    #[account(seeds = [AUTHORITY_SEED.as_bytes()], bump)]
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
     authority_pda: UncheckedAccount<'info>,
 
-    /// Asset owner, transaction and rent payer.
+    /// Asset owner, or its burn delegate in burn redeem mode
+    /// or its transfer delegate in vault redeem mode, receives the rent of the asset index.
     #[account(mut)]
     user: Signer<'info>,
 
+    /// Transaction, rent and fees payer.
+    #[account(mut)]
+    payer: Signer<'info>,
+
     /// Account of the asset.
     #[account(
         mut,
-        constraint = asset.owner == user.key(),
+        constraint = is_asset_authority(
+            &asset.to_account_info(),
+            &asset.owner,
+            &user.key(),
+            fusion_data.asset_data.redeem_mode
+        ) @ FusionError::InvalidAssetAuthority,
         constraint = asset.update_authority == UpdateAuthority::Collection(collection.key()),
     )]
     asset: Account<'info, BaseAssetV1>,
@@ -212,14 +514,14 @@ pub struct FusionFromV1Ctx<'info> {
     )]
     escrow_ata_pda: Account<'info, TokenAccount>,
 
-    /// User ata account, will be initialized if needed.
+    /// User ata account, required without a recipient, will be initialized if needed.
     #[account(
         init_if_needed,
-        payer = user,
+        payer = payer,
         associated_token::mint = token_mint,
         associated_token::authority = user
     )]
-    user_ata: Account<'info, TokenAccount>,
+    user_ata: Option<Account<'info, TokenAccount>>,
 
     /// Protocol fee account.
     /// CHECK: checked by account constraint
@@ -246,4 +548,37 @@ pub struct FusionFromV1Ctx<'info> {
     /// The SPL Noop program.
     /// CHECK: Checked in mpl-core.
     log_wrapper: Option<AccountInfo<'info>>,
+
+    /// Asset record account, proves that the asset was minted by the program.
+    /// CHECK: account checked in seeds constraint and in process_asset_record
+    #[account(
+        mut,
+        seeds = [RECORD_SEED.as_bytes(), fusion_data.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    asset_record: UncheckedAccount<'info>,
+
+    /// Asset index account (seeds `[b"asset_index", fusion_data, mint_index]`), required in burn
+    /// redeem mode, closed with the asset.
+    /// CHECK: address checked against the recorded mint index in process_asset_index
+    #[account(mut)]
+    asset_index: Option<UncheckedAccount<'info>>,
+
+    /// Recipient of the redeemed tokens, optional. The tokens go to the user ata when not set.
+    /// Required with the asset owner when the user is a delegate.
+    /// CHECK: any wallet can receive the tokens
+    recipient: Option<UncheckedAccount<'info>>,
+
+    /// Recipient ata account, required with the recipient, will be initialized if needed.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = recipient
+    )]
+    recipient_ata: Option<Account<'info, TokenAccount>>,
+
+    /// Metadata pool account, required in recycled metadata mode.
+    #[account(mut, seeds = [METADATA_POOL_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    metadata_pool: Option<Account<'info, MetadataPoolV1>>,
 }
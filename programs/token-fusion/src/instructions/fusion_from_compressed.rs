@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_bubblegum::{
+    accounts::TreeConfig,
+    programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    ID as BUBBLEGUM_PROGRAM_ID,
+};
+
+use crate::{
+    constants::{AUTHORITY_SEED, DATA_SEED, PROTOCOL_FEE, PROTOCOL_FEE_WALLET},
+    errors::FusionError,
+    instructions::fusion_from::{process_transfer, FusionFromTokenAccountsV1},
+    utils::{
+        burn_compressed_v1, cmp_pubkeys_opt, sol_transfer, BurnCompressedV1Args, LeafV1Accounts,
+        TreeV1Accounts,
+    },
+    FusionDataV1,
+};
+
+/// Compressed leaf to burn, as returned by the DAS asset proof
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompressedLeafV1 {
+    /// Current root of the merkle tree
+    pub root: [u8; 32],
+    /// Hash of the leaf metadata
+    pub data_hash: [u8; 32],
+    /// Hash of the leaf creators
+    pub creator_hash: [u8; 32],
+    /// Leaf nonce
+    pub nonce: u64,
+    /// Leaf index in the merkle tree
+    pub index: u32,
+}
+
+pub fn handler_fusion_from_compressed_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, FusionFromCompressedV1Ctx<'info>>,
+    leaf_data: CompressedLeafV1,
+) -> Result<()> {
+    let fusion = &mut ctx.accounts.fusion_data;
+
+    // additional ingredients accounts come first, the rest is the proof
+    let ingredients_len = fusion.ingredients.len() * 2;
+    require!(
+        ctx.remaining_accounts.len() >= ingredients_len,
+        FusionError::InvalidRemainingAccounts
+    );
+    let (ingredients, proof) = ctx.remaining_accounts.split_at(ingredients_len);
+
+    let token_accounts = FusionFromTokenAccountsV1 {
+        // program pda
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        // token related accounts
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        from: ctx.accounts.escrow_ata_pda.to_account_info(),
+        to: ctx.accounts.user_ata.to_account_info(),
+        // programs
+        token_program: ctx.accounts.token_program.to_account_info(),
+        // additional ingredients
+        ingredients: ingredients.to_vec(),
+    };
+    let accounts = TreeV1Accounts {
+        tree_config: ctx.accounts.tree_config.to_account_info(),
+        merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+        payer: ctx.accounts.user.to_account_info(),
+        tree_creator: ctx.accounts.authority_pda.to_account_info(),
+        bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+        compression_program: ctx.accounts.compression_program.to_account_info(),
+        log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    let leaf = LeafV1Accounts {
+        leaf_owner: ctx.accounts.user.to_account_info(),
+        leaf_delegate: ctx
+            .accounts
+            .leaf_delegate
+            .as_ref()
+            .map_or(ctx.accounts.user.to_account_info(), |leaf_delegate| {
+                leaf_delegate.to_account_info()
+            }),
+    };
+
+    process_burn_compressed(fusion, accounts, leaf, leaf_data, proof)?;
+
+    let escrow_amount = fusion.fee_data.escrow_amount;
+    process_transfer(
+        fusion,
+        &token_accounts,
+        escrow_amount,
+        ctx.bumps.authority_pda,
+    )?;
+
+    sol_transfer(
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.fee_account.to_account_info(),
+        PROTOCOL_FEE,
+    )?;
+
+    Ok(())
+}
+
+/// Burn a compressed asset, the leaf is verified against the proof by bubblegum.
+pub(crate) fn process_burn_compressed<'info>(
+    fusion: &mut Account<'_, FusionDataV1>,
+    accounts: TreeV1Accounts<'info>,
+    leaf: LeafV1Accounts<'info>,
+    leaf_data: CompressedLeafV1,
+    proof: &[AccountInfo<'info>],
+) -> Result<()> {
+    // (1) sanity checks
+
+    // is not paused
+    fusion.validate()?;
+
+    // only leaves of the fusion merkle tree are backed by the escrow,
+    // the tree is private so all of its leaves are minted by the program
+    // also checked in account constraints
+    if !cmp_pubkeys_opt(Some(accounts.merkle_tree.key), fusion.merkle_tree.as_ref()) {
+        return err!(FusionError::InvalidMerkleTree);
+    }
+
+    // decrease compressed supply
+    fusion.compressed_supply = fusion
+        .compressed_supply
+        .checked_sub(1)
+        .ok_or(FusionError::NumericalOverflowError)?;
+
+    msg!("Compressed asset: {} burned", leaf_data.nonce);
+
+    // (2) burning
+    burn_compressed_v1(
+        accounts,
+        leaf,
+        BurnCompressedV1Args {
+            root: leaf_data.root,
+            data_hash: leaf_data.data_hash,
+            creator_hash: leaf_data.creator_hash,
+            nonce: leaf_data.nonce,
+            index: leaf_data.index,
+        },
+        proof,
+    )
+}
+
+/// Fusion tokens from a compressed asset.
+#[derive(Accounts)]
+pub struct FusionFromCompressedV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Leaf owner, transaction and rent payer.
+    #[account(mut)]
+    user: Signer<'info>,
+
+    /// Leaf delegate, optional, defaults to the leaf owner.
+    /// CHECK: checked in bubblegum against the leaf
+    leaf_delegate: Option<UncheckedAccount<'info>>,
+
+    /// Mint account of the token.
+    #[account(address = fusion_data.token_mint)]
+    token_mint: Account<'info, Mint>,
+
+    /// Token escrow pda ata account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = authority_pda
+    )]
+    escrow_ata_pda: Account<'info, TokenAccount>,
+
+    /// User ata account, will be initialized if needed.
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_mint,
+        associated_token::authority = user
+    )]
+    user_ata: Account<'info, TokenAccount>,
+
+    /// Protocol fee account.
+    /// CHECK: checked by account constraint
+    #[account(
+        mut,
+        address = PROTOCOL_FEE_WALLET @ FusionError::InvalidProtocolFeeWallet
+    )]
+    fee_account: UncheckedAccount<'info>,
+
+    /// Merkle tree account.
+    /// CHECK: checked by account constraint
+    #[account(
+        mut,
+        constraint = fusion_data.merkle_tree == Some(merkle_tree.key()) @ FusionError::InvalidMerkleTree
+    )]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// Bubblegum tree config account.
+    /// CHECK: checked by account constraint
+    #[account(address = TreeConfig::find_pda(&merkle_tree.key()).0)]
+    tree_config: UncheckedAccount<'info>,
+
+    /// Token program.
+    token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Bubblegum program.
+    /// CHECK: checked by account constraint
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    /// SPL Account Compression program.
+    /// CHECK: checked by account constraint
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// The SPL Noop program.
+    /// CHECK: checked by account constraint
+    #[account(address = SPL_NOOP_ID)]
+    log_wrapper: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -1,25 +1,42 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::sysvar::slot_hashes};
 use anchor_spl::{
     associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
     token::{Burn, Mint, Token, TokenAccount, Transfer},
 };
 
-use mpl_core::{accounts::BaseCollectionV1, ID as CORE_PROGRAM_ID};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    types::{
+        Attribute, Attributes, FreezeDelegate, PermanentBurnDelegate, Plugin, PluginAuthority,
+        PluginAuthorityPair, UpdateAuthority,
+    },
+    ID as CORE_PROGRAM_ID,
+};
 
 use crate::{
-    constants::{AUTHORITY_SEED, DATA_SEED, PROTOCOL_FEE, PROTOCOL_FEE_WALLET},
+    constants::{
+        ASSET_SEED, ATTRIBUTE_BACKING, ATTRIBUTE_INDEX, ATTRIBUTE_MINTED_AT, ATTRIBUTE_TIER,
+        AUTHORITY_SEED, DATA_SEED, INDEX_SEED, METADATA_POOL_SEED, PROTOCOL_FEE,
+        PROTOCOL_FEE_WALLET, RECORD_SEED,
+    },
     errors::FusionError,
     utils::{
-        cmp_pubkeys, cmp_pubkeys_opt, create_asset_v1, get_asset_hash,
-        get_pubkey_opt_from_account_info, sol_transfer, AssetV1Accounts, CreateV1Args,
+        cmp_pubkeys, cmp_pubkeys_opt, create_asset_v1, find_asset_address,
+        find_asset_index_address, get_pubkey_opt_from_account_info, get_random_value,
+        is_token_authority, sol_transfer, transfer_asset_v1, AssetV1Accounts, CreateV1Args,
     },
-    FusionDataV1,
+    AssetAddressModeV1, AssetIndexV1, AssetOutputModeV1, AssetRecordV1, FeeDataV1, FusionDataV1,
+    MetadataModeV1, MetadataPoolV1,
 };
 
-/// Accounts for CPI calls
-pub(crate) struct FusionIntoAccountsV1<'info> {
+/// Token accounts for the fee and escrow CPI calls
+pub(crate) struct FusionIntoTokenAccountsV1<'info> {
     // payer
     pub payer: AccountInfo<'info>,
+    // token owner
+    pub user: AccountInfo<'info>,
+    // program pda
+    pub authority_pda: AccountInfo<'info>,
     //token related accounts
     pub token_mint: AccountInfo<'info>,
     pub from: AccountInfo<'info>,
@@ -28,45 +45,169 @@ pub(crate) struct FusionIntoAccountsV1<'info> {
     pub fee_from: AccountInfo<'info>,
     pub fee_recipient: Option<AccountInfo<'info>>,
     pub fee_recipient_ata: Option<AccountInfo<'info>>,
+    // programs
+    pub token_program: AccountInfo<'info>,
+    // additional ingredients accounts, see `IngredientV1::accounts_len`
+    pub ingredients: Vec<AccountInfo<'info>>,
+}
+
+/// Accounts for CPI calls
+pub(crate) struct FusionIntoAccountsV1<'info> {
+    // payer
+    pub payer: AccountInfo<'info>,
+    // program pda
+    pub authority_pda: AccountInfo<'info>,
     // asset related accounts
     pub asset: AccountInfo<'info>,
     pub collection: AccountInfo<'info>,
+    // asset owner, the recipient if set, otherwise the user
+    pub owner: AccountInfo<'info>,
     // programs
-    pub token_program: AccountInfo<'info>,
     pub core_program: AccountInfo<'info>,
     pub system_program: AccountInfo<'info>,
     pub log_wrapper: Option<AccountInfo<'info>>,
 }
 
-impl From<&FusionDataV1> for CreateV1Args {
-    fn from(data: &FusionDataV1) -> Self {
-        Self {
-            name: format!(
-                "{}{}",
-                data.asset_data.name_prefix, data.asset_data.next_index
-            ),
-            uri: format!(
-                "{}{}{}/{}",
-                data.asset_data.uri_prefix,
-                data.asset_data.next_index,
-                data.asset_data.uri_suffix,
-                get_asset_hash(
-                    &data.asset_data.next_index,
-                    &data.collection,
-                    &PROTOCOL_FEE_WALLET
-                )
-            ),
+/// Builds the name, uri and plugins of a newly minted asset with the given metadata index
+/// and record.
+pub(crate) fn get_asset_args(
+    fusion: &FusionDataV1,
+    index: u64,
+    record: &AssetRecordV1,
+    authority_pda: &Pubkey,
+) -> Result<CreateV1Args> {
+    let (name, uri) = get_asset_name_and_uri(fusion, index, record)?;
+
+    Ok(CreateV1Args {
+        name,
+        uri,
+        plugins: get_asset_plugins(fusion, index, record, authority_pda)?,
+    })
+}
+
+/// Renders the name and uri of an asset with the given metadata index and record.
+pub(crate) fn get_asset_name_and_uri(
+    fusion: &FusionDataV1,
+    index: u64,
+    record: &AssetRecordV1,
+) -> Result<(String, String)> {
+    let asset_data = &fusion.asset_data;
+
+    // tier templates override the asset data templates
+    let tier = match record.tier {
+        Some(tier) => Some(
+            fusion
+                .tiers
+                .get(tier as usize)
+                .ok_or(FusionError::InvalidTier)?,
+        ),
+        None => None,
+    };
+    let name_template = tier
+        .and_then(|tier| tier.name_template.as_ref())
+        .or(asset_data.name_template.as_ref());
+    let uri_template = tier
+        .and_then(|tier| tier.uri_template.as_ref())
+        .or(asset_data.uri_template.as_ref());
+
+    // amount bracket of variable backed assets
+    let bracket = match &fusion.backing {
+        Some(backing) => backing.bracket(record.amount),
+        None => 0,
+    };
+
+    Ok((
+        asset_data.asset_name_from(name_template, index, bracket, &fusion.collection),
+        match &asset_data.placeholder_uri {
+            Some(placeholder_uri) => placeholder_uri.clone(),
+            None => asset_data.asset_uri_from(uri_template, index, bracket, &fusion.collection),
+        },
+    ))
+}
+
+/// Builds the list of plugins configured for a newly minted asset.
+pub(crate) fn get_asset_plugins(
+    fusion: &FusionDataV1,
+    index: u64,
+    record: &AssetRecordV1,
+    authority_pda: &Pubkey,
+) -> Result<Vec<PluginAuthorityPair>> {
+    let plugin_data = &fusion.asset_data.plugins;
+    let mut plugins = vec![];
+
+    if let Some(royalties) = &plugin_data.royalties {
+        plugins.push(PluginAuthorityPair {
+            plugin: Plugin::Royalties(royalties.into()),
+            authority: None,
+        });
+    }
+
+    if plugin_data.attributes {
+        // plain assets are backed by the escrow amount of the fusion
+        let escrow_amount = record.backing_amount(fusion.fee_data.escrow_amount);
+
+        let mut attribute_list = vec![
+            Attribute {
+                key: ATTRIBUTE_INDEX.to_string(),
+                value: index.to_string(),
+            },
+            Attribute {
+                key: ATTRIBUTE_BACKING.to_string(),
+                value: escrow_amount.to_string(),
+            },
+            Attribute {
+                key: ATTRIBUTE_MINTED_AT.to_string(),
+                value: Clock::get()?.unix_timestamp.to_string(),
+            },
+        ];
+
+        if let Some(tier) = record.tier {
+            attribute_list.push(Attribute {
+                key: ATTRIBUTE_TIER.to_string(),
+                value: tier.to_string(),
+            });
         }
+
+        plugins.push(PluginAuthorityPair {
+            plugin: Plugin::Attributes(Attributes { attribute_list }),
+            authority: None,
+        });
+    }
+
+    if plugin_data.permanent_burn_delegate {
+        plugins.push(PluginAuthorityPair {
+            plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
+            authority: Some(PluginAuthority::Address {
+                address: *authority_pda,
+            }),
+        });
+    }
+
+    if plugin_data.freeze_delegate {
+        plugins.push(PluginAuthorityPair {
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+            authority: Some(PluginAuthority::Address {
+                address: *authority_pda,
+            }),
+        });
     }
+
+    Ok(plugins)
 }
 
 pub fn handler_fusion_into_v1<'info>(
     ctx: Context<'_, '_, '_, 'info, FusionIntoV1Ctx<'info>>,
+    tier: Option<u8>,
+    amount: Option<u64>,
 ) -> Result<()> {
     let fusion = &mut ctx.accounts.fusion_data;
-    let accounts = FusionIntoAccountsV1 {
+    let token_accounts = FusionIntoTokenAccountsV1 {
         // payer
-        payer: ctx.accounts.user.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        // token owner
+        user: ctx.accounts.user.to_account_info(),
+        // program pda
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
         // token related accounts
         token_mint: ctx.accounts.token_mint.to_account_info(),
         from: ctx.accounts.user_ata.to_account_info(),
@@ -83,11 +224,24 @@ pub fn handler_fusion_into_v1<'info>(
             .fee_recipient_ata
             .as_ref()
             .map(|sol_fee_dest| sol_fee_dest.to_account_info()),
+        // programs
+        token_program: ctx.accounts.token_program.to_account_info(),
+        // additional ingredients
+        ingredients: ctx.remaining_accounts.to_vec(),
+    };
+    let accounts = FusionIntoAccountsV1 {
+        // payer
+        payer: ctx.accounts.payer.to_account_info(),
+        // program pda
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
         // asset related accounts
         asset: ctx.accounts.asset.to_account_info(),
         collection: ctx.accounts.collection.to_account_info(),
+        owner: match &ctx.accounts.recipient {
+            Some(recipient) => recipient.to_account_info(),
+            None => ctx.accounts.user.to_account_info(),
+        },
         // programs
-        token_program: ctx.accounts.token_program.to_account_info(),
         core_program: ctx.accounts.core_program.to_account_info(),
         system_program: ctx.accounts.system_program.to_account_info(),
         log_wrapper: ctx
@@ -97,9 +251,89 @@ pub fn handler_fusion_into_v1<'info>(
             .map(|log_wrapper| log_wrapper.to_account_info()),
     };
 
-    process_fee_and_transfer(fusion, &accounts)?;
+    require!(
+        fusion.asset_data.output_mode == AssetOutputModeV1::Core,
+        FusionError::InvalidOutputMode
+    );
+
+    // a tier must be chosen while the fusion has tiers, its fees replace the fusion fees
+    let mut fee_data = match tier {
+        Some(tier) => fusion
+            .tiers
+            .get(tier as usize)
+            .ok_or(FusionError::InvalidTier)?
+            .fee_data
+            .clone(),
+        None => {
+            require!(fusion.tiers.is_empty(), FusionError::InvalidTier);
+            fusion.fee_data.clone()
+        }
+    };
 
-    process_mint(fusion, &accounts, ctx.bumps.authority_pda)?;
+    // an amount must be chosen with the variable backing, it replaces the escrow amount
+    match (&fusion.backing, amount) {
+        (Some(backing), Some(amount)) => {
+            backing.validate_amount(amount)?;
+            fee_data.escrow_amount = amount;
+        }
+        (None, None) => {}
+        _ => return err!(FusionError::InvalidBacking),
+    }
+
+    process_fee_and_transfer(fusion, &fee_data, &token_accounts)?;
+    process_ingredients(fusion, &token_accounts)?;
+
+    // vaulted assets are handed out before any new asset is minted,
+    // they keep the record they were minted with
+    if fusion.vault_size > 0 {
+        require!(
+            ctx.accounts.asset_record.is_none() && ctx.accounts.vaulted_asset_record.is_some(),
+            FusionError::InvalidAssetRecord
+        );
+        require!(
+            ctx.accounts.asset_index.is_none(),
+            FusionError::InvalidAssetIndex
+        );
+        process_unvault(fusion, &accounts, ctx.bumps.authority_pda)?;
+    } else {
+        let index = process_metadata_index(
+            fusion,
+            ctx.accounts.metadata_pool.as_mut(),
+            ctx.accounts.slot_hashes.as_ref(),
+            &ctx.accounts.user.key(),
+        )?;
+
+        // every minted asset is recorded, tiered and variable backed assets with their amount
+        let record = AssetRecordV1 {
+            tier,
+            amount: if fusion.has_recorded_backing() {
+                fee_data.escrow_amount
+            } else {
+                0
+            },
+            index,
+            mint_index: fusion.asset_data.next_index,
+        };
+
+        process_mint(
+            fusion,
+            &accounts,
+            index,
+            &record,
+            ctx.accounts
+                .asset_index
+                .as_mut()
+                .ok_or(FusionError::InvalidAssetIndex)?,
+            &ctx.accounts.user.key(),
+            ctx.bumps.authority_pda,
+        )?;
+
+        ctx.accounts
+            .asset_record
+            .as_mut()
+            .ok_or(FusionError::InvalidAssetRecord)?
+            .set_inner(record);
+    }
 
     sol_transfer(
         accounts.payer.to_account_info(),
@@ -112,8 +346,9 @@ pub fn handler_fusion_into_v1<'info>(
 
 /// Transfers tokens to the escrow and take fees according to the fee data.
 pub(crate) fn process_fee_and_transfer(
-    fusion: &mut Account<'_, FusionDataV1>,
-    accounts: &FusionIntoAccountsV1,
+    fusion: &FusionDataV1,
+    fee_data: &FeeDataV1,
+    accounts: &FusionIntoTokenAccountsV1,
 ) -> Result<()> {
     // (0) sanity checks
 
@@ -126,13 +361,13 @@ pub(crate) fn process_fee_and_transfer(
     }
 
     // short references
-    let fee_amount = fusion.fee_data.fee_amount;
-    let sol_fee_amount = fusion.fee_data.sol_fee_amount;
-    let burn_amount = fusion.fee_data.burn_amount;
-    let escrow_amount = fusion.fee_data.escrow_amount;
+    let fee_amount = fee_data.fee_amount;
+    let sol_fee_amount = fee_data.sol_fee_amount;
+    let burn_amount = fee_data.burn_amount;
+    let escrow_amount = fee_data.escrow_amount;
 
-    let is_fee_charged = fusion.fee_data.is_fee_charged();
-    let fee_recipient_opt = fusion.fee_data.fee_recipient.as_ref();
+    let is_fee_charged = fee_data.is_fee_charged();
+    let fee_recipient_opt = fee_data.fee_recipient.as_ref();
 
     // Check that we have correct fee recipient accounts
     if is_fee_charged {
@@ -183,7 +418,7 @@ pub(crate) fn process_fee_and_transfer(
                 Transfer {
                     from: accounts.fee_from.to_account_info(),
                     to: fee_recipient_ata.to_account_info(),
-                    authority: accounts.payer.to_account_info(),
+                    authority: accounts.user.to_account_info(),
                 },
             );
 
@@ -199,7 +434,7 @@ pub(crate) fn process_fee_and_transfer(
             Burn {
                 mint: accounts.token_mint.to_account_info(),
                 from: accounts.from.to_account_info(),
-                authority: accounts.payer.to_account_info(),
+                authority: accounts.user.to_account_info(),
             },
         );
 
@@ -215,7 +450,7 @@ pub(crate) fn process_fee_and_transfer(
             Transfer {
                 from: accounts.from.to_account_info(),
                 to: accounts.to.to_account_info(),
-                authority: accounts.payer.to_account_info(),
+                authority: accounts.user.to_account_info(),
             },
         );
 
@@ -226,10 +461,161 @@ pub(crate) fn process_fee_and_transfer(
     Ok(())
 }
 
-/// Mint a new NFT.
+/// Transfers the additional ingredients to their escrows and take their fees.
+pub(crate) fn process_ingredients(
+    fusion: &FusionDataV1,
+    accounts: &FusionIntoTokenAccountsV1,
+) -> Result<()> {
+    // (0) sanity checks
+
+    // every ingredient has its group of accounts
+    let accounts_len: usize = fusion
+        .ingredients
+        .iter()
+        .map(|ingredient| ingredient.accounts_len())
+        .sum();
+
+    require!(
+        accounts.ingredients.len() == accounts_len,
+        FusionError::InvalidRemainingAccounts
+    );
+
+    let mut ingredient_accounts = accounts.ingredients.iter();
+
+    for ingredient in &fusion.ingredients {
+        let mut next_account = || {
+            ingredient_accounts
+                .next()
+                .ok_or(FusionError::InvalidRemainingAccounts)
+        };
+
+        let token_mint = next_account()?;
+        let from = next_account()?;
+        let to = next_account()?;
+
+        // double check that we got the correct token mint
+        if !cmp_pubkeys(token_mint.key, &ingredient.mint) {
+            return err!(FusionError::InvalidIngredients);
+        }
+
+        // escrow must be the ata of the authority pda
+        let escrow_ata = get_associated_token_address_with_program_id(
+            &accounts.authority_pda.key(),
+            &ingredient.mint,
+            &accounts.token_program.key(),
+        );
+        if !cmp_pubkeys(to.key, &escrow_ata) {
+            return err!(FusionError::InvalidIngredients);
+        }
+
+        // (1) If fee amount is set, transfer spl to fee recipient ata
+        if ingredient.fee_amount > 0 {
+            let fee_recipient_ata = next_account()?;
+            let fee_recipient = fusion
+                .fee_data
+                .fee_recipient
+                .as_ref()
+                .ok_or(FusionError::InvalidFeeRecipient)?;
+
+            let expected_fee_recipient_ata = get_associated_token_address_with_program_id(
+                fee_recipient,
+                &ingredient.mint,
+                &accounts.token_program.key(),
+            );
+            if !cmp_pubkeys(fee_recipient_ata.key, &expected_fee_recipient_ata) {
+                return err!(FusionError::InvalidFeeRecipient);
+            }
+
+            let cpi_ctx = CpiContext::new(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: fee_recipient_ata.to_account_info(),
+                    authority: accounts.user.to_account_info(),
+                },
+            );
+
+            anchor_spl::token::transfer(cpi_ctx, ingredient.fee_amount)?;
+            msg!("Fee: {} SPL {}", ingredient.fee_amount, ingredient.mint);
+        }
+
+        // (2) If burn amount is set, burn the amount
+        if ingredient.burn_amount > 0 {
+            let cpi_ctx = CpiContext::new(
+                accounts.token_program.to_account_info(),
+                Burn {
+                    mint: token_mint.to_account_info(),
+                    from: from.to_account_info(),
+                    authority: accounts.user.to_account_info(),
+                },
+            );
+
+            anchor_spl::token::burn(cpi_ctx, ingredient.burn_amount)?;
+            msg!("Burn: {} SPL {}", ingredient.burn_amount, ingredient.mint);
+        }
+
+        // (3) Transfer the rest to the escrow if there is any
+        if ingredient.escrow_amount > 0 {
+            let cpi_ctx = CpiContext::new(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: accounts.user.to_account_info(),
+                },
+            );
+
+            anchor_spl::token::transfer(cpi_ctx, ingredient.escrow_amount)?;
+            msg!(
+                "Escrow: {} SPL {}",
+                ingredient.escrow_amount,
+                ingredient.mint
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Assigns the metadata index of the asset to mint according to the metadata mode.
+pub(crate) fn process_metadata_index(
+    fusion: &Account<'_, FusionDataV1>,
+    metadata_pool: Option<&mut Account<'_, MetadataPoolV1>>,
+    slot_hashes: Option<&UncheckedAccount<'_>>,
+    user: &Pubkey,
+) -> Result<u64> {
+    match fusion.asset_data.metadata_mode {
+        MetadataModeV1::Sequential => Ok(fusion.asset_data.next_index),
+        MetadataModeV1::Random => {
+            let metadata_pool = metadata_pool.ok_or(FusionError::MissingMetadataPool)?;
+            let slot_hashes = slot_hashes.ok_or(FusionError::MissingSlotHashesSysvar)?;
+
+            // mix the recent slot hash with the minter and the mint counter
+            let random = get_random_value(
+                slot_hashes,
+                &[
+                    user.as_ref(),
+                    &fusion.asset_data.next_index.to_le_bytes(),
+                    &Clock::get()?.slot.to_le_bytes(),
+                ],
+            )?;
+
+            metadata_pool.draw(random)
+        }
+        MetadataModeV1::Recycled => metadata_pool
+            .ok_or(FusionError::MissingMetadataPool)?
+            .take(),
+    }
+}
+
+/// Mint a new NFT with the given metadata index and record, indexed by its mint index.
 pub(crate) fn process_mint(
     fusion: &mut Account<'_, FusionDataV1>,
     accounts: &FusionIntoAccountsV1,
+    index: u64,
+    record: &AssetRecordV1,
+    asset_index: &mut Account<'_, AssetIndexV1>,
+    minter: &Pubkey,
     bump: u8,
 ) -> Result<()> {
     // (1) sanity checks
@@ -251,19 +637,77 @@ pub(crate) fn process_mint(
         return err!(FusionError::IncorrectOwner);
     }
 
-    // current collection minted assets should be less then max supply
+    // current collection minted assets and compressed assets should be less then max supply
     let collection_metadata = BaseCollectionV1::try_from(&accounts.collection.to_account_info())?;
-    if collection_metadata.current_size >= fusion.asset_data.max_supply.unwrap_or(u32::MAX) {
+    if collection_metadata
+        .current_size
+        .saturating_add(fusion.compressed_supply)
+        >= fusion.asset_data.max_supply.unwrap_or(u32::MAX)
+    {
         return err!(FusionError::MaxSupplyReached);
     }
 
+    // tier minted assets should be less then the tier max supply
+    if let Some(tier) = record.tier {
+        let tier_data = fusion
+            .tiers
+            .get(tier as usize)
+            .ok_or(FusionError::InvalidTier)?;
+        if tier_data.supply >= tier_data.max_supply.unwrap_or(u32::MAX) {
+            return err!(FusionError::MaxSupplyReached);
+        }
+    }
+
+    // asset account must match the configured address mode
+    let index_bytes = fusion.asset_data.next_index.to_le_bytes();
+    let fusion_key = fusion.key();
+    let asset_bump = match fusion.asset_data.address_mode {
+        AssetAddressModeV1::Keypair => {
+            if !accounts.asset.is_signer {
+                return err!(FusionError::AssetMustBeSigner);
+            }
+            None
+        }
+        AssetAddressModeV1::Pda => {
+            let (asset_address, asset_bump) =
+                find_asset_address(&fusion_key, fusion.asset_data.next_index);
+            if !cmp_pubkeys(&accounts.asset.key(), &asset_address) {
+                return err!(FusionError::InvalidAssetAddress);
+            }
+            Some(asset_bump)
+        }
+    };
+
     // (2) prepare an asset to mint
 
+    // asset index of the mint index, also checked in account constraints,
+    // initialized once for every mint index
+    let (asset_index_address, _) = find_asset_index_address(&fusion_key, record.mint_index);
+    if !cmp_pubkeys(&asset_index.key(), &asset_index_address)
+        || record.mint_index != fusion.asset_data.next_index
+    {
+        return err!(FusionError::InvalidAssetIndex);
+    }
+
+    asset_index.set_inner(AssetIndexV1 {
+        asset: accounts.asset.key(),
+        slot: Clock::get()?.slot,
+        minter: *minter,
+        index,
+    });
+
+    // asset args
+    let args = get_asset_args(fusion, index, record, &accounts.authority_pda.key())?;
+
+    // asset owner
+    let owner = Some(accounts.owner.clone());
+
     // asset accounts
     let accounts = AssetV1Accounts {
         asset: accounts.asset.to_account_info(),
         collection: Some(accounts.collection.to_account_info()),
         payer: accounts.payer.to_account_info(),
+        authority: Some(accounts.authority_pda.to_account_info()),
         core_program: accounts.core_program.to_account_info(),
         system_program: accounts.system_program.to_account_info(),
         log_wrapper: accounts
@@ -272,9 +716,6 @@ pub(crate) fn process_mint(
             .map(|log_wrapper| log_wrapper.to_account_info()),
     };
 
-    // asset args
-    let args = CreateV1Args::from(fusion.as_ref());
-
     msg!("Asset: {:?} minted", args);
 
     // increase next index
@@ -284,29 +725,128 @@ pub(crate) fn process_mint(
         .checked_add(1)
         .ok_or(FusionError::NumericalOverflowError)?;
 
+    // increase tier or variable backing supply, plain assets are not counted
+    if record.is_backed() {
+        let supply = match (record.tier, fusion.backing.as_mut()) {
+            (Some(tier), _) => &mut fusion.tiers[tier as usize].supply,
+            (None, Some(backing)) => &mut backing.supply,
+            (None, None) => return err!(FusionError::InvalidAssetRecord),
+        };
+        *supply = supply
+            .checked_add(1)
+            .ok_or(FusionError::NumericalOverflowError)?;
+    }
+
     // (3) minting
-    create_asset_v1(accounts, args, [AUTHORITY_SEED.as_bytes(), &[bump]])
+    let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED.as_bytes(), fusion_key.as_ref(), &[bump]];
+
+    match asset_bump {
+        Some(asset_bump) => {
+            let asset_seeds: &[&[u8]] = &[
+                ASSET_SEED.as_bytes(),
+                fusion_key.as_ref(),
+                &index_bytes,
+                &[asset_bump],
+            ];
+            create_asset_v1(accounts, owner, args, &[authority_seeds, asset_seeds])
+        }
+        None => create_asset_v1(accounts, owner, args, &[authority_seeds]),
+    }
+}
+
+/// Hand out an asset locked in the vault.
+pub(crate) fn process_unvault(
+    fusion: &mut Account<'_, FusionDataV1>,
+    accounts: &FusionIntoAccountsV1,
+    bump: u8,
+) -> Result<()> {
+    // (1) sanity checks
+
+    // is not paused
+    fusion.validate()?;
+
+    if accounts.asset.data_is_empty() {
+        return err!(FusionError::AssetNotInVault);
+    }
+    // double check that we got the correct collection mint
+    // also checked in account constraints
+    if !cmp_pubkeys(&accounts.collection.key(), &fusion.collection) {
+        return err!(FusionError::CollectionKeyMismatch);
+    }
+
+    // asset must be owned by mpl_core program
+    if !cmp_pubkeys(accounts.asset.owner, &CORE_PROGRAM_ID) {
+        return err!(FusionError::IncorrectOwner);
+    }
+
+    // asset must be of the collection and locked in the vault
+    let asset_metadata = BaseAssetV1::try_from(&accounts.asset.to_account_info())?;
+    if asset_metadata.update_authority != UpdateAuthority::Collection(fusion.collection) {
+        return err!(FusionError::CollectionKeyMismatch);
+    }
+    if !cmp_pubkeys(&asset_metadata.owner, &accounts.authority_pda.key()) {
+        return err!(FusionError::AssetNotInVault);
+    }
+
+    // (2) prepare an asset to hand out
+
+    // new owner, the recipient if set, otherwise the user
+    let new_owner = accounts.owner.to_account_info();
+
+    // asset accounts, the owner is the authority pda
+    let accounts = AssetV1Accounts {
+        asset: accounts.asset.to_account_info(),
+        collection: Some(accounts.collection.to_account_info()),
+        payer: accounts.payer.to_account_info(),
+        authority: Some(accounts.authority_pda.to_account_info()),
+        core_program: accounts.core_program.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        log_wrapper: accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    msg!("Asset: {} unvaulted", asset_metadata.name);
+
+    // decrease vault size
+    fusion.vault_size = fusion
+        .vault_size
+        .checked_sub(1)
+        .ok_or(FusionError::NumericalOverflowError)?;
+
+    // (3) transfer
+    let fusion_key = fusion.key();
+    let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED.as_bytes(), fusion_key.as_ref(), &[bump]];
+    transfer_asset_v1(accounts, new_owner, &[authority_seeds])
 }
 
 /// Fusion tokens into an asset.
 #[derive(Accounts)]
 pub struct FusionIntoV1Ctx<'info> {
     /// Fusion data account.
-    #[account(mut, seeds = [DATA_SEED.as_bytes()], bump)]
+    #[account(mut, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
     fusion_data: Account<'info, FusionDataV1>,
 
     /// Authority pda.
     /// CHECK: account checked in seeds constraint
-    #[account(seeds = [AUTHORITY_SEED.as_bytes()], bump)]
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
     authority_pda: UncheckedAccount<'info>,
 
-    /// Asset owner, transaction and rent payer.
-    #[account(mut)]
+    /// Asset owner unless a recipient is set, token owner.
     user: Signer<'info>,
 
+    /// Transaction, rent and sol fees payer.
+    #[account(mut)]
+    payer: Signer<'info>,
+
     /// Account of the Asset. Will be initialized with the new asset.
+    /// Either a signer keypair or the asset PDA, depending on the address mode,
+    /// or any asset locked in the vault while the vault is not empty.
+    /// CHECK: checked against the address mode in process_mint or in process_unvault,
+    /// vaulted assets must have a record
     #[account(mut)]
-    asset: Signer<'info>,
+    asset: UncheckedAccount<'info>,
 
     /// Collection account.
     #[account(mut, address = fusion_data.collection, owner = CORE_PROGRAM_ID)]
@@ -324,11 +864,11 @@ pub struct FusionIntoV1Ctx<'info> {
     )]
     escrow_ata_pda: Account<'info, TokenAccount>,
 
-    /// User ata account.
+    /// User token account, owned by the user or delegated to the user.
     #[account(
         mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = user
+        token::mint = token_mint,
+        constraint = is_token_authority(&user_ata, &user.key()) @ FusionError::InvalidTokenAuthority
     )]
     user_ata: Account<'info, TokenAccount>,
 
@@ -367,4 +907,47 @@ pub struct FusionIntoV1Ctx<'info> {
     /// The SPL Noop program.
     /// CHECK: Checked in mpl-core.
     log_wrapper: Option<AccountInfo<'info>>,
+
+    /// Metadata pool account, required in random and recycled metadata modes.
+    #[account(mut, seeds = [METADATA_POOL_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    metadata_pool: Option<Account<'info, MetadataPoolV1>>,
+
+    /// Slot hashes sysvar, required in random metadata mode.
+    /// CHECK: checked by account constraint
+    #[account(address = slot_hashes::ID)]
+    slot_hashes: Option<UncheckedAccount<'info>>,
+
+    /// Asset record account, proves that the asset was minted by the program.
+    /// Required when minting.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AssetRecordV1::INIT_SPACE,
+        seeds = [RECORD_SEED.as_bytes(), fusion_data.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    asset_record: Option<Account<'info, AssetRecordV1>>,
+
+    /// Asset recipient, optional. The Asset is owned by the user when not set.
+    /// CHECK: any wallet can receive the asset
+    recipient: Option<UncheckedAccount<'info>>,
+
+    /// Asset index account of the next index of the fusion, required when minting.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AssetIndexV1::INIT_SPACE,
+        seeds = [
+            INDEX_SEED.as_bytes(),
+            fusion_data.key().as_ref(),
+            &fusion_data.asset_data.next_index.to_le_bytes()
+        ],
+        bump
+    )]
+    asset_index: Option<Account<'info, AssetIndexV1>>,
+
+    /// Record of the vaulted asset, kept since it was minted.
+    /// Required when handing out a vaulted asset.
+    #[account(seeds = [RECORD_SEED.as_bytes(), fusion_data.key().as_ref(), asset.key().as_ref()], bump)]
+    vaulted_asset_record: Option<Account<'info, AssetRecordV1>>,
 }
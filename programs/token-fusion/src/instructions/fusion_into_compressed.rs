@@ -0,0 +1,298 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::slot_hashes};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_bubblegum::{
+    accounts::TreeConfig,
+    programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    types::{Creator, MetadataArgs, TokenProgramVersion, TokenStandard},
+    ID as BUBBLEGUM_PROGRAM_ID,
+};
+use mpl_core::{accounts::BaseCollectionV1, ID as CORE_PROGRAM_ID};
+
+use crate::{
+    constants::{AUTHORITY_SEED, DATA_SEED, METADATA_POOL_SEED, PROTOCOL_FEE, PROTOCOL_FEE_WALLET},
+    errors::FusionError,
+    instructions::{
+        process_fee_and_transfer, process_ingredients, process_metadata_index,
+        FusionIntoTokenAccountsV1,
+    },
+    utils::{
+        cmp_pubkeys_opt, is_token_authority, mint_compressed_v1, sol_transfer, LeafV1Accounts,
+        TreeV1Accounts,
+    },
+    AssetOutputModeV1, FusionDataV1, MetadataPoolV1,
+};
+
+/// Builds the metadata of a newly minted compressed asset with the given metadata index.
+pub(crate) fn get_compressed_metadata(fusion: &FusionDataV1, index: u64) -> MetadataArgs {
+    let royalties = fusion.asset_data.plugins.royalties.as_ref();
+
+    MetadataArgs {
+        name: fusion.asset_data.asset_name(index, &fusion.collection),
+        symbol: String::new(),
+        uri: fusion
+            .asset_data
+            .minted_asset_uri(index, &fusion.collection),
+        seller_fee_basis_points: royalties.map_or(0, |royalties| royalties.basis_points),
+        primary_sale_happened: false,
+        is_mutable: true,
+        edition_nonce: None,
+        token_standard: Some(TokenStandard::NonFungible),
+        collection: None,
+        uses: None,
+        token_program_version: TokenProgramVersion::Original,
+        creators: royalties.map_or(vec![], |royalties| {
+            royalties
+                .creators
+                .iter()
+                .map(|creator| Creator {
+                    address: creator.address,
+                    verified: false,
+                    share: creator.percentage,
+                })
+                .collect()
+        }),
+    }
+}
+
+pub fn handler_fusion_into_compressed_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, FusionIntoCompressedV1Ctx<'info>>,
+) -> Result<()> {
+    let fusion = &mut ctx.accounts.fusion_data;
+    let token_accounts = FusionIntoTokenAccountsV1 {
+        // payer
+        payer: ctx.accounts.user.to_account_info(),
+        // token owner
+        user: ctx.accounts.user.to_account_info(),
+        // program pda
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        // token related accounts
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        from: ctx.accounts.user_ata.to_account_info(),
+        to: ctx.accounts.escrow_ata_pda.to_account_info(),
+        // fee related accounts
+        fee_from: ctx.accounts.user_ata.to_account_info(),
+        fee_recipient: ctx
+            .accounts
+            .fee_recipient
+            .as_ref()
+            .map(|spl_fee_dest| spl_fee_dest.to_account_info()),
+        fee_recipient_ata: ctx
+            .accounts
+            .fee_recipient_ata
+            .as_ref()
+            .map(|sol_fee_dest| sol_fee_dest.to_account_info()),
+        // programs
+        token_program: ctx.accounts.token_program.to_account_info(),
+        // additional ingredients
+        ingredients: ctx.remaining_accounts.to_vec(),
+    };
+    let accounts = TreeV1Accounts {
+        tree_config: ctx.accounts.tree_config.to_account_info(),
+        merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+        payer: ctx.accounts.user.to_account_info(),
+        tree_creator: ctx.accounts.authority_pda.to_account_info(),
+        bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+        compression_program: ctx.accounts.compression_program.to_account_info(),
+        log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    let leaf = LeafV1Accounts {
+        leaf_owner: ctx.accounts.user.to_account_info(),
+        leaf_delegate: ctx.accounts.user.to_account_info(),
+    };
+
+    require!(
+        fusion.asset_data.output_mode == AssetOutputModeV1::Compressed,
+        FusionError::InvalidOutputMode
+    );
+
+    process_fee_and_transfer(fusion, &fusion.fee_data, &token_accounts)?;
+    process_ingredients(fusion, &token_accounts)?;
+
+    let index = process_metadata_index(
+        fusion,
+        ctx.accounts.metadata_pool.as_mut(),
+        ctx.accounts.slot_hashes.as_ref(),
+        &ctx.accounts.user.key(),
+    )?;
+
+    process_mint_compressed(
+        fusion,
+        &ctx.accounts.collection,
+        accounts,
+        leaf,
+        index,
+        ctx.bumps.authority_pda,
+    )?;
+
+    sol_transfer(
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.fee_account.to_account_info(),
+        PROTOCOL_FEE,
+    )?;
+
+    Ok(())
+}
+
+/// Mint a new compressed asset with the given metadata index into the fusion merkle tree.
+pub(crate) fn process_mint_compressed<'info>(
+    fusion: &mut Account<'_, FusionDataV1>,
+    collection: &BaseCollectionV1,
+    accounts: TreeV1Accounts<'info>,
+    leaf: LeafV1Accounts<'info>,
+    index: u64,
+    bump: u8,
+) -> Result<()> {
+    // (1) sanity checks
+
+    // is not paused
+    fusion.validate()?;
+
+    // double check that we got the fusion merkle tree
+    // also checked in account constraints
+    if !cmp_pubkeys_opt(Some(accounts.merkle_tree.key), fusion.merkle_tree.as_ref()) {
+        return err!(FusionError::InvalidMerkleTree);
+    }
+
+    // current collection minted assets and compressed assets should be less then max supply
+    if collection
+        .current_size
+        .saturating_add(fusion.compressed_supply)
+        >= fusion.asset_data.max_supply.unwrap_or(u32::MAX)
+    {
+        return err!(FusionError::MaxSupplyReached);
+    }
+
+    // (2) prepare a compressed asset to mint
+    let metadata = get_compressed_metadata(fusion, index);
+
+    msg!("Compressed asset: {:?} minted", metadata);
+
+    // increase next index and compressed supply
+    fusion.asset_data.next_index = fusion
+        .asset_data
+        .next_index
+        .checked_add(1)
+        .ok_or(FusionError::NumericalOverflowError)?;
+
+    fusion.compressed_supply = fusion
+        .compressed_supply
+        .checked_add(1)
+        .ok_or(FusionError::NumericalOverflowError)?;
+
+    // (3) minting
+    mint_compressed_v1(
+        accounts,
+        leaf,
+        metadata,
+        [AUTHORITY_SEED.as_bytes(), fusion.key().as_ref(), &[bump]],
+    )
+}
+
+/// Fusion tokens into a compressed asset.
+#[derive(Accounts)]
+pub struct FusionIntoCompressedV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Leaf owner, transaction and rent payer.
+    #[account(mut)]
+    user: Signer<'info>,
+
+    /// Collection account, used for the max supply check.
+    #[account(address = fusion_data.collection, owner = CORE_PROGRAM_ID)]
+    collection: Account<'info, BaseCollectionV1>,
+
+    /// Mint account of the token.
+    #[account(mut, address = fusion_data.token_mint)]
+    token_mint: Account<'info, Mint>,
+
+    /// Token escrow pda ata account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = authority_pda
+    )]
+    escrow_ata_pda: Account<'info, TokenAccount>,
+
+    /// User token account, owned by the user or delegated to the user.
+    #[account(
+        mut,
+        token::mint = token_mint,
+        constraint = is_token_authority(&user_ata, &user.key()) @ FusionError::InvalidTokenAuthority
+    )]
+    user_ata: Account<'info, TokenAccount>,
+
+    /// Fee recipient account, optional.
+    /// CHECK: checked in the fee cpis
+    #[account(mut)]
+    fee_recipient: Option<UncheckedAccount<'info>>,
+
+    /// Fee recipient ata account, optional.
+    /// CHECK: checked in the fee cpis
+    #[account(mut)]
+    fee_recipient_ata: Option<UncheckedAccount<'info>>,
+
+    /// Protocol fee account.
+    /// CHECK: checked by account constraint
+    #[account(
+        mut,
+        address = PROTOCOL_FEE_WALLET @ FusionError::InvalidProtocolFeeWallet
+    )]
+    fee_account: UncheckedAccount<'info>,
+
+    /// Merkle tree account.
+    /// CHECK: checked by account constraint
+    #[account(
+        mut,
+        constraint = fusion_data.merkle_tree == Some(merkle_tree.key()) @ FusionError::InvalidMerkleTree
+    )]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// Bubblegum tree config account.
+    /// CHECK: checked by account constraint
+    #[account(mut, address = TreeConfig::find_pda(&merkle_tree.key()).0)]
+    tree_config: UncheckedAccount<'info>,
+
+    /// Token program.
+    token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Bubblegum program.
+    /// CHECK: checked by account constraint
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    /// SPL Account Compression program.
+    /// CHECK: checked by account constraint
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// The SPL Noop program.
+    /// CHECK: checked by account constraint
+    #[account(address = SPL_NOOP_ID)]
+    log_wrapper: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// Metadata pool account, required in random metadata mode.
+    #[account(mut, seeds = [METADATA_POOL_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    metadata_pool: Option<Account<'info, MetadataPoolV1>>,
+
+    /// Slot hashes sysvar, required in random metadata mode.
+    /// CHECK: checked by account constraint
+    #[account(address = slot_hashes::ID)]
+    slot_hashes: Option<UncheckedAccount<'info>>,
+}
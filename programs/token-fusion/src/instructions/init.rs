@@ -67,7 +67,7 @@ pub struct InitV1Ctx<'info> {
         init,
         payer = payer,
         space = 8 + FusionDataV1::INIT_SPACE,
-        seeds = [DATA_SEED.as_bytes()],
+        seeds = [DATA_SEED.as_bytes(), collection.key().as_ref()],
         bump
     )]
     fusion_data: Account<'info, FusionDataV1>,
@@ -78,7 +78,7 @@ pub struct InitV1Ctx<'info> {
     /// CHECK: account checked in seeds constraint
     #[account(
         mut,
-        seeds = [AUTHORITY_SEED.as_bytes()],
+        seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()],
         bump
     )]
     authority_pda: UncheckedAccount<'info>,
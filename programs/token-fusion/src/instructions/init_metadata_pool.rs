@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DATA_SEED, METADATA_POOL_MAX_SIZE, METADATA_POOL_SEED},
+    errors::FusionError,
+    FusionDataV1, MetadataPoolV1,
+};
+
+pub fn handler_init_metadata_pool_v1(ctx: Context<InitMetadataPoolV1Ctx>) -> Result<()> {
+    let asset_data = &ctx.accounts.fusion_data.asset_data;
+
+    // validation
+    let size = asset_data
+        .max_supply
+        .ok_or(FusionError::InvalidMetadataPoolSize)?;
+
+    require!(
+        size > 0 && size <= METADATA_POOL_MAX_SIZE,
+        FusionError::InvalidMetadataPoolSize
+    );
+
+    // pool covers `max_supply` indexes starting from the next index
+    ctx.accounts
+        .metadata_pool
+        .set_inner(MetadataPoolV1::new(asset_data.next_index, size));
+
+    Ok(())
+}
+
+/// Initializes the metadata pool used in random and recycled metadata modes.
+#[derive(Accounts)]
+pub struct InitMetadataPoolV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Metadata pool account.
+    #[account(
+        init,
+        payer = authority,
+        space = MetadataPoolV1::space(fusion_data.asset_data.max_supply.unwrap_or_default()),
+        seeds = [METADATA_POOL_SEED.as_bytes(), fusion_data.key().as_ref()],
+        bump
+    )]
+    metadata_pool: Account<'info, MetadataPoolV1>,
+
+    /// Authority and payer of the transaction.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::{
+    accounts::TreeConfig,
+    programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    ID as BUBBLEGUM_PROGRAM_ID,
+};
+
+use crate::{
+    constants::{AUTHORITY_SEED, DATA_SEED},
+    errors::FusionError,
+    utils::{create_tree_v1, CreateTreeV1Args, TreeV1Accounts},
+    FusionDataV1,
+};
+
+pub fn handler_init_tree_v1(
+    ctx: Context<InitTreeV1Ctx>,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Result<()> {
+    let fusion = &ctx.accounts.fusion_data;
+
+    // the tree can only be replaced once all compressed assets are redeemed
+    require!(
+        fusion.merkle_tree.is_none() || fusion.compressed_supply == 0,
+        FusionError::InvalidMerkleTree
+    );
+
+    let accounts = TreeV1Accounts {
+        tree_config: ctx.accounts.tree_config.to_account_info(),
+        merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+        payer: ctx.accounts.authority.to_account_info(),
+        tree_creator: ctx.accounts.authority_pda.to_account_info(),
+        bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+        compression_program: ctx.accounts.compression_program.to_account_info(),
+        log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    // the authority pda is the tree creator, so only the program is able to mint into the tree
+    create_tree_v1(
+        accounts,
+        CreateTreeV1Args {
+            max_depth,
+            max_buffer_size,
+        },
+        [
+            AUTHORITY_SEED.as_bytes(),
+            ctx.accounts.fusion_data.key().as_ref(),
+            &[ctx.bumps.authority_pda],
+        ],
+    )?;
+
+    ctx.accounts.fusion_data.merkle_tree = Some(ctx.accounts.merkle_tree.key());
+
+    Ok(())
+}
+
+/// Initializes the Bubblegum merkle tree of compressed assets.
+#[derive(Accounts)]
+pub struct InitTreeV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority and payer of the transaction.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Merkle tree account, allocated by the client and owned by the compression program.
+    /// CHECK: checked in bubblegum
+    #[account(mut, owner = SPL_ACCOUNT_COMPRESSION_ID)]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// Bubblegum tree config account.
+    /// CHECK: checked by account constraint
+    #[account(mut, address = TreeConfig::find_pda(&merkle_tree.key()).0)]
+    tree_config: UncheckedAccount<'info>,
+
+    /// Bubblegum program.
+    /// CHECK: checked by account constraint
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    /// SPL Account Compression program.
+    /// CHECK: checked by account constraint
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// The SPL Noop program.
+    /// CHECK: checked by account constraint
+    #[account(address = SPL_NOOP_ID)]
+    log_wrapper: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    types::UpdateAuthority,
+    ID as CORE_PROGRAM_ID,
+};
+
+use crate::{
+    constants::{
+        AUTHORITY_SEED, DATA_SEED, METADATA_POOL_SEED, PROTOCOL_FEE, PROTOCOL_FEE_WALLET,
+        RECORD_SEED,
+    },
+    errors::FusionError,
+    instructions::{
+        fusion_from::{process_asset_index, process_burn, process_release_index},
+        process_record_amount, FusionFromAccountsV1,
+    },
+    utils::{sol_transfer, AssetV1Accounts},
+    AssetRecordV1, FusionDataV1, MetadataPoolV1,
+};
+
+pub fn handler_merge_assets_v1(ctx: Context<MergeAssetsV1Ctx>) -> Result<()> {
+    let fusion = &mut ctx.accounts.fusion_data;
+    let burn_accounts = FusionFromAccountsV1 {
+        // payer
+        payer: ctx.accounts.user.to_account_info(),
+        // asset owner or delegate
+        authority: ctx.accounts.user.to_account_info(),
+        // program pda
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        // asset related accounts
+        asset: ctx.accounts.burned_asset.to_account_info(),
+        collection: ctx.accounts.collection.to_account_info(),
+        // programs
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+    let accounts = AssetV1Accounts {
+        asset: ctx.accounts.asset.to_account_info(),
+        collection: Some(ctx.accounts.collection.to_account_info()),
+        payer: ctx.accounts.user.to_account_info(),
+        authority: Some(ctx.accounts.authority_pda.to_account_info()),
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    // only variable backed assets are merged
+    let backing = fusion.backing.as_ref().ok_or(FusionError::InvalidBacking)?;
+    require!(
+        ctx.accounts.asset_record.is_variable() && ctx.accounts.burned_asset_record.is_variable(),
+        FusionError::InvalidAssetRecord
+    );
+
+    // the merged backing should stay below the max amount
+    let amount = ctx
+        .accounts
+        .asset_record
+        .amount
+        .checked_add(ctx.accounts.burned_asset_record.amount)
+        .ok_or(FusionError::NumericalOverflowError)?;
+    backing.validate_amount(amount)?;
+
+    // (1) burn the merged asset, its record is closed in account constraints
+    process_burn(fusion, &burn_accounts)?;
+
+    process_asset_index(
+        fusion,
+        ctx.accounts.burned_asset_index.to_account_info(),
+        &ctx.accounts.burned_asset.key(),
+        ctx.accounts.burned_asset_record.mint_index,
+        ctx.accounts.user.to_account_info(),
+    )?;
+
+    process_release_index(
+        fusion,
+        ctx.accounts.metadata_pool.as_mut(),
+        ctx.accounts.burned_asset_record.index,
+    )?;
+
+    // decrease variable backing supply
+    let backing = fusion.backing.as_mut().ok_or(FusionError::InvalidBacking)?;
+    backing.supply = backing
+        .supply
+        .checked_sub(1)
+        .ok_or(FusionError::NumericalOverflowError)?;
+
+    // (2) move its backing to the remaining asset
+    process_record_amount(
+        fusion,
+        &mut ctx.accounts.asset_record,
+        accounts,
+        amount,
+        ctx.bumps.authority_pda,
+    )?;
+
+    sol_transfer(
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.fee_account.to_account_info(),
+        PROTOCOL_FEE,
+    )?;
+
+    Ok(())
+}
+
+/// Merge two variable backed assets into one.
+#[derive(Accounts)]
+pub struct MergeAssetsV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Owner of both assets, transaction and rent payer.
+    #[account(mut)]
+    user: Signer<'info>,
+
+    /// Account of the asset receiving the merged backing.
+    #[account(
+        mut,
+        constraint = asset.owner == user.key(),
+        constraint = asset.update_authority == UpdateAuthority::Collection(collection.key()),
+    )]
+    asset: Account<'info, BaseAssetV1>,
+
+    /// Asset record account.
+    #[account(
+        mut,
+        seeds = [RECORD_SEED.as_bytes(), fusion_data.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    asset_record: Account<'info, AssetRecordV1>,
+
+    /// Account of the asset to burn.
+    #[account(
+        mut,
+        constraint = burned_asset.key() != asset.key() @ FusionError::InvalidAssetRecord,
+        constraint = burned_asset.owner == user.key(),
+        constraint = burned_asset.update_authority == UpdateAuthority::Collection(collection.key()),
+    )]
+    burned_asset: Account<'info, BaseAssetV1>,
+
+    /// Record account of the asset to burn, will be closed.
+    #[account(
+        mut,
+        close = user,
+        seeds = [RECORD_SEED.as_bytes(), fusion_data.key().as_ref(), burned_asset.key().as_ref()],
+        bump
+    )]
+    burned_asset_record: Account<'info, AssetRecordV1>,
+
+    /// Index account of the asset to burn (seeds `[b"asset_index", fusion_data, mint_index]`).
+    /// CHECK: address checked against the recorded mint index in process_asset_index
+    #[account(mut)]
+    burned_asset_index: UncheckedAccount<'info>,
+
+    /// Collection account.
+    #[account(mut, address = fusion_data.collection, owner = CORE_PROGRAM_ID)]
+    collection: Account<'info, BaseCollectionV1>,
+
+    /// Protocol fee account.
+    /// CHECK: checked by account constraint
+    #[account(
+        mut,
+        address = PROTOCOL_FEE_WALLET @ FusionError::InvalidProtocolFeeWallet
+    )]
+    fee_account: UncheckedAccount<'info>,
+
+    /// MPL Core program.
+    /// CHECK: checked by account constraint
+    #[account(address = CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// The SPL Noop program.
+    /// CHECK: Checked in mpl-core.
+    log_wrapper: Option<AccountInfo<'info>>,
+
+    /// Metadata pool account, required in recycled metadata mode.
+    #[account(mut, seeds = [METADATA_POOL_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    metadata_pool: Option<Account<'info, MetadataPoolV1>>,
+}
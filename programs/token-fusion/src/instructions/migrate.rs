@@ -0,0 +1,193 @@
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+use mpl_core::{
+    accounts::BaseCollectionV1,
+    types::{PluginAuthority, PluginType},
+    ID as CORE_PROGRAM_ID,
+};
+
+use crate::{
+    constants::{AUTHORITY_SEED, DATA_SEED},
+    errors::FusionError,
+    utils::{
+        approve_collection_authority_v1, close_account, cmp_pubkeys,
+        ApproveCollectionPluginAuthorityV1Args, CollectionPluginAuthorityV1Accounts,
+    },
+    FusionDataV1, LegacyFusionDataV1,
+};
+
+pub fn handler_migrate_v1(ctx: Context<MigrateV1Ctx>) -> Result<()> {
+    // (1) legacy fusion data, owned by the program with the fusion data discriminator
+    let legacy_fusion_data = ctx.accounts.legacy_fusion_data.to_account_info();
+    if !cmp_pubkeys(legacy_fusion_data.owner, &crate::ID) {
+        return err!(FusionError::IncorrectOwner);
+    }
+
+    let legacy = {
+        let data = legacy_fusion_data.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == FusionDataV1::DISCRIMINATOR,
+            FusionError::InvalidLegacyFusion
+        );
+        LegacyFusionDataV1::deserialize(&mut &data[8..])?
+    };
+
+    require!(
+        cmp_pubkeys(&legacy.authority, ctx.accounts.authority.key)
+            && cmp_pubkeys(&legacy.collection, &ctx.accounts.collection.key())
+            && cmp_pubkeys(&legacy.token_mint, &ctx.accounts.token_mint.key()),
+        FusionError::InvalidLegacyFusion
+    );
+
+    // (2) fusion data of the collection, assets keep their legacy names and uris
+    // and get their records with record_assets_v1
+    let fusion = &mut ctx.accounts.fusion_data;
+    fusion.set_inner(legacy.into());
+    fusion.legacy_supply = ctx.accounts.collection.current_size;
+    fusion.legacy_next_index = fusion.asset_data.next_index;
+
+    // (3) move the escrow to the new authority pda and close the legacy escrow
+    let legacy_authority_seeds: &[&[u8]] =
+        &[AUTHORITY_SEED.as_bytes(), &[ctx.bumps.legacy_authority_pda]];
+    let signer_seeds = &[legacy_authority_seeds];
+
+    let escrow_amount = ctx.accounts.legacy_escrow_ata_pda.amount;
+    if escrow_amount > 0 {
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.legacy_escrow_ata_pda.to_account_info(),
+                to: ctx.accounts.escrow_ata_pda.to_account_info(),
+                authority: ctx.accounts.legacy_authority_pda.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        anchor_spl::token::transfer(cpi_ctx, escrow_amount)?;
+        msg!("Escrow: {} SPL", escrow_amount);
+    }
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.legacy_escrow_ata_pda.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.legacy_authority_pda.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    anchor_spl::token::close_account(cpi_ctx)?;
+
+    // (4) close the legacy fusion data and withdraw the rent to the authority
+    close_account(legacy_fusion_data, ctx.accounts.authority.to_account_info())?;
+
+    // (5) hand the update delegate of the collection over to the new authority pda
+    let approve_accounts = CollectionPluginAuthorityV1Accounts {
+        collection: ctx.accounts.collection.to_account_info(),
+        payer: ctx.accounts.authority.to_account_info(),
+        authority: Some(ctx.accounts.authority.to_account_info()),
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    let approve_args = ApproveCollectionPluginAuthorityV1Args {
+        plugin_type: PluginType::UpdateDelegate,
+        new_authority: PluginAuthority::Address {
+            address: ctx.accounts.authority_pda.key(),
+        },
+    };
+
+    approve_collection_authority_v1(approve_accounts, approve_args)
+}
+
+/// Migrates the fusion data account and the escrow of the first version of the program
+/// to the fusion data account of the collection and its authority pda.
+#[derive(Accounts)]
+pub struct MigrateV1Ctx<'info> {
+    /// Legacy fusion data account.
+    ///
+    /// CHECK: account checked in seeds constraint, deserialized in the handler
+    #[account(mut, seeds = [DATA_SEED.as_bytes()], bump)]
+    legacy_fusion_data: UncheckedAccount<'info>,
+
+    /// Legacy authority pda, owner of the legacy escrow.
+    ///
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes()], bump)]
+    legacy_authority_pda: UncheckedAccount<'info>,
+
+    /// Fusion data account of the collection.
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FusionDataV1::INIT_SPACE,
+        seeds = [DATA_SEED.as_bytes(), collection.key().as_ref()],
+        bump
+    )]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority pda of the collection fusion.
+    ///
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the legacy fusion and of the collection, payer of the transaction.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Mint account of the token.
+    token_mint: Account<'info, Mint>,
+
+    /// Legacy token escrow pda ata account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = legacy_authority_pda
+    )]
+    legacy_escrow_ata_pda: Account<'info, TokenAccount>,
+
+    /// Token escrow pda ata account.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = token_mint,
+        associated_token::authority = authority_pda
+    )]
+    escrow_ata_pda: Account<'info, TokenAccount>,
+
+    /// Collection account.
+    #[account(
+        mut,
+        owner = CORE_PROGRAM_ID,
+        constraint = collection.update_authority == authority.key(),
+    )]
+    collection: Account<'info, BaseCollectionV1>,
+
+    /// Token program.
+    token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    /// MPL Core program.
+    /// CHECK: checked by account constraint
+    #[account(address = CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// The SPL Noop program.
+    /// CHECK: Checked in mpl-core.
+    log_wrapper: Option<AccountInfo<'info>>,
+}
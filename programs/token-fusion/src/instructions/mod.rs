@@ -1,15 +1,55 @@
+pub mod add_collection_plugin;
 pub mod destroy;
+pub mod evolve;
 pub mod fusion_from;
+pub mod fusion_from_compressed;
 pub mod fusion_into;
+pub mod fusion_into_compressed;
 pub mod init;
+pub mod init_metadata_pool;
+pub mod init_tree;
+pub mod merge_assets;
+pub mod migrate;
+pub mod record_assets;
+pub mod remove_collection_plugin;
+pub mod reveal;
 pub mod set_authority;
+pub mod set_backing;
+pub mod set_evolution;
+pub mod set_ingredients;
 pub mod set_pause;
+pub mod set_tiers;
+pub mod split_asset;
+pub mod top_up;
 pub mod update;
+pub mod update_assets;
+pub mod update_collection_plugin;
+pub mod withdraw_partial;
 
+pub use add_collection_plugin::*;
 pub use destroy::*;
+pub use evolve::*;
 pub use fusion_from::*;
+pub use fusion_from_compressed::*;
 pub use fusion_into::*;
+pub use fusion_into_compressed::*;
 pub use init::*;
+pub use init_metadata_pool::*;
+pub use init_tree::*;
+pub use merge_assets::*;
+pub use migrate::*;
+pub use record_assets::*;
+pub use remove_collection_plugin::*;
+pub use reveal::*;
 pub use set_authority::*;
+pub use set_backing::*;
+pub use set_evolution::*;
+pub use set_ingredients::*;
 pub use set_pause::*;
+pub use set_tiers::*;
+pub use split_asset::*;
+pub use top_up::*;
 pub use update::*;
+pub use update_assets::*;
+pub use update_collection_plugin::*;
+pub use withdraw_partial::*;
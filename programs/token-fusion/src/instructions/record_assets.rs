@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use mpl_core::{accounts::BaseAssetV1, types::UpdateAuthority, ID as CORE_PROGRAM_ID};
+
+use crate::{
+    constants::{DATA_SEED, INDEX_SEED, RECORD_SEED},
+    errors::FusionError,
+    utils::{cmp_pubkeys, create_pda_account, find_asset_index_address, find_asset_record_address},
+    AssetIndexV1, AssetRecordV1, FusionDataV1,
+};
+
+pub fn handler_record_assets_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, RecordAssetsV1Ctx<'info>>,
+) -> Result<()> {
+    let fusion = &mut ctx.accounts.fusion_data;
+
+    // every asset comes with its record and its index
+    let assets = ctx.remaining_accounts.chunks_exact(3);
+    require!(
+        assets.remainder().is_empty(),
+        FusionError::InvalidRemainingAccounts
+    );
+
+    let fusion_key = fusion.key();
+    let slot = Clock::get()?.slot;
+
+    for asset_accounts in assets {
+        let (asset, asset_record, asset_index) =
+            (&asset_accounts[0], &asset_accounts[1], &asset_accounts[2]);
+
+        // only assets minted by the first version of the program can be recorded
+        fusion.legacy_supply = fusion
+            .legacy_supply
+            .checked_sub(1)
+            .ok_or(FusionError::InvalidAssetRecord)?;
+
+        // asset must be owned by mpl_core program
+        if !cmp_pubkeys(asset.owner, &CORE_PROGRAM_ID) {
+            return err!(FusionError::IncorrectOwner);
+        }
+
+        // check that the collection of the asset is correct
+        let asset_metadata = BaseAssetV1::try_from(asset)?;
+        match asset_metadata.update_authority {
+            UpdateAuthority::Collection(collection) => {
+                if !cmp_pubkeys(&collection, &fusion.collection) {
+                    return err!(FusionError::CollectionKeyMismatch);
+                }
+            }
+            _ => return err!(FusionError::MissingCollectionMint),
+        }
+
+        // legacy assets are named with the name prefix followed by their metadata index,
+        // assets minted after the migration have an index from the legacy next index on
+        let index = asset_metadata
+            .name
+            .strip_prefix(&fusion.asset_data.name_prefix)
+            .and_then(|index| index.parse::<u64>().ok())
+            .filter(|index| *index < fusion.legacy_next_index)
+            .ok_or(FusionError::InvalidAssetRecord)?;
+
+        // record must not exist yet
+        let (asset_record_address, record_bump) = find_asset_record_address(&fusion_key, asset.key);
+        if !cmp_pubkeys(asset_record.key, &asset_record_address) {
+            return err!(FusionError::InvalidAssetRecord);
+        }
+        if !asset_record.data_is_empty() {
+            return err!(FusionError::InvalidAssetRecord);
+        }
+
+        // index must not exist yet, each legacy metadata index is recorded once
+        let (asset_index_address, index_bump) = find_asset_index_address(&fusion_key, index);
+        if !cmp_pubkeys(asset_index.key, &asset_index_address) {
+            return err!(FusionError::InvalidAssetIndex);
+        }
+        if !asset_index.data_is_empty() {
+            return err!(FusionError::InvalidAssetIndex);
+        }
+
+        create_pda_account(
+            ctx.accounts.authority.to_account_info(),
+            asset_record.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            8 + AssetRecordV1::INIT_SPACE,
+            &[
+                RECORD_SEED.as_bytes(),
+                fusion_key.as_ref(),
+                asset.key.as_ref(),
+                &[record_bump],
+            ],
+        )?;
+
+        create_pda_account(
+            ctx.accounts.authority.to_account_info(),
+            asset_index.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            8 + AssetIndexV1::INIT_SPACE,
+            &[
+                INDEX_SEED.as_bytes(),
+                fusion_key.as_ref(),
+                &index.to_le_bytes(),
+                &[index_bump],
+            ],
+        )?;
+
+        // plain record, backed by the escrow amount of the fusion,
+        // legacy assets were minted sequentially with their metadata index
+        let record = AssetRecordV1 {
+            tier: None,
+            amount: 0,
+            index,
+            mint_index: index,
+        };
+        record.try_serialize(&mut &mut asset_record.try_borrow_mut_data()?[..])?;
+
+        // the legacy minter is unknown, the asset is indexed with its current owner
+        let asset_index_data = AssetIndexV1 {
+            asset: *asset.key,
+            slot,
+            minter: asset_metadata.owner,
+            index,
+        };
+        asset_index_data.try_serialize(&mut &mut asset_index.try_borrow_mut_data()?[..])?;
+
+        msg!(
+            "Asset: {} recorded with index {}",
+            asset_metadata.name,
+            index
+        );
+    }
+
+    Ok(())
+}
+
+/// Records the assets minted by the first version of the program.
+#[derive(Accounts)]
+pub struct RecordAssetsV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority and payer of the transaction.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
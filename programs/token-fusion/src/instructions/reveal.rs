@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use mpl_core::ID as CORE_PROGRAM_ID;
+
+use crate::{
+    constants::{AUTHORITY_SEED, DATA_SEED},
+    instructions::{get_asset_name_and_uri, process_update_assets, UpdateAssetsAccountsV1},
+    utils::UpdateV1Args,
+    FusionDataV1,
+};
+
+pub fn handler_reveal_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevealV1Ctx<'info>>,
+    uri_prefix: Option<String>,
+    salt: Option<[u8; 32]>,
+) -> Result<()> {
+    let fusion = &mut ctx.accounts.fusion_data;
+
+    // set the real uri base and the hash salt, if provided, and drop the placeholder
+    if let Some(uri_prefix) = uri_prefix {
+        fusion.asset_data.uri_prefix = uri_prefix;
+    }
+    if let Some(salt) = salt {
+        fusion.asset_data.hash_salt = Some(salt);
+    }
+    fusion.asset_data.placeholder_uri = None;
+
+    // validation, the salt must match the commitment in salted hash mode
+    fusion.asset_data.validate()?;
+
+    let accounts = UpdateAssetsAccountsV1 {
+        payer: ctx.accounts.authority.to_account_info(),
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        collection: ctx.accounts.collection.to_account_info(),
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    // revealed uris of the batch, for the recorded metadata index of each asset
+    process_update_assets(
+        fusion,
+        &accounts,
+        ctx.remaining_accounts,
+        ctx.bumps.authority_pda,
+        |_, record| {
+            let (_, uri) = get_asset_name_and_uri(fusion, record.index, record)?;
+
+            Ok(UpdateV1Args {
+                name: None,
+                uri: Some(uri),
+            })
+        },
+    )
+}
+
+/// Reveals the asset metadata and updates the uris of the assets passed as remaining accounts.
+#[derive(Accounts)]
+pub struct RevealV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, has_one = authority, has_one = collection, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority PDA, update delegate of the collection.
+    ///
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority and payer of the transaction.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Collection account
+    ///
+    /// CHECK: account checked in fusion data constraint
+    #[account(mut)]
+    collection: UncheckedAccount<'info>,
+
+    /// MPL Core program.
+    /// CHECK: checked by account constraint
+    #[account(address = CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// The SPL Noop program.
+    /// CHECK: Checked in mpl-core.
+    log_wrapper: Option<AccountInfo<'info>>,
+}
@@ -15,7 +15,7 @@ pub fn handler_set_authority_v1(
 #[derive(Accounts)]
 pub struct SetAuthorityV1Ctx<'info> {
     /// Fusion data account.
-    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes()], bump)]
+    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
     fusion_data: Account<'info, FusionDataV1>,
 
     /// Current authority
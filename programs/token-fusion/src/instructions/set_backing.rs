@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::DATA_SEED, errors::FusionError, BackingDataV1, FusionDataV1};
+
+pub fn handler_set_backing_v1(
+    ctx: Context<SetBackingV1Ctx>,
+    backing: Option<BackingDataV1>,
+) -> Result<()> {
+    let fusion = &mut ctx.accounts.fusion_data;
+
+    // supply is managed by the program, keep it while there are assets in circulation
+    let supply = fusion.backing.as_ref().map_or(0, |backing| backing.supply);
+
+    match backing {
+        Some(mut backing) => {
+            // variable backing replaces tiers, vaulted assets have no amount to hand out
+            require!(
+                fusion.tiers.is_empty() && fusion.vault_size == 0,
+                FusionError::InvalidBacking
+            );
+
+            backing.validate(&fusion.asset_data)?;
+            backing.supply = supply;
+
+            fusion.backing = Some(backing);
+        }
+        None => {
+            require!(supply == 0, FusionError::InvalidBacking);
+
+            fusion.backing = None;
+        }
+    }
+
+    Ok(())
+}
+
+/// Set the variable backing of the fusion.
+#[derive(Accounts)]
+pub struct SetBackingV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Current authority
+    #[account(mut)]
+    authority: Signer<'info>,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::DATA_SEED, errors::FusionError, utils::cmp_pubkeys, EvolutionDataV1, FusionDataV1,
+};
+
+pub fn handler_set_evolution_v1(
+    ctx: Context<SetEvolutionV1Ctx>,
+    evolution: Option<EvolutionDataV1>,
+) -> Result<()> {
+    if let Some(evolution) = &evolution {
+        evolution.validate()?;
+
+        // the target fusion must be linked by its own authority as well
+        let target = ctx
+            .accounts
+            .target_fusion_data
+            .as_ref()
+            .ok_or(FusionError::InvalidEvolution)?;
+        let target_authority = ctx
+            .accounts
+            .target_authority
+            .as_ref()
+            .ok_or(FusionError::InvalidEvolution)?;
+
+        require!(
+            cmp_pubkeys(&target.key(), &evolution.target),
+            FusionError::InvalidEvolution
+        );
+        require!(
+            !cmp_pubkeys(&target.key(), &ctx.accounts.fusion_data.key()),
+            FusionError::InvalidEvolution
+        );
+        require!(
+            cmp_pubkeys(&target.authority, &target_authority.key()),
+            FusionError::InvalidEvolution
+        );
+
+        // backing is moved between the escrows, so both fusions must share the token
+        require!(
+            cmp_pubkeys(&target.token_mint, &ctx.accounts.fusion_data.token_mint),
+            FusionError::TokenKeyMismatch
+        );
+    }
+
+    ctx.accounts.fusion_data.evolution = evolution;
+
+    Ok(())
+}
+
+/// Set the evolution link to a higher tier fusion.
+#[derive(Accounts)]
+pub struct SetEvolutionV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Current authority
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Target fusion data account, required when setting the link.
+    #[account(seeds = [DATA_SEED.as_bytes(), target_fusion_data.collection.as_ref()], bump)]
+    target_fusion_data: Option<Account<'info, FusionDataV1>>,
+
+    /// Target fusion authority, required when setting the link.
+    target_authority: Option<Signer<'info>>,
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, AssociatedToken, Create},
+    token::Token,
+};
+use mpl_core::{accounts::BaseCollectionV1, ID as CORE_PROGRAM_ID};
+
+use crate::{
+    constants::{AUTHORITY_SEED, DATA_SEED, INGREDIENTS_MAX},
+    errors::FusionError,
+    utils::cmp_pubkeys,
+    FusionDataV1, IngredientV1,
+};
+
+pub fn handler_set_ingredients_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetIngredientsV1Ctx<'info>>,
+    ingredients: Vec<IngredientV1>,
+) -> Result<()> {
+    let fusion = &ctx.accounts.fusion_data;
+
+    // validation
+    require!(
+        ingredients.len() <= INGREDIENTS_MAX,
+        FusionError::InvalidIngredients
+    );
+
+    // assets in circulation are redeemed with the recipe they were fused with
+    require!(
+        ctx.accounts
+            .collection
+            .current_size
+            .saturating_add(fusion.compressed_supply)
+            == 0,
+        FusionError::InvalidIngredients
+    );
+
+    for (i, ingredient) in ingredients.iter().enumerate() {
+        ingredient.validate(fusion.fee_data.fee_recipient.as_ref())?;
+
+        // ingredient mints are unique and differ from the primary token
+        require!(
+            !cmp_pubkeys(&ingredient.mint, &fusion.token_mint)
+                && !ingredients[..i]
+                    .iter()
+                    .any(|other| cmp_pubkeys(&other.mint, &ingredient.mint)),
+            FusionError::InvalidIngredients
+        );
+    }
+
+    // escrow of every ingredient, `[mint, escrow ata]` per ingredient
+    require!(
+        ctx.remaining_accounts.len() == ingredients.len() * 2,
+        FusionError::InvalidRemainingAccounts
+    );
+
+    for (ingredient, ingredient_accounts) in ingredients
+        .iter()
+        .zip(ctx.remaining_accounts.chunks_exact(2))
+    {
+        let (token_mint, escrow_ata) = (&ingredient_accounts[0], &ingredient_accounts[1]);
+
+        if !cmp_pubkeys(token_mint.key, &ingredient.mint) {
+            return err!(FusionError::InvalidIngredients);
+        }
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.authority.to_account_info(),
+                associated_token: escrow_ata.to_account_info(),
+                authority: ctx.accounts.authority_pda.to_account_info(),
+                mint: token_mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        );
+
+        associated_token::create_idempotent(cpi_ctx)?;
+    }
+
+    ctx.accounts.fusion_data.ingredients = ingredients;
+
+    Ok(())
+}
+
+/// Set the additional ingredient tokens of the recipe.
+#[derive(Accounts)]
+pub struct SetIngredientsV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority and payer of the transaction.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Token program.
+    token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// Collection account.
+    #[account(address = fusion_data.collection, owner = CORE_PROGRAM_ID)]
+    collection: Account<'info, BaseCollectionV1>,
+}
@@ -12,7 +12,7 @@ pub fn handler_set_pause_v1(ctx: Context<SetPauseV1Ctx>, paused: bool) -> Result
 #[derive(Accounts)]
 pub struct SetPauseV1Ctx<'info> {
     /// Fusion data account.
-    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes()], bump)]
+    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
     fusion_data: Account<'info, FusionDataV1>,
 
     /// Current authority
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DATA_SEED, TIERS_MAX},
+    errors::FusionError,
+    FusionDataV1, TierV1,
+};
+
+pub fn handler_set_tiers_v1(ctx: Context<SetTiersV1Ctx>, mut tiers: Vec<TierV1>) -> Result<()> {
+    let fusion = &mut ctx.accounts.fusion_data;
+
+    // validation
+    require!(tiers.len() <= TIERS_MAX, FusionError::InvalidTier);
+
+    // tiers replace the variable backing, vaulted assets have no tier to hand out
+    require!(
+        tiers.is_empty() || (fusion.backing.is_none() && fusion.vault_size == 0),
+        FusionError::InvalidTier
+    );
+
+    for tier in &tiers {
+        tier.validate(&fusion.asset_data)?;
+    }
+
+    // supply is managed by the program, tiers with assets in circulation can not be removed
+    for (index, current) in fusion.tiers.iter().enumerate() {
+        match tiers.get_mut(index) {
+            Some(tier) => tier.supply = current.supply,
+            None => require!(current.supply == 0, FusionError::InvalidTier),
+        }
+    }
+
+    fusion.tiers = tiers;
+
+    Ok(())
+}
+
+/// Set the denomination tiers of the fusion.
+#[derive(Accounts)]
+pub struct SetTiersV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Current authority
+    #[account(mut)]
+    authority: Signer<'info>,
+}
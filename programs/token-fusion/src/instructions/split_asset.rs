@@ -0,0 +1,211 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::slot_hashes};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    types::UpdateAuthority,
+    ID as CORE_PROGRAM_ID,
+};
+
+use crate::{
+    constants::{
+        AUTHORITY_SEED, DATA_SEED, INDEX_SEED, METADATA_POOL_SEED, PROTOCOL_FEE,
+        PROTOCOL_FEE_WALLET, RECORD_SEED,
+    },
+    errors::FusionError,
+    instructions::{
+        process_metadata_index, process_mint, process_record_amount, FusionIntoAccountsV1,
+    },
+    utils::{sol_transfer, AssetV1Accounts},
+    AssetIndexV1, AssetRecordV1, FusionDataV1, MetadataPoolV1,
+};
+
+pub fn handler_split_asset_v1(ctx: Context<SplitAssetV1Ctx>, amount: u64) -> Result<()> {
+    let fusion = &mut ctx.accounts.fusion_data;
+    let mint_accounts = FusionIntoAccountsV1 {
+        // payer
+        payer: ctx.accounts.user.to_account_info(),
+        // program pda
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        // asset related accounts
+        asset: ctx.accounts.new_asset.to_account_info(),
+        collection: ctx.accounts.collection.to_account_info(),
+        owner: ctx.accounts.user.to_account_info(),
+        // programs
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+    let accounts = AssetV1Accounts {
+        asset: ctx.accounts.asset.to_account_info(),
+        collection: Some(ctx.accounts.collection.to_account_info()),
+        payer: ctx.accounts.user.to_account_info(),
+        authority: Some(ctx.accounts.authority_pda.to_account_info()),
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    // only variable backed assets are split
+    let backing = fusion.backing.as_ref().ok_or(FusionError::InvalidBacking)?;
+    require!(
+        ctx.accounts.asset_record.is_variable(),
+        FusionError::InvalidAssetRecord
+    );
+
+    // both parts should stay between the min and max amounts
+    let remaining = ctx
+        .accounts
+        .asset_record
+        .amount
+        .checked_sub(amount)
+        .ok_or(FusionError::InsufficientBacking)?;
+    backing.validate_amount(amount)?;
+    backing.validate_amount(remaining)?;
+
+    // (1) mint the new asset with its part of the backing
+    let index = process_metadata_index(
+        fusion,
+        ctx.accounts.metadata_pool.as_mut(),
+        ctx.accounts.slot_hashes.as_ref(),
+        &ctx.accounts.user.key(),
+    )?;
+
+    let record = AssetRecordV1 {
+        tier: None,
+        amount,
+        index,
+        mint_index: fusion.asset_data.next_index,
+    };
+
+    process_mint(
+        fusion,
+        &mint_accounts,
+        index,
+        &record,
+        &mut ctx.accounts.new_asset_index,
+        &ctx.accounts.user.key(),
+        ctx.bumps.authority_pda,
+    )?;
+
+    ctx.accounts.new_asset_record.set_inner(record);
+
+    // (2) keep the rest of the backing on the split asset
+    process_record_amount(
+        fusion,
+        &mut ctx.accounts.asset_record,
+        accounts,
+        remaining,
+        ctx.bumps.authority_pda,
+    )?;
+
+    sol_transfer(
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.fee_account.to_account_info(),
+        PROTOCOL_FEE,
+    )?;
+
+    Ok(())
+}
+
+/// Split a variable backed asset into two.
+#[derive(Accounts)]
+pub struct SplitAssetV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(mut, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Asset owner, transaction and rent payer.
+    #[account(mut)]
+    user: Signer<'info>,
+
+    /// Account of the asset to split.
+    #[account(
+        mut,
+        constraint = asset.owner == user.key(),
+        constraint = asset.update_authority == UpdateAuthority::Collection(collection.key()),
+    )]
+    asset: Account<'info, BaseAssetV1>,
+
+    /// Asset record account.
+    #[account(
+        mut,
+        seeds = [RECORD_SEED.as_bytes(), fusion_data.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    asset_record: Account<'info, AssetRecordV1>,
+
+    /// Account of the new Asset. Will be initialized with the split backing.
+    /// Either a signer keypair or the asset PDA, depending on the address mode.
+    /// CHECK: checked against the address mode in process_mint
+    #[account(mut)]
+    new_asset: UncheckedAccount<'info>,
+
+    /// Record account of the new asset.
+    #[account(
+        init,
+        payer = user,
+        space = 8 + AssetRecordV1::INIT_SPACE,
+        seeds = [RECORD_SEED.as_bytes(), fusion_data.key().as_ref(), new_asset.key().as_ref()],
+        bump
+    )]
+    new_asset_record: Account<'info, AssetRecordV1>,
+
+    /// Index account of the new asset, keyed on the next index of the fusion.
+    #[account(
+        init,
+        payer = user,
+        space = 8 + AssetIndexV1::INIT_SPACE,
+        seeds = [
+            INDEX_SEED.as_bytes(),
+            fusion_data.key().as_ref(),
+            &fusion_data.asset_data.next_index.to_le_bytes()
+        ],
+        bump
+    )]
+    new_asset_index: Account<'info, AssetIndexV1>,
+
+    /// Collection account.
+    #[account(mut, address = fusion_data.collection, owner = CORE_PROGRAM_ID)]
+    collection: Account<'info, BaseCollectionV1>,
+
+    /// Protocol fee account.
+    /// CHECK: checked by account constraint
+    #[account(
+        mut,
+        address = PROTOCOL_FEE_WALLET @ FusionError::InvalidProtocolFeeWallet
+    )]
+    fee_account: UncheckedAccount<'info>,
+
+    /// MPL Core program.
+    /// CHECK: checked by account constraint
+    #[account(address = CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// The SPL Noop program.
+    /// CHECK: Checked in mpl-core.
+    log_wrapper: Option<AccountInfo<'info>>,
+
+    /// Metadata pool account, required in random and recycled metadata modes.
+    #[account(mut, seeds = [METADATA_POOL_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    metadata_pool: Option<Account<'info, MetadataPoolV1>>,
+
+    /// Slot hashes sysvar, required in random metadata mode.
+    /// CHECK: checked by account constraint
+    #[account(address = slot_hashes::ID)]
+    slot_hashes: Option<UncheckedAccount<'info>>,
+}
@@ -0,0 +1,256 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_plugin,
+    types::{Attributes, Plugin, PluginType, UpdateAuthority},
+    ID as CORE_PROGRAM_ID,
+};
+
+use crate::{
+    constants::{
+        ATTRIBUTE_BACKING, AUTHORITY_SEED, DATA_SEED, PROTOCOL_FEE, PROTOCOL_FEE_WALLET,
+        RECORD_SEED,
+    },
+    errors::FusionError,
+    instructions::{get_asset_name_and_uri, process_fee_and_transfer, FusionIntoTokenAccountsV1},
+    utils::{sol_transfer, update_asset_plugin_v1, update_asset_v1, AssetV1Accounts, UpdateV1Args},
+    AssetRecordV1, FusionDataV1,
+};
+
+pub fn handler_top_up_v1(ctx: Context<TopUpV1Ctx>, amount: u64) -> Result<()> {
+    let fusion = &ctx.accounts.fusion_data;
+    let token_accounts = FusionIntoTokenAccountsV1 {
+        // payer
+        payer: ctx.accounts.user.to_account_info(),
+        // token owner
+        user: ctx.accounts.user.to_account_info(),
+        // program pda
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        // token related accounts
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        from: ctx.accounts.user_ata.to_account_info(),
+        to: ctx.accounts.escrow_ata_pda.to_account_info(),
+        // fee related accounts
+        fee_from: ctx.accounts.user_ata.to_account_info(),
+        fee_recipient: ctx
+            .accounts
+            .fee_recipient
+            .as_ref()
+            .map(|spl_fee_dest| spl_fee_dest.to_account_info()),
+        fee_recipient_ata: ctx
+            .accounts
+            .fee_recipient_ata
+            .as_ref()
+            .map(|sol_fee_dest| sol_fee_dest.to_account_info()),
+        // programs
+        token_program: ctx.accounts.token_program.to_account_info(),
+        // ingredients are not topped up
+        ingredients: vec![],
+    };
+    let accounts = AssetV1Accounts {
+        asset: ctx.accounts.asset.to_account_info(),
+        collection: Some(ctx.accounts.collection.to_account_info()),
+        payer: ctx.accounts.user.to_account_info(),
+        authority: Some(ctx.accounts.authority_pda.to_account_info()),
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    // only variable backed assets are topped up
+    let backing = fusion.backing.as_ref().ok_or(FusionError::InvalidBacking)?;
+    require!(
+        ctx.accounts.asset_record.is_variable(),
+        FusionError::InvalidAssetRecord
+    );
+
+    // the new backing should stay below the max amount
+    let new_amount = ctx
+        .accounts
+        .asset_record
+        .amount
+        .checked_add(amount)
+        .ok_or(FusionError::NumericalOverflowError)?;
+    require!(
+        amount > 0 && new_amount <= backing.max_amount,
+        FusionError::InvalidBacking
+    );
+
+    // the fusion fees are taken from the top up, the rest is escrowed
+    let mut fee_data = fusion.fee_data.clone();
+    fee_data.escrow_amount = amount;
+
+    process_fee_and_transfer(fusion, &fee_data, &token_accounts)?;
+
+    process_record_amount(
+        fusion,
+        &mut ctx.accounts.asset_record,
+        accounts,
+        new_amount,
+        ctx.bumps.authority_pda,
+    )?;
+
+    sol_transfer(
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.fee_account.to_account_info(),
+        PROTOCOL_FEE,
+    )?;
+
+    Ok(())
+}
+
+/// Records the new backing of a variable backed asset, the backing attribute of the asset
+/// follows the amount and its name and uri are updated if its amount bracket changes.
+pub(crate) fn process_record_amount(
+    fusion: &Account<'_, FusionDataV1>,
+    record: &mut Account<'_, AssetRecordV1>,
+    accounts: AssetV1Accounts,
+    amount: u64,
+    bump: u8,
+) -> Result<()> {
+    let backing = fusion.backing.as_ref().ok_or(FusionError::InvalidBacking)?;
+    let bracket = backing.bracket(record.amount);
+    let fusion_key = fusion.key();
+    let signer_seeds = [AUTHORITY_SEED.as_bytes(), fusion_key.as_ref(), &[bump]];
+
+    if amount == record.amount {
+        return Ok(());
+    }
+
+    record.amount = amount;
+
+    msg!("Record: {} SPL", amount);
+
+    // assets minted with the attributes plugin show their backing
+    if let Ok((_, mut attributes, _)) =
+        fetch_plugin::<BaseAssetV1, Attributes>(&accounts.asset, PluginType::Attributes)
+    {
+        attributes
+            .attribute_list
+            .iter_mut()
+            .filter(|attribute| attribute.key == ATTRIBUTE_BACKING)
+            .for_each(|attribute| attribute.value = amount.to_string());
+
+        update_asset_plugin_v1(
+            accounts.clone(),
+            Plugin::Attributes(attributes),
+            signer_seeds,
+        )?;
+    }
+
+    if backing.bracket(amount) == bracket {
+        return Ok(());
+    }
+
+    let (name, uri) = get_asset_name_and_uri(fusion, record.index, record)?;
+
+    update_asset_v1(
+        accounts,
+        UpdateV1Args {
+            name: Some(name),
+            uri: Some(uri),
+        },
+        signer_seeds,
+    )
+}
+
+/// Top up the recorded backing of an asset.
+#[derive(Accounts)]
+pub struct TopUpV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Asset owner, transaction and rent payer.
+    #[account(mut)]
+    user: Signer<'info>,
+
+    /// Account of the asset.
+    #[account(
+        mut,
+        constraint = asset.owner == user.key(),
+        constraint = asset.update_authority == UpdateAuthority::Collection(collection.key()),
+    )]
+    asset: Account<'info, BaseAssetV1>,
+
+    /// Asset record account.
+    #[account(
+        mut,
+        seeds = [RECORD_SEED.as_bytes(), fusion_data.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    asset_record: Account<'info, AssetRecordV1>,
+
+    /// Collection account.
+    #[account(mut, address = fusion_data.collection, owner = CORE_PROGRAM_ID)]
+    collection: Account<'info, BaseCollectionV1>,
+
+    /// Mint account of the token.
+    #[account(mut, address = fusion_data.token_mint)]
+    token_mint: Account<'info, Mint>,
+
+    /// Token escrow pda ata account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = authority_pda
+    )]
+    escrow_ata_pda: Account<'info, TokenAccount>,
+
+    /// User ata account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = user
+    )]
+    user_ata: Account<'info, TokenAccount>,
+
+    /// Fee recipient account, optional.
+    /// CHECK: checked in the fee cpis
+    #[account(mut)]
+    fee_recipient: Option<UncheckedAccount<'info>>,
+
+    /// Fee recipient ata account, optional.
+    /// CHECK: checked in the fee cpis
+    #[account(mut)]
+    fee_recipient_ata: Option<UncheckedAccount<'info>>,
+
+    /// Protocol fee account.
+    /// CHECK: checked by account constraint
+    #[account(
+        mut,
+        address = PROTOCOL_FEE_WALLET @ FusionError::InvalidProtocolFeeWallet
+    )]
+    fee_account: UncheckedAccount<'info>,
+
+    /// Token program.
+    token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    /// MPL Core program.
+    /// CHECK: checked by account constraint
+    #[account(address = CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// The SPL Noop program.
+    /// CHECK: Checked in mpl-core.
+    log_wrapper: Option<AccountInfo<'info>>,
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::DATA_SEED, AssetDataV1, FeeDataV1, FusionDataV1};
+use crate::{
+    constants::{DATA_SEED, METADATA_POOL_SEED},
+    errors::FusionError,
+    utils::cmp_pubkeys,
+    AssetDataV1, FeeDataV1, FusionDataV1, MetadataPoolV1,
+};
 
 pub fn handler_update_v1(
     ctx: Context<UpdateV1Ctx>,
@@ -11,20 +16,84 @@ pub fn handler_update_v1(
     asset_data.validate()?;
     fee_data.validate()?;
 
+    // ingredient fees go to the same fee recipient
+    for ingredient in &ctx.accounts.fusion_data.ingredients {
+        ingredient.validate(fee_data.fee_recipient.as_ref())?;
+    }
+
+    // tier templates depend on the hash mode of the asset data
+    for tier in &ctx.accounts.fusion_data.tiers {
+        tier.validate(&asset_data)?;
+    }
+
+    if let Some(backing) = &ctx.accounts.fusion_data.backing {
+        backing.validate(&asset_data)?;
+    }
+
+    // vaulted and compressed assets are handed out and redeemed with the modes they were minted with
+    let fusion = &ctx.accounts.fusion_data;
+    let current = &fusion.asset_data;
+    if fusion.vault_size > 0 || fusion.compressed_supply > 0 {
+        require!(
+            asset_data.redeem_mode == current.redeem_mode
+                && asset_data.output_mode == current.output_mode
+                && asset_data.address_mode == current.address_mode,
+            FusionError::AssetModeLocked
+        );
+    }
+
+    if let Some(metadata_pool) = load_metadata_pool(&ctx.accounts.metadata_pool)? {
+        // the metadata pool covers exactly `max_supply` indexes
+        require!(
+            asset_data.max_supply == current.max_supply,
+            FusionError::MaxSupplyLocked
+        );
+
+        // the indexes of the minted assets depend on the metadata mode they were minted with
+        if asset_data.metadata_mode != current.metadata_mode {
+            require!(
+                current.next_index <= metadata_pool.start_index,
+                FusionError::MetadataModeLocked
+            );
+        }
+    }
+
     ctx.accounts.fusion_data.asset_data = asset_data;
     ctx.accounts.fusion_data.fee_data = fee_data;
 
     Ok(())
 }
 
+/// Loads the metadata pool if it is initialized.
+fn load_metadata_pool(metadata_pool: &AccountInfo) -> Result<Option<MetadataPoolV1>> {
+    if metadata_pool.data_is_empty() {
+        return Ok(None);
+    }
+
+    // pool must be owned by the program
+    if !cmp_pubkeys(metadata_pool.owner, &crate::ID) {
+        return err!(FusionError::IncorrectOwner);
+    }
+
+    let metadata_pool =
+        MetadataPoolV1::try_deserialize(&mut &metadata_pool.try_borrow_data()?[..])?;
+
+    Ok(Some(metadata_pool))
+}
+
 /// Initializes a new exchange machine.
 #[derive(Accounts)]
 pub struct UpdateV1Ctx<'info> {
     /// Fusion data account.
-    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes()], bump)]
+    #[account(mut, has_one = authority, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
     fusion_data: Account<'info, FusionDataV1>,
 
     /// Current authority
     #[account(mut)]
     authority: Signer<'info>,
+
+    /// Metadata pool account, may be uninitialized.
+    /// CHECK: deserialized in the handler if initialized
+    #[account(seeds = [METADATA_POOL_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    metadata_pool: UncheckedAccount<'info>,
 }
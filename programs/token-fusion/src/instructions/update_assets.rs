@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use mpl_core::{accounts::BaseAssetV1, types::UpdateAuthority, ID as CORE_PROGRAM_ID};
+
+use crate::{
+    constants::{AUTHORITY_SEED, DATA_SEED},
+    errors::FusionError,
+    instructions::get_asset_name_and_uri,
+    utils::{
+        cmp_pubkeys, find_asset_record_address, update_asset_v1, AssetV1Accounts, UpdateV1Args,
+    },
+    AssetRecordV1, FusionDataV1,
+};
+
+/// Accounts for CPI calls
+pub(crate) struct UpdateAssetsAccountsV1<'info> {
+    // payer
+    pub payer: AccountInfo<'info>,
+    // program pda
+    pub authority_pda: AccountInfo<'info>,
+    // asset related accounts
+    pub collection: AccountInfo<'info>,
+    // programs
+    pub core_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub log_wrapper: Option<AccountInfo<'info>>,
+}
+
+/// Asset Update Struct
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AssetUpdateV1 {
+    /// Explicit asset name, overrides the asset data scheme
+    pub name: Option<String>,
+    /// Explicit asset uri, overrides the asset data scheme
+    pub uri: Option<String>,
+}
+
+pub fn handler_update_assets_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateAssetsV1Ctx<'info>>,
+    assets: Vec<AssetUpdateV1>,
+) -> Result<()> {
+    let fusion = &ctx.accounts.fusion_data;
+
+    // every asset needs its own update
+    require!(
+        ctx.remaining_accounts.len() == assets.len() * 2,
+        FusionError::InvalidRemainingAccounts
+    );
+
+    let accounts = UpdateAssetsAccountsV1 {
+        payer: ctx.accounts.authority.to_account_info(),
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        collection: ctx.accounts.collection.to_account_info(),
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    // explicit values or the current asset data scheme for the recorded metadata index
+    process_update_assets(
+        fusion,
+        &accounts,
+        ctx.remaining_accounts,
+        ctx.bumps.authority_pda,
+        |position, record| {
+            let (name, uri) = get_asset_name_and_uri(fusion, record.index, record)?;
+            let asset = &assets[position];
+
+            Ok(UpdateV1Args {
+                name: Some(asset.name.clone().unwrap_or(name)),
+                uri: Some(asset.uri.clone().unwrap_or(uri)),
+            })
+        },
+    )
+}
+
+/// Loads the record of an asset to update, the metadata index is read from the record.
+fn load_asset_record(
+    fusion: &Account<'_, FusionDataV1>,
+    asset: &AccountInfo,
+    asset_record: &AccountInfo,
+) -> Result<AssetRecordV1> {
+    let (asset_record_address, _) = find_asset_record_address(&fusion.key(), asset.key);
+    if !cmp_pubkeys(asset_record.key, &asset_record_address) {
+        return err!(FusionError::InvalidAssetRecord);
+    }
+
+    // record must be initialized and owned by the program
+    if asset_record.data_is_empty() {
+        return err!(FusionError::InvalidAssetRecord);
+    }
+    if !cmp_pubkeys(asset_record.owner, &crate::ID) {
+        return err!(FusionError::IncorrectOwner);
+    }
+
+    AssetRecordV1::try_deserialize(&mut &asset_record.try_borrow_data()?[..])
+}
+
+/// Updates the assets passed as remaining accounts, `[asset, asset record]` per asset,
+/// using the authority pda update delegate.
+pub(crate) fn process_update_assets<'info>(
+    fusion: &Account<'_, FusionDataV1>,
+    accounts: &UpdateAssetsAccountsV1<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    bump: u8,
+    mut get_update: impl FnMut(usize, &AssetRecordV1) -> Result<UpdateV1Args>,
+) -> Result<()> {
+    // (1) sanity checks
+
+    // every asset comes with its record
+    let assets = remaining_accounts.chunks_exact(2);
+    require!(
+        assets.remainder().is_empty(),
+        FusionError::InvalidRemainingAccounts
+    );
+
+    // double check that we got the correct collection
+    // also checked in account constraints
+    if !cmp_pubkeys(&accounts.collection.key(), &fusion.collection) {
+        return err!(FusionError::CollectionKeyMismatch);
+    }
+
+    // (2) updating
+    for (position, asset_accounts) in assets.enumerate() {
+        let (asset, asset_record) = (&asset_accounts[0], &asset_accounts[1]);
+
+        // asset must be owned by mpl_core program
+        if !cmp_pubkeys(asset.owner, &CORE_PROGRAM_ID) {
+            return err!(FusionError::IncorrectOwner);
+        }
+
+        // check that the collection of the asset is correct
+        let asset_metadata = BaseAssetV1::try_from(asset)?;
+        match asset_metadata.update_authority {
+            UpdateAuthority::Collection(collection) => {
+                if !cmp_pubkeys(&collection, &fusion.collection) {
+                    return err!(FusionError::CollectionKeyMismatch);
+                }
+            }
+            _ => return err!(FusionError::MissingCollectionMint),
+        }
+
+        let record = load_asset_record(fusion, asset, asset_record)?;
+        let args = get_update(position, &record)?;
+
+        msg!("Asset: {} updated {:?}", asset_metadata.name, args);
+
+        let asset_accounts = AssetV1Accounts {
+            asset: asset.to_account_info(),
+            collection: Some(accounts.collection.to_account_info()),
+            payer: accounts.payer.to_account_info(),
+            authority: Some(accounts.authority_pda.to_account_info()),
+            core_program: accounts.core_program.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            log_wrapper: accounts
+                .log_wrapper
+                .as_ref()
+                .map(|log_wrapper| log_wrapper.to_account_info()),
+        };
+
+        update_asset_v1(
+            asset_accounts,
+            args,
+            [AUTHORITY_SEED.as_bytes(), fusion.key().as_ref(), &[bump]],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Updates the name and uri of minted assets.
+#[derive(Accounts)]
+pub struct UpdateAssetsV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(has_one = authority, has_one = collection, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority PDA, update delegate of the collection.
+    ///
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority and payer of the transaction.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Collection account
+    ///
+    /// CHECK: account checked in fusion data constraint
+    #[account(mut)]
+    collection: UncheckedAccount<'info>,
+
+    /// MPL Core program.
+    /// CHECK: checked by account constraint
+    #[account(address = CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// The SPL Noop program.
+    /// CHECK: Checked in mpl-core.
+    log_wrapper: Option<AccountInfo<'info>>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use mpl_core::{types::Plugin, ID as CORE_PROGRAM_ID};
+
+use crate::{
+    constants::{AUTHORITY_SEED, DATA_SEED},
+    utils::{
+        update_collection_plugin_v1, CollectionPluginAuthorityV1Accounts,
+        UpdateCollectionPluginV1Args,
+    },
+    CollectionPluginV1, FusionDataV1,
+};
+
+pub fn handler_update_collection_plugin_v1(
+    ctx: Context<UpdateCollectionPluginV1Ctx>,
+    plugin: CollectionPluginV1,
+) -> Result<()> {
+    // validation
+    plugin.validate()?;
+
+    let accounts = CollectionPluginAuthorityV1Accounts {
+        collection: ctx.accounts.collection.to_account_info(),
+        payer: ctx.accounts.authority.to_account_info(),
+        authority: Some(ctx.accounts.authority_pda.to_account_info()),
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    let args = UpdateCollectionPluginV1Args {
+        plugin: Plugin::from(&plugin),
+    };
+
+    update_collection_plugin_v1(
+        accounts,
+        args,
+        [
+            AUTHORITY_SEED.as_bytes(),
+            ctx.accounts.fusion_data.key().as_ref(),
+            &[ctx.bumps.authority_pda],
+        ],
+    )
+}
+
+/// Updates a plugin of the collection through the authority pda delegate.
+#[derive(Accounts)]
+pub struct UpdateCollectionPluginV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(has_one = authority, has_one = collection, seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority PDA, update delegate of the collection.
+    ///
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority and payer of the transaction.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Collection account
+    ///
+    /// CHECK: account checked in fusion data constraint
+    #[account(mut)]
+    collection: UncheckedAccount<'info>,
+
+    /// MPL Core program.
+    /// CHECK: checked by account constraint
+    #[account(address = CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// The SPL Noop program.
+    /// CHECK: Checked in mpl-core.
+    log_wrapper: Option<AccountInfo<'info>>,
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount, Transfer},
+};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    types::UpdateAuthority,
+    ID as CORE_PROGRAM_ID,
+};
+
+use crate::{
+    constants::{AUTHORITY_SEED, DATA_SEED, PROTOCOL_FEE, PROTOCOL_FEE_WALLET, RECORD_SEED},
+    errors::FusionError,
+    instructions::process_record_amount,
+    utils::{sol_transfer, AssetV1Accounts},
+    AssetRecordV1, FusionDataV1,
+};
+
+pub fn handler_withdraw_partial_v1(ctx: Context<WithdrawPartialV1Ctx>, amount: u64) -> Result<()> {
+    let fusion = &ctx.accounts.fusion_data;
+    let record = &mut ctx.accounts.asset_record;
+    let accounts = AssetV1Accounts {
+        asset: ctx.accounts.asset.to_account_info(),
+        collection: Some(ctx.accounts.collection.to_account_info()),
+        payer: ctx.accounts.user.to_account_info(),
+        authority: Some(ctx.accounts.authority_pda.to_account_info()),
+        core_program: ctx.accounts.core_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+    };
+
+    // (1) sanity checks
+
+    // is not paused
+    fusion.validate()?;
+
+    // only variable backed assets are partially withdrawn
+    let backing = fusion.backing.as_ref().ok_or(FusionError::InvalidBacking)?;
+    require!(record.is_variable(), FusionError::InvalidAssetRecord);
+
+    // the rest of the backing should stay above the min remaining amount
+    let remaining = record
+        .amount
+        .checked_sub(amount)
+        .ok_or(FusionError::InsufficientBacking)?;
+    require!(
+        amount > 0 && remaining >= backing.min_remaining,
+        FusionError::InsufficientBacking
+    );
+
+    // (2) decrease the recorded backing
+    process_record_amount(fusion, record, accounts, remaining, ctx.bumps.authority_pda)?;
+
+    // (3) transfer
+    let fusion_key = fusion.key();
+    let authority_seeds = &[
+        AUTHORITY_SEED.as_bytes(),
+        fusion_key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.escrow_ata_pda.to_account_info(),
+            to: ctx.accounts.user_ata.to_account_info(),
+            authority: ctx.accounts.authority_pda.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    anchor_spl::token::transfer(cpi_ctx, amount)?;
+    msg!("Escrow: {} SPL", amount);
+
+    sol_transfer(
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.fee_account.to_account_info(),
+        PROTOCOL_FEE,
+    )?;
+
+    Ok(())
+}
+
+/// Withdraw part of the recorded backing of an asset.
+#[derive(Accounts)]
+pub struct WithdrawPartialV1Ctx<'info> {
+    /// Fusion data account.
+    #[account(seeds = [DATA_SEED.as_bytes(), fusion_data.collection.as_ref()], bump)]
+    fusion_data: Account<'info, FusionDataV1>,
+
+    /// Authority pda.
+    /// CHECK: account checked in seeds constraint
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), fusion_data.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Asset owner, transaction and rent payer.
+    #[account(mut)]
+    user: Signer<'info>,
+
+    /// Account of the asset.
+    #[account(
+        mut,
+        constraint = asset.owner == user.key(),
+        constraint = asset.update_authority == UpdateAuthority::Collection(collection.key()),
+    )]
+    asset: Account<'info, BaseAssetV1>,
+
+    /// Asset record account.
+    #[account(
+        mut,
+        seeds = [RECORD_SEED.as_bytes(), fusion_data.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    asset_record: Account<'info, AssetRecordV1>,
+
+    /// Collection account.
+    #[account(mut, address = fusion_data.collection, owner = CORE_PROGRAM_ID)]
+    collection: Account<'info, BaseCollectionV1>,
+
+    /// Mint account of the token.
+    #[account(address = fusion_data.token_mint)]
+    token_mint: Account<'info, Mint>,
+
+    /// Token escrow pda ata account.
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = authority_pda
+    )]
+    escrow_ata_pda: Account<'info, TokenAccount>,
+
+    /// User ata account, will be initialized if needed.
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_mint,
+        associated_token::authority = user
+    )]
+    user_ata: Account<'info, TokenAccount>,
+
+    /// Protocol fee account.
+    /// CHECK: checked by account constraint
+    #[account(
+        mut,
+        address = PROTOCOL_FEE_WALLET @ FusionError::InvalidProtocolFeeWallet
+    )]
+    fee_account: UncheckedAccount<'info>,
+
+    /// Token program.
+    token_program: Program<'info, Token>,
+
+    /// Associated Token program.
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    /// MPL Core program.
+    /// CHECK: checked by account constraint
+    #[account(address = CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// The SPL Noop program.
+    /// CHECK: Checked in mpl-core.
+    log_wrapper: Option<AccountInfo<'info>>,
+}
@@ -24,8 +24,8 @@ pub mod token_fusion {
     ///
     /// # Input accounts
     ///
-    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data"]`)
-    ///   1. `[writable]` Authority PDA (seeds `[b"authority"]`)
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[writable]` Authority PDA (seeds `[b"authority", fusion_data]`)
     ///   2. `[signer]` Collection update authority
     ///   3. `[signer]` Payer of the transaction
     ///   4. `[]` Token mint
@@ -47,53 +47,82 @@ pub mod token_fusion {
     /// Fusion tokens into Asset.
     ///
     /// User's tokens transferred to the escrow and optionally partially burned
-    /// and new Asset is minted in exchange.
+    /// and new Asset is minted in exchange. While the vault is not empty, a vaulted
+    /// Asset is handed out instead, it must have been recorded when minted. While the fusion has tiers, the Asset is minted in
+    /// the chosen tier with the tier fees and recorded. With the variable backing, the chosen
+    /// amount replaces the escrow amount. Every minted Asset is recorded, so that only Assets
+    /// minted by the program can be redeemed. The Asset is registered in the index account of
+    /// its mint index, the next index of the fusion, along with its metadata index. The Asset is
+    /// owned by the recipient if set, otherwise by the user. The rent and fees are paid by
+    /// the payer, which can differ from the user.
     ///
     /// # Accounts
     ///
-    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data"]`)
-    ///   1. `[writable]` Authority PDA (seeds `[b"authority"]`)
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[writable]` Authority PDA (seeds `[b"authority", fusion_data]`)
     ///   2. `[signer]` User
-    ///   3. `[signer]` Asset account
-    ///   4. `[writable]` Collection account
-    ///   5. `[]` Token mint
-    ///   6. `[]` Escrow ata with authority_pda as authority
-    ///   7. `[]` User ata with user as authority
-    ///   8. `[optional]` Fee recipient account
-    ///   9. `[optional]` Fee recipient ata
-    ///   10. `[]` Fee sol account
-    ///   11. `[]` Token program
-    ///   12.`[]` Associated Token program
-    ///   13.`[]` Core program
-    ///   14.`[]` System program
-    ///   15.`[optional]` SPL Noop program
+    ///   3. `[writable, signer]` Payer of the rent and fees
+    ///   4. `[writable]` Asset account (signer or PDA with seeds `[b"asset", fusion_data, next_index]`, or a vaulted asset)
+    ///   5. `[writable]` Collection account
+    ///   6. `[]` Token mint
+    ///   7. `[]` Escrow ata with authority_pda as authority
+    ///   8. `[]` User token account with user as owner or delegate
+    ///   9. `[optional]` Fee recipient account
+    ///   10.`[optional]` Fee recipient ata
+    ///   11.`[]` Fee sol account
+    ///   12.`[]` Token program
+    ///   13.`[]` Associated Token program
+    ///   14.`[]` Core program
+    ///   15.`[]` System program
+    ///   16.`[optional]` SPL Noop program
+    ///   17.`[optional, writable]` Metadata pool (seeds `[b"metadata_pool", fusion_data]`)
+    ///   18.`[optional]` Slot hashes sysvar
+    ///   19.`[optional, writable]` Asset record (seeds `[b"asset_record", fusion_data, asset]`), required when minting
+    ///   20.`[optional]` Recipient account, owner of the Asset instead of the user
+    ///   21.`[optional, writable]` Asset index (seeds `[b"asset_index", fusion_data, next_index]`), required when minting
+    ///   22.`[optional]` Vaulted asset record (seeds `[b"asset_record", fusion_data, asset]`), required when handing out a vaulted Asset
+    ///   23.`[writable]` Ingredient accounts, `[mint, user token account, escrow ata, fee recipient ata]` per ingredient, without the fee recipient ata if it has no fee (remaining accounts)
     pub fn fusion_into_v1<'info>(
         ctx: Context<'_, '_, '_, 'info, FusionIntoV1Ctx<'info>>,
+        tier: Option<u8>,
+        amount: Option<u64>,
     ) -> Result<()> {
-        instructions::handler_fusion_into_v1(ctx)
+        instructions::handler_fusion_into_v1(ctx, tier, amount)
     }
 
     /// Fusion tokens from Asset.
     ///
-    /// User's asset is burned and corresponding amount of tokens are transferred
-    /// to the user's account from the escrow.
+    /// User's asset is burned, or locked in the vault in vault redeem mode, and
+    /// corresponding amount of tokens are transferred to the user's account from the escrow,
+    /// or to the recipient's account if set. The rent and fees are paid by the payer, which
+    /// can differ from the user. The index account of a burned asset is closed, and its
+    /// metadata index is released back to the pool in recycled metadata mode. The burn
+    /// delegate of the asset in burn redeem mode, or its transfer delegate in vault redeem
+    /// mode, can redeem it on behalf of the owner, the owner must then be the recipient.
     ///
     /// # Accounts
     ///
-    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data"]`)
-    ///   1. `[writable]` Authority PDA (seeds `[b"authority"]`)
-    ///   2. `[signer]` User
-    ///   3. `[signer]` Asset account
-    ///   4. `[writable]` Collection account
-    ///   5. `[]` Token mint
-    ///   6. `[]` Escrow ata with authority_pda as authority
-    ///   7. `[]` User ata with user as authority
-    ///   8. `[]` Fee sol account
-    ///   9. `[]` Token program
-    ///   10.`[]` Associated Token program
-    ///   11.`[]` Core program
-    ///   12.`[]` System program
-    ///   13.`[optional]` SPL Noop program
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[writable]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[writable, signer]` User, asset owner or delegate, receives the rent of the asset index
+    ///   3. `[writable, signer]` Payer of the rent and fees
+    ///   4. `[signer]` Asset account
+    ///   5. `[writable]` Collection account
+    ///   6. `[]` Token mint
+    ///   7. `[]` Escrow ata with authority_pda as authority
+    ///   8. `[optional, writable]` User ata with user as authority, required without a recipient
+    ///   9. `[]` Fee sol account
+    ///   10.`[]` Token program
+    ///   11.`[]` Associated Token program
+    ///   12.`[]` Core program
+    ///   13.`[]` System program
+    ///   14.`[optional]` SPL Noop program
+    ///   15.`[writable]` Asset record (seeds `[b"asset_record", fusion_data, asset]`), assets without a record are rejected
+    ///   16.`[optional, writable]` Asset index (seeds `[b"asset_index", fusion_data, mint_index]`), required in burn redeem mode
+    ///   17.`[optional]` Recipient account
+    ///   18.`[optional, writable]` Recipient ata with recipient as authority, required with the recipient
+    ///   19.`[optional, writable]` Metadata pool (seeds `[b"metadata_pool", fusion_data]`), required in recycled metadata mode
+    ///   20.`[writable]` Ingredient accounts, `[escrow ata, destination token account]` per ingredient (remaining accounts)
     pub fn fusion_from_v1<'info>(
         ctx: Context<'_, '_, '_, 'info, FusionFromV1Ctx<'info>>,
     ) -> Result<()> {
@@ -104,7 +133,7 @@ pub mod token_fusion {
     ///
     /// # Accounts
     ///
-    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data"]`)
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
     ///   1. `[signer]` current authority
     pub fn set_authority_v1(ctx: Context<SetAuthorityV1Ctx>, new_authority: Pubkey) -> Result<()> {
         instructions::handler_set_authority_v1(ctx, new_authority)
@@ -112,10 +141,14 @@ pub mod token_fusion {
 
     /// Update the fusion data account with the specified data.
     ///
+    /// The max supply can not change once the metadata pool is initialized, and the metadata
+    /// mode can not switch into or out of recycled once assets were minted outside the pool.
+    ///
     /// # Accounts
     ///
-    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data"]`)
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
     ///   1. `[signer]` authority
+    ///   2. `[]` Metadata pool (seeds `[b"metadata_pool", fusion_data]`), may be uninitialized
     pub fn update_v1(
         ctx: Context<UpdateV1Ctx>,
         asset_data: AssetDataV1,
@@ -128,8 +161,8 @@ pub mod token_fusion {
     ///
     /// # Accounts
     ///
-    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data"]`)
-    ///   1. `[writable]` Authority PDA (seeds `[b"authority"]`)
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[writable]` Authority PDA (seeds `[b"authority", fusion_data]`)
     ///   2. `[signer]` authority
     ///   3. `[]` Token Mint account
     ///   4. `[writable]` Escrow ata with authority_pda as authority
@@ -140,7 +173,10 @@ pub mod token_fusion {
     ///   9. `[]` Core program
     ///   10.`[]` System program
     ///   11.`[]` SPL Noop program
-    pub fn destroy_v1(ctx: Context<DestroyV1Ctx>) -> Result<()> {
+    ///   12.`[writable]` Ingredient accounts, `[escrow ata, authority token account]` per ingredient (remaining accounts)
+    pub fn destroy_v1<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DestroyV1Ctx<'info>>,
+    ) -> Result<()> {
         instructions::handler_destroy_v1(ctx)
     }
 
@@ -148,9 +184,488 @@ pub mod token_fusion {
     ///
     /// # Accounts
     ///
-    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data"]`)
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
     ///   1. `[signer]` authority
     pub fn set_pause_v1(ctx: Context<SetPauseV1Ctx>, paused: bool) -> Result<()> {
         instructions::handler_set_pause_v1(ctx, paused)
     }
+
+    /// Add a plugin to the collection using the authority PDA update delegate.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` authority
+    ///   3. `[writable]` Collection account
+    ///   4. `[]` Core program
+    ///   5. `[]` System program
+    ///   6. `[optional]` SPL Noop program
+    pub fn add_collection_plugin_v1(
+        ctx: Context<AddCollectionPluginV1Ctx>,
+        plugin: CollectionPluginV1,
+    ) -> Result<()> {
+        instructions::handler_add_collection_plugin_v1(ctx, plugin)
+    }
+
+    /// Update a plugin of the collection using the authority PDA update delegate.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` authority
+    ///   3. `[writable]` Collection account
+    ///   4. `[]` Core program
+    ///   5. `[]` System program
+    ///   6. `[optional]` SPL Noop program
+    pub fn update_collection_plugin_v1(
+        ctx: Context<UpdateCollectionPluginV1Ctx>,
+        plugin: CollectionPluginV1,
+    ) -> Result<()> {
+        instructions::handler_update_collection_plugin_v1(ctx, plugin)
+    }
+
+    /// Remove a plugin from the collection using the authority PDA update delegate.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` authority
+    ///   3. `[writable]` Collection account
+    ///   4. `[]` Core program
+    ///   5. `[]` System program
+    ///   6. `[optional]` SPL Noop program
+    pub fn remove_collection_plugin_v1(
+        ctx: Context<RemoveCollectionPluginV1Ctx>,
+        plugin_type: CollectionPluginTypeV1,
+    ) -> Result<()> {
+        instructions::handler_remove_collection_plugin_v1(ctx, plugin_type)
+    }
+
+    /// Initialize the metadata pool used to draw random or recycled metadata indexes.
+    ///
+    /// The pool covers `max_supply` indexes starting from the current `next_index`.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[writable]` Metadata pool (seeds `[b"metadata_pool", fusion_data]`)
+    ///   2. `[signer]` authority
+    ///   3. `[]` System program
+    pub fn init_metadata_pool_v1(ctx: Context<InitMetadataPoolV1Ctx>) -> Result<()> {
+        instructions::handler_init_metadata_pool_v1(ctx)
+    }
+
+    /// Reveal the asset metadata.
+    ///
+    /// Sets the real uri prefix and reveals the hash salt, if provided, and drops the
+    /// placeholder uri so the next minted assets get their final uri. Already minted assets passed as remaining accounts
+    /// are updated to the revealed uri of the metadata index stored in their record, so the reveal
+    /// can be batched across multiple transactions.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` authority
+    ///   3. `[writable]` Collection account
+    ///   4. `[]` Core program
+    ///   5. `[]` System program
+    ///   6. `[optional]` SPL Noop program
+    ///   7. `[writable]` Asset accounts, `[asset, asset record]` per asset (remaining accounts)
+    pub fn reveal_v1<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealV1Ctx<'info>>,
+        uri_prefix: Option<String>,
+        salt: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::handler_reveal_v1(ctx, uri_prefix, salt)
+    }
+
+    /// Update the name and uri of minted assets.
+    ///
+    /// Each asset is rewritten according to the current asset data scheme for the metadata
+    /// index stored in its record, unless explicit values are provided.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` authority
+    ///   3. `[writable]` Collection account
+    ///   4. `[]` Core program
+    ///   5. `[]` System program
+    ///   6. `[optional]` SPL Noop program
+    ///   7. `[writable]` Asset accounts, `[asset, asset record]` per update (remaining accounts)
+    pub fn update_assets_v1<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateAssetsV1Ctx<'info>>,
+        assets: Vec<AssetUpdateV1>,
+    ) -> Result<()> {
+        instructions::handler_update_assets_v1(ctx, assets)
+    }
+
+    /// Initialize the Bubblegum merkle tree of compressed assets.
+    ///
+    /// The merkle tree account must be allocated by the client beforehand. The authority PDA
+    /// is the creator of a private tree, so only the program is able to mint into it.
+    /// The tree can only be replaced once all compressed assets are redeemed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` authority
+    ///   3. `[writable]` Merkle tree account
+    ///   4. `[writable]` Tree config (seeds `[merkle_tree]` of the Bubblegum program)
+    ///   5. `[]` Bubblegum program
+    ///   6. `[]` SPL Account Compression program
+    ///   7. `[]` SPL Noop program
+    ///   8. `[]` System program
+    pub fn init_tree_v1(
+        ctx: Context<InitTreeV1Ctx>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        instructions::handler_init_tree_v1(ctx, max_depth, max_buffer_size)
+    }
+
+    /// Fusion tokens into a compressed Asset.
+    ///
+    /// Same as `fusion_into_v1`, but a compressed leaf is minted into the fusion merkle tree
+    /// instead of a Core asset. Requires the compressed output mode.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` User
+    ///   3. `[]` Collection account
+    ///   4. `[]` Token mint
+    ///   5. `[]` Escrow ata with authority_pda as authority
+    ///   6. `[]` User token account with user as owner or delegate
+    ///   7. `[optional]` Fee recipient account
+    ///   8. `[optional]` Fee recipient ata
+    ///   9. `[]` Fee sol account
+    ///   10.`[writable]` Merkle tree account
+    ///   11.`[writable]` Tree config (seeds `[merkle_tree]` of the Bubblegum program)
+    ///   12.`[]` Token program
+    ///   13.`[]` Associated Token program
+    ///   14.`[]` Bubblegum program
+    ///   15.`[]` SPL Account Compression program
+    ///   16.`[]` SPL Noop program
+    ///   17.`[]` System program
+    ///   18.`[optional, writable]` Metadata pool (seeds `[b"metadata_pool", fusion_data]`)
+    ///   19.`[optional]` Slot hashes sysvar
+    ///   20.`[writable]` Ingredient accounts, same as in `fusion_into_v1` (remaining accounts)
+    pub fn fusion_into_compressed_v1<'info>(
+        ctx: Context<'_, '_, '_, 'info, FusionIntoCompressedV1Ctx<'info>>,
+    ) -> Result<()> {
+        instructions::handler_fusion_into_compressed_v1(ctx)
+    }
+
+    /// Fusion tokens from a compressed Asset.
+    ///
+    /// User's compressed leaf is verified against the proof and burned, and corresponding
+    /// amount of tokens are transferred to the user's account from the escrow.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` User, the leaf owner
+    ///   3. `[optional]` Leaf delegate, defaults to the leaf owner
+    ///   4. `[]` Token mint
+    ///   5. `[]` Escrow ata with authority_pda as authority
+    ///   6. `[]` User ata with user as authority
+    ///   7. `[]` Fee sol account
+    ///   8. `[writable]` Merkle tree account
+    ///   9. `[]` Tree config (seeds `[merkle_tree]` of the Bubblegum program)
+    ///   10.`[]` Token program
+    ///   11.`[]` Associated Token program
+    ///   12.`[]` Bubblegum program
+    ///   13.`[]` SPL Account Compression program
+    ///   14.`[]` SPL Noop program
+    ///   15.`[]` System program
+    ///   16.`[writable]` Ingredient accounts, same as in `fusion_from_v1` (remaining accounts)
+    ///   17.`[]` Proof nodes, after the ingredient accounts (remaining accounts)
+    pub fn fusion_from_compressed_v1<'info>(
+        ctx: Context<'_, '_, '_, 'info, FusionFromCompressedV1Ctx<'info>>,
+        leaf: CompressedLeafV1,
+    ) -> Result<()> {
+        instructions::handler_fusion_from_compressed_v1(ctx, leaf)
+    }
+
+    /// Set the evolution link to a higher tier fusion.
+    ///
+    /// Both fusions must share the token mint and the target authority must sign to link.
+    /// Passing `None` removes the link.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[signer]` authority
+    ///   2. `[optional]` Target fusion data account, required when setting the link
+    ///   3. `[optional, signer]` Target fusion authority, required when setting the link
+    pub fn set_evolution_v1(
+        ctx: Context<SetEvolutionV1Ctx>,
+        evolution: Option<EvolutionDataV1>,
+    ) -> Result<()> {
+        instructions::handler_set_evolution_v1(ctx, evolution)
+    }
+
+    /// Evolve assets into an asset of the linked higher tier fusion.
+    ///
+    /// `burn_count` assets of the source collection are burned and their backing is moved
+    /// from the source escrow to the target escrow, up to the target escrow amount. The rest of
+    /// the target escrow amount is taken from the user, up to the evolution token amount, and the
+    /// backing above the target escrow amount is refunded to the user, so the target escrow holds
+    /// exactly the escrow amount of the minted asset. Then an asset of the target collection
+    /// is minted in exchange. Only source
+    /// assets with a record are evolved, their records are closed and their metadata indexes
+    /// are released back to the source pool in recycled metadata mode.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Source fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Source authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[writable]` Source collection account
+    ///   3. `[writable]` Source escrow ata with source authority_pda as authority
+    ///   4. `[writable]` Target fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   5. `[]` Target authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   6. `[writable]` Target collection account
+    ///   7. `[writable]` Target escrow ata with target authority_pda as authority
+    ///   8. `[signer]` User
+    ///   9. `[writable]` Target asset account (signer, PDA or a vaulted asset)
+    ///   10.`[]` Token mint
+    ///   11.`[writable]` User ata with user as authority
+    ///   12.`[]` Fee sol account
+    ///   13.`[]` Token program
+    ///   14.`[]` Associated Token program
+    ///   15.`[]` Core program
+    ///   16.`[]` System program
+    ///   17.`[optional]` SPL Noop program
+    ///   18.`[optional, writable]` Target metadata pool (seeds `[b"metadata_pool", fusion_data]`)
+    ///   19.`[optional]` Slot hashes sysvar
+    ///   20.`[optional, writable]` Target asset record (seeds `[b"asset_record", target_fusion_data, asset]`), required when minting
+    ///   21.`[optional, writable]` Target asset index (seeds `[b"asset_index", target_fusion_data, next_index]`), required when minting
+    ///   22.`[optional, writable]` Source metadata pool (seeds `[b"metadata_pool", source_fusion_data]`), required in recycled metadata mode
+    ///   23.`[optional]` Vaulted target asset record (seeds `[b"asset_record", target_fusion_data, asset]`), required when handing out a vaulted asset
+    ///   24.`[writable]` Source asset accounts to burn, `[asset, asset record, asset index]` per asset (remaining accounts)
+    pub fn evolve_v1<'info>(ctx: Context<'_, '_, '_, 'info, EvolveV1Ctx<'info>>) -> Result<()> {
+        instructions::handler_evolve_v1(ctx)
+    }
+
+    /// Set the additional ingredient tokens of the recipe.
+    ///
+    /// Every ingredient has its own escrow, burn and fee amounts, and its own escrow ata,
+    /// which is created if needed. Passing an empty list leaves only the primary token.
+    /// The recipe can only change while no Asset of the collection is in circulation.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[writable, signer]` authority
+    ///   3. `[]` Token program
+    ///   4. `[]` Associated Token program
+    ///   5. `[]` System program
+    ///   6. `[]` Collection account
+    ///   7. `[writable]` Ingredient accounts, `[mint, escrow ata]` per ingredient (remaining accounts)
+    pub fn set_ingredients_v1<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetIngredientsV1Ctx<'info>>,
+        ingredients: Vec<IngredientV1>,
+    ) -> Result<()> {
+        instructions::handler_set_ingredients_v1(ctx, ingredients)
+    }
+
+    /// Set the denomination tiers of the fusion.
+    ///
+    /// Every tier has its own escrow amount, fees, supply cap and name/uri templates.
+    /// While the fusion has tiers, `fusion_into_v1` mints in the chosen tier and records it,
+    /// and `fusion_from_v1` pays the recorded amount. Tier supply is managed by the program.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[signer]` authority
+    pub fn set_tiers_v1(ctx: Context<SetTiersV1Ctx>, tiers: Vec<TierV1>) -> Result<()> {
+        instructions::handler_set_tiers_v1(ctx, tiers)
+    }
+
+    /// Set the variable backing of the fusion.
+    ///
+    /// With the variable backing, any amount between the min and max amounts is fused into
+    /// a single asset, the amount is recorded and its bracket rendered in the `{bracket}`
+    /// placeholder of the templates. Passing `None` removes it once no variable backed
+    /// assets are left in circulation.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[signer]` authority
+    pub fn set_backing_v1(
+        ctx: Context<SetBackingV1Ctx>,
+        backing: Option<BackingDataV1>,
+    ) -> Result<()> {
+        instructions::handler_set_backing_v1(ctx, backing)
+    }
+
+    /// Withdraw part of the backing of a variable backed Asset.
+    ///
+    /// Tokens are transferred to the user's account from the escrow and the recorded amount
+    /// is decreased, without burning the Asset. The recorded amount left must not be less
+    /// than the min remaining amount of the variable backing. The name and uri of the Asset
+    /// are updated if its amount bracket changes.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` User
+    ///   3. `[writable]` Asset account
+    ///   4. `[writable]` Asset record (seeds `[b"asset_record", fusion_data, asset]`)
+    ///   5. `[writable]` Collection account
+    ///   6. `[]` Token mint
+    ///   7. `[writable]` Escrow ata with authority_pda as authority
+    ///   8. `[writable]` User ata with user as authority
+    ///   9. `[]` Fee sol account
+    ///   10.`[]` Token program
+    ///   11.`[]` Associated Token program
+    ///   12.`[]` Core program
+    ///   13.`[]` System program
+    ///   14.`[optional]` SPL Noop program
+    pub fn withdraw_partial_v1(ctx: Context<WithdrawPartialV1Ctx>, amount: u64) -> Result<()> {
+        instructions::handler_withdraw_partial_v1(ctx, amount)
+    }
+
+    /// Top up the backing of a variable backed Asset.
+    ///
+    /// User's tokens are transferred to the escrow after the fees of the fee data and the
+    /// recorded amount is increased up to the max amount of the variable backing. The name
+    /// and uri of the Asset are updated if its amount bracket changes.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` User
+    ///   3. `[writable]` Asset account
+    ///   4. `[writable]` Asset record (seeds `[b"asset_record", fusion_data, asset]`)
+    ///   5. `[writable]` Collection account
+    ///   6. `[writable]` Token mint
+    ///   7. `[writable]` Escrow ata with authority_pda as authority
+    ///   8. `[writable]` User ata with user as authority
+    ///   9. `[optional]` Fee recipient account
+    ///   10.`[optional]` Fee recipient ata
+    ///   11.`[]` Fee sol account
+    ///   12.`[]` Token program
+    ///   13.`[]` Associated Token program
+    ///   14.`[]` Core program
+    ///   15.`[]` System program
+    ///   16.`[optional]` SPL Noop program
+    pub fn top_up_v1(ctx: Context<TopUpV1Ctx>, amount: u64) -> Result<()> {
+        instructions::handler_top_up_v1(ctx, amount)
+    }
+
+    /// Merge two variable backed Assets into one.
+    ///
+    /// The burned Asset is burned and its record closed, its metadata index is released back to
+    /// the pool in recycled metadata mode. Its recorded amount is added to the
+    /// remaining Asset up to the max amount of the variable backing. The name and uri of the
+    /// remaining Asset are updated if its amount bracket changes.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` User
+    ///   3. `[writable]` Asset account
+    ///   4. `[writable]` Asset record (seeds `[b"asset_record", fusion_data, asset]`)
+    ///   5. `[writable]` Burned asset account
+    ///   6. `[writable]` Burned asset record (seeds `[b"asset_record", fusion_data, burned_asset]`)
+    ///   7. `[writable]` Burned asset index (seeds `[b"asset_index", fusion_data, mint_index]`)
+    ///   8. `[writable]` Collection account
+    ///   9. `[]` Fee sol account
+    ///   10.`[]` Core program
+    ///   11.`[]` System program
+    ///   12.`[optional]` SPL Noop program
+    ///   13.`[optional, writable]` Metadata pool (seeds `[b"metadata_pool", fusion_data]`), required in recycled metadata mode
+    pub fn merge_assets_v1(ctx: Context<MergeAssetsV1Ctx>) -> Result<()> {
+        instructions::handler_merge_assets_v1(ctx)
+    }
+
+    /// Split a variable backed Asset into two.
+    ///
+    /// A new Asset is minted with the given amount of the recorded backing, the rest stays
+    /// on the split Asset. Both amounts must be between the min and max amounts of the
+    /// variable backing, and the new Asset respects the max supply.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   2. `[signer]` User
+    ///   3. `[writable]` Asset account
+    ///   4. `[writable]` Asset record (seeds `[b"asset_record", fusion_data, asset]`)
+    ///   5. `[writable]` New asset account (signer or PDA with seeds `[b"asset", fusion_data, next_index]`)
+    ///   6. `[writable]` New asset record (seeds `[b"asset_record", fusion_data, new_asset]`)
+    ///   7. `[writable]` New asset index (seeds `[b"asset_index", fusion_data, next_index]`)
+    ///   8. `[writable]` Collection account
+    ///   9. `[]` Fee sol account
+    ///   10.`[]` Core program
+    ///   11.`[]` System program
+    ///   12.`[optional]` SPL Noop program
+    ///   13.`[optional, writable]` Metadata pool (seeds `[b"metadata_pool", fusion_data]`)
+    ///   14.`[optional]` Slot hashes sysvar
+    pub fn split_asset_v1(ctx: Context<SplitAssetV1Ctx>, amount: u64) -> Result<()> {
+        instructions::handler_split_asset_v1(ctx, amount)
+    }
+
+    /// Migrate the fusion of the first version of the program.
+    ///
+    /// The legacy fusion data account is converted into the fusion data account of its
+    /// collection, keeping the names, uris and hashes of the minted Assets. The escrow is moved
+    /// to the new authority PDA, which becomes the update delegate of the collection, and the
+    /// legacy fusion data account and escrow ata are closed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Legacy fusion data account (seeds `[b"fusion_data"]`)
+    ///   1. `[]` Legacy authority PDA (seeds `[b"authority"]`)
+    ///   2. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   3. `[]` Authority PDA (seeds `[b"authority", fusion_data]`)
+    ///   4. `[writable, signer]` authority, collection update authority and payer
+    ///   5. `[]` Token mint
+    ///   6. `[writable]` Legacy escrow ata with legacy authority_pda as authority
+    ///   7. `[writable]` Escrow ata with authority_pda as authority
+    ///   8. `[writable]` Collection account
+    ///   9. `[]` Token program
+    ///   10.`[]` Associated Token program
+    ///   11.`[]` Core program
+    ///   12.`[]` System program
+    ///   13.`[optional]` SPL Noop program
+    pub fn migrate_v1(ctx: Context<MigrateV1Ctx>) -> Result<()> {
+        instructions::handler_migrate_v1(ctx)
+    }
+
+    /// Record the Assets minted by the first version of the program.
+    ///
+    /// Migrated Assets have no record and can not be redeemed, evolved or merged until the
+    /// authority records them. Each Asset gets a plain record backed by the escrow amount,
+    /// with the metadata index read from its legacy name, and the index account of that
+    /// metadata index, up to the number of Assets of the collection at the migration. Only
+    /// metadata indexes below the next index of the fusion at the migration are recorded.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` fusion data account (seeds `[b"fusion_data", collection]`)
+    ///   1. `[writable, signer]` authority and payer
+    ///   2. `[]` System program
+    ///   3. `[writable]` Asset accounts, `[asset, asset record, asset index]` per asset (remaining accounts)
+    pub fn record_assets_v1<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecordAssetsV1Ctx<'info>>,
+    ) -> Result<()> {
+        instructions::handler_record_assets_v1(ctx)
+    }
 }
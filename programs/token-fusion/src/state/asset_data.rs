@@ -1,8 +1,16 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hash};
 
 use crate::{
-    constants::{ASSET_NAME_PREFIX_MAX, ASSET_URI_PREFIX_MAX, ASSET_URI_SUFFIX_MAX},
+    constants::{
+        ASSET_HASH_FULL_LEN, ASSET_HASH_SHORT_LEN, ASSET_NAME_MAX, ASSET_NAME_PREFIX_MAX,
+        ASSET_NAME_TEMPLATE_MAX, ASSET_URI_MAX, ASSET_URI_PREFIX_MAX, ASSET_URI_SUFFIX_MAX,
+        ASSET_URI_TEMPLATE_MAX,
+    },
     errors::FusionError,
+    utils::{
+        get_asset_hash, get_legacy_asset_hash, render_template, validate_template, TemplateValues,
+    },
+    PluginDataV1,
 };
 
 /// Asset Data Struct
@@ -15,7 +23,7 @@ pub struct AssetDataV1 {
     pub next_index: u64,
 
     #[max_len(ASSET_NAME_PREFIX_MAX)]
-    /// Asset name prefix, i.e. `Token #`, max 10 symbols
+    /// Asset name prefix, i.e. `Token #`, max 32 symbols
     pub name_prefix: String,
 
     #[max_len(ASSET_URI_PREFIX_MAX)]
@@ -25,9 +33,214 @@ pub struct AssetDataV1 {
     #[max_len(ASSET_URI_SUFFIX_MAX)]
     /// Asset URI suffix, i.e. `.json`, max 5 symbols
     pub uri_suffix: String,
+
+    /// How the address of a new asset is derived
+    pub address_mode: AssetAddressModeV1,
+
+    /// Plugins applied to every minted asset
+    pub plugins: PluginDataV1,
+
+    /// How the metadata index of a new asset is assigned
+    pub metadata_mode: MetadataModeV1,
+
+    #[max_len(ASSET_URI_PREFIX_MAX)]
+    /// Optional placeholder URI for all minted assets until revealed, max 200 symbols
+    pub placeholder_uri: Option<String>,
+
+    #[max_len(ASSET_NAME_TEMPLATE_MAX)]
+    /// Optional asset name template, i.e. `Token #{index:05}`, overrides the name prefix,
+    /// supports `{index}`, `{index:0N}`, `{hash}`, `{collection}` and `{bracket}` placeholders,
+    /// max 64 symbols
+    pub name_template: Option<String>,
+
+    #[max_len(ASSET_URI_TEMPLATE_MAX)]
+    /// Optional asset URI template, i.e. `https://stf.org/{collection}/{index}.json?h={hash}`,
+    /// overrides the uri prefix and suffix, supports the same placeholders, max 200 symbols
+    pub uri_template: Option<String>,
+
+    /// How the asset hash in uris is computed
+    pub hash_mode: AssetHashModeV1,
+
+    /// Use the full hash instead of the short 8 symbols one
+    pub full_hash: bool,
+
+    /// Salt revealed by the authority, must match the commitment of the salted hash mode
+    pub hash_salt: Option<[u8; 32]>,
+
+    /// Whether assets are minted as Core assets or as compressed leaves
+    pub output_mode: AssetOutputModeV1,
+
+    /// What happens to an asset fused back into tokens
+    pub redeem_mode: AssetRedeemModeV1,
+}
+
+/// Asset Address Mode Enum
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace,
+)]
+pub enum AssetAddressModeV1 {
+    /// Asset account is a fresh keypair generated client-side and signing the transaction
+    #[default]
+    Keypair,
+    /// Asset account is a PDA derived from the fusion data account and the asset index
+    /// (seeds `[b"asset", fusion_data, next_index]`), signed by the program
+    Pda,
+}
+
+/// Asset Output Mode Enum
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace,
+)]
+pub enum AssetOutputModeV1 {
+    /// Assets are minted as MPL Core assets of the collection
+    #[default]
+    Core,
+    /// Assets are minted as Bubblegum compressed leaves into the merkle tree of the fusion
+    Compressed,
+}
+
+/// Asset Redeem Mode Enum
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace,
+)]
+pub enum AssetRedeemModeV1 {
+    /// Redeemed assets are burned
+    #[default]
+    Burn,
+    /// Redeemed assets are locked in the vault owned by the authority PDA
+    /// and handed out again by the next fusions before any new asset is minted
+    Vault,
+}
+
+/// Metadata Mode Enum
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace,
+)]
+pub enum MetadataModeV1 {
+    /// Metadata index is the sequential `next_index`
+    #[default]
+    Sequential,
+    /// Metadata index is drawn at random from the remaining indexes of the metadata pool
+    /// (seeds `[b"metadata_pool", fusion_data]`)
+    Random,
+    /// Metadata index is the lowest remaining index of the metadata pool,
+    /// indexes of burned assets are released back to the pool
+    Recycled,
+}
+
+/// Asset Hash Mode Enum
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace,
+)]
+pub enum AssetHashModeV1 {
+    /// Hash of the base58 collection, the metadata index and the protocol fee wallet strings,
+    /// as computed by the first version of the program
+    #[default]
+    Legacy,
+    /// No hash in asset uris
+    None,
+    /// Hash of the collection and the metadata index
+    Public,
+    /// Hash of the collection, the metadata index and a secret salt committed by the authority
+    /// as `sha256(salt)`, uris are only known once the salt is revealed
+    Salted { commitment: [u8; 32] },
 }
 
 impl AssetDataV1 {
+    /// Asset hash for the given metadata index, `None` if disabled or the salt is not revealed
+    pub fn asset_hash(&self, index: u64, collection: &Pubkey) -> Option<String> {
+        let hash = match self.hash_mode {
+            AssetHashModeV1::None => return None,
+            AssetHashModeV1::Legacy => get_legacy_asset_hash(index, collection),
+            AssetHashModeV1::Public => get_asset_hash(index, collection, None),
+            AssetHashModeV1::Salted { .. } => {
+                get_asset_hash(index, collection, Some(self.hash_salt.as_ref()?))
+            }
+        };
+
+        match self.full_hash {
+            true => Some(hash),
+            false => Some(hash[0..ASSET_HASH_SHORT_LEN].to_string()),
+        }
+    }
+
+    fn asset_hash_len(&self) -> usize {
+        match (self.hash_mode, self.full_hash) {
+            (AssetHashModeV1::None, _) => 0,
+            (_, true) => ASSET_HASH_FULL_LEN,
+            (_, false) => ASSET_HASH_SHORT_LEN,
+        }
+    }
+
+    /// Asset name for the given metadata index, i.e. `Token #1`
+    pub fn asset_name(&self, index: u64, collection: &Pubkey) -> String {
+        self.asset_name_from(self.name_template.as_ref(), index, 0, collection)
+    }
+
+    /// Asset name rendered from the given template and amount bracket,
+    /// the name prefix and the index if there is no template
+    pub fn asset_name_from(
+        &self,
+        name_template: Option<&String>,
+        index: u64,
+        bracket: u8,
+        collection: &Pubkey,
+    ) -> String {
+        match name_template {
+            Some(name_template) => render_template(
+                name_template,
+                &TemplateValues {
+                    index,
+                    hash: &self.asset_hash(index, collection).unwrap_or_default(),
+                    collection,
+                    bracket,
+                },
+            ),
+            None => format!("{}{}", self.name_prefix, index),
+        }
+    }
+
+    /// Asset uri for the given metadata index, the placeholder uri if not revealed yet
+    pub fn minted_asset_uri(&self, index: u64, collection: &Pubkey) -> String {
+        match &self.placeholder_uri {
+            Some(placeholder_uri) => placeholder_uri.clone(),
+            None => self.asset_uri(index, collection),
+        }
+    }
+
+    /// Asset uri for the given metadata index, i.e. `https://stf.org/metadata/1.json/<hash>`
+    pub fn asset_uri(&self, index: u64, collection: &Pubkey) -> String {
+        self.asset_uri_from(self.uri_template.as_ref(), index, 0, collection)
+    }
+
+    /// Asset uri rendered from the given template and amount bracket,
+    /// the uri prefix, the index and the suffix if there is no template
+    pub fn asset_uri_from(
+        &self,
+        uri_template: Option<&String>,
+        index: u64,
+        bracket: u8,
+        collection: &Pubkey,
+    ) -> String {
+        let hash = self.asset_hash(index, collection);
+
+        match uri_template {
+            Some(uri_template) => render_template(
+                uri_template,
+                &TemplateValues {
+                    index,
+                    hash: hash.as_deref().unwrap_or_default(),
+                    collection,
+                    bracket,
+                },
+            ),
+            None => match hash {
+                Some(hash) => format!("{}{}{}/{}", self.uri_prefix, index, self.uri_suffix, hash),
+                None => format!("{}{}{}", self.uri_prefix, index, self.uri_suffix),
+            },
+        }
+    }
+
     pub fn validate(&self) -> Result<()> {
         require!(self.next_index > 0, FusionError::InvalidNextAssetIndex);
 
@@ -46,6 +259,103 @@ impl AssetDataV1 {
             FusionError::ExceededLengthError
         );
 
+        if let Some(placeholder_uri) = &self.placeholder_uri {
+            require!(
+                placeholder_uri.len() <= ASSET_URI_PREFIX_MAX,
+                FusionError::ExceededLengthError
+            );
+        }
+
+        self.validate_templates(self.name_template.as_ref(), self.uri_template.as_ref())?;
+
+        match self.hash_mode {
+            AssetHashModeV1::Salted { commitment } => {
+                match &self.hash_salt {
+                    // revealed salt must match the commitment
+                    Some(salt) => require!(
+                        hash(salt).to_bytes() == commitment,
+                        FusionError::InvalidHashSalt
+                    ),
+                    // assets can only be minted with a placeholder until the salt is revealed
+                    None => require!(
+                        self.placeholder_uri.is_some(),
+                        FusionError::HashSaltNotRevealed
+                    ),
+                }
+            }
+            _ => require!(self.hash_salt.is_none(), FusionError::InvalidHashSalt),
+        }
+
+        // compressed leaves can not be revealed nor vaulted
+        if self.output_mode == AssetOutputModeV1::Compressed {
+            require!(
+                self.placeholder_uri.is_none(),
+                FusionError::InvalidOutputMode
+            );
+            require!(
+                self.redeem_mode == AssetRedeemModeV1::Burn,
+                FusionError::InvalidOutputMode
+            );
+        }
+
+        // only indexes of recorded assets can be released when burned
+        if self.metadata_mode == MetadataModeV1::Recycled {
+            require!(
+                self.output_mode == AssetOutputModeV1::Core
+                    && self.redeem_mode == AssetRedeemModeV1::Burn,
+                FusionError::InvalidMetadataMode
+            );
+        }
+
+        self.plugins.validate()?;
+
+        Ok(())
+    }
+
+    /// Whether minted assets can be recorded per asset,
+    /// recorded assets are never compressed, vaulted or revealed
+    pub fn is_recordable(&self) -> bool {
+        self.output_mode == AssetOutputModeV1::Core
+            && self.redeem_mode == AssetRedeemModeV1::Burn
+            && self.placeholder_uri.is_none()
+    }
+
+    /// Validates name and uri templates against the hash mode of the asset data
+    pub fn validate_templates(
+        &self,
+        name_template: Option<&String>,
+        uri_template: Option<&String>,
+    ) -> Result<()> {
+        if let Some(name_template) = name_template {
+            require!(
+                name_template.len() <= ASSET_NAME_TEMPLATE_MAX,
+                FusionError::ExceededLengthError
+            );
+            require!(
+                validate_template(name_template, self.asset_hash_len())? <= ASSET_NAME_MAX,
+                FusionError::ExceededLengthError
+            );
+
+            // names are set at mint time, before the salt is revealed
+            if let AssetHashModeV1::Salted { .. } = self.hash_mode {
+                require!(
+                    !name_template.contains("{hash}"),
+                    FusionError::InvalidTemplate
+                );
+            }
+        }
+
+        if let Some(uri_template) = uri_template {
+            require!(
+                uri_template.len() <= ASSET_URI_TEMPLATE_MAX,
+                FusionError::ExceededLengthError
+            );
+            require!(
+                validate_template(uri_template, self.asset_hash_len())? <= ASSET_URI_MAX,
+                FusionError::ExceededLengthError
+            );
+        }
+
         Ok(())
     }
 }
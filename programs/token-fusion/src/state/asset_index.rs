@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Asset Index Account, asset minted with a mint index, the next index of the fusion
+/// when the asset was minted (seeds `[b"asset_index", fusion_data, mint_index]`)
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct AssetIndexV1 {
+    /// Asset minted with the mint index
+    pub asset: Pubkey,
+    /// Slot of the mint
+    pub slot: u64,
+    /// User who minted the asset
+    pub minter: Pubkey,
+    /// Metadata index assigned to the asset
+    pub index: u64,
+}
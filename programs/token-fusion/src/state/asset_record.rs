@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// Asset Record Account, provenance and backing of an asset minted by the program
+/// (seeds `[b"asset_record", fusion_data, asset]`)
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct AssetRecordV1 {
+    /// Tier of the asset, none for variable backed and plain assets
+    pub tier: Option<u8>,
+    /// How many spl tokens can be recovered from the asset,
+    /// zero for plain assets backed by the escrow amount of the fusion
+    pub amount: u64,
+    /// Metadata index of the asset
+    pub index: u64,
+    /// Mint index of the asset, key of its asset index account
+    pub mint_index: u64,
+}
+
+impl AssetRecordV1 {
+    /// Whether the asset is backed by the recorded amount instead of the fusion escrow amount
+    pub fn is_backed(&self) -> bool {
+        self.tier.is_some() || self.amount > 0
+    }
+
+    /// Tokens backing the asset, plain assets are backed by the escrow amount of the fusion
+    pub fn backing_amount(&self, escrow_amount: u64) -> u64 {
+        if self.is_backed() {
+            self.amount
+        } else {
+            escrow_amount
+        }
+    }
+
+    /// Whether the asset is backed by a variable amount
+    pub fn is_variable(&self) -> bool {
+        self.tier.is_none() && self.amount > 0
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::BRACKETS_MAX, errors::FusionError, AssetDataV1};
+
+/// Backing Data Struct, variable amount fused into a single asset
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct BackingDataV1 {
+    /// Min amount of spl tokens backing an asset
+    pub min_amount: u64,
+    /// Max amount of spl tokens backing an asset
+    pub max_amount: u64,
+    /// Ascending lower bounds of the amount brackets past the first one,
+    /// i.e. `[1000, 10000]` for the `0`, `1` and `2` brackets, max 8 bounds
+    #[max_len(BRACKETS_MAX)]
+    pub brackets: Vec<u64>,
+    /// Min amount of spl tokens left backing an asset after a partial withdrawal
+    pub min_remaining: u64,
+    /// Number of variable backed assets currently in circulation, managed by the program
+    pub supply: u32,
+}
+
+impl BackingDataV1 {
+    /// Amount bracket of the given amount, rendered as the `{bracket}` placeholder
+    pub fn bracket(&self, amount: u64) -> u8 {
+        self.brackets
+            .iter()
+            .take_while(|bound| **bound <= amount)
+            .count() as u8
+    }
+
+    pub fn validate_amount(&self, amount: u64) -> Result<()> {
+        require!(
+            amount >= self.min_amount && amount <= self.max_amount,
+            FusionError::InvalidBacking
+        );
+
+        Ok(())
+    }
+
+    pub fn validate(&self, asset_data: &AssetDataV1) -> Result<()> {
+        require!(
+            self.min_amount > 0 && self.min_amount <= self.max_amount,
+            FusionError::InvalidBacking
+        );
+
+        require!(self.min_remaining > 0, FusionError::InvalidBacking);
+
+        require!(
+            self.brackets.len() <= BRACKETS_MAX
+                && self.brackets.windows(2).all(|bounds| bounds[0] < bounds[1]),
+            FusionError::InvalidBacking
+        );
+
+        // amount of the asset is recorded
+        require!(asset_data.is_recordable(), FusionError::InvalidBacking);
+
+        Ok(())
+    }
+}
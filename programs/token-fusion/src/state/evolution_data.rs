@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::FusionError;
+
+/// Evolution Data Struct
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct EvolutionDataV1 {
+    /// Fusion data account of the higher tier collection
+    pub target: Pubkey,
+    /// How many assets are burned to evolve into one asset of the target collection
+    pub burn_count: u8,
+    /// Max SPL amount taken from the user to cover the target escrow amount
+    pub token_amount: u64,
+}
+
+impl EvolutionDataV1 {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.burn_count > 0, FusionError::InvalidEvolution);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{AssetDataV1, FeeDataV1, FusionError};
+use crate::{
+    constants::{INGREDIENTS_MAX, TIERS_MAX},
+    AssetDataV1, BackingDataV1, EvolutionDataV1, FeeDataV1, FusionError, IngredientV1, TierV1,
+};
 
 /// Fusion Data Account
 #[account]
@@ -18,11 +21,36 @@ pub struct FusionDataV1 {
     pub asset_data: AssetDataV1,
     /// Token specific data
     pub fee_data: FeeDataV1,
+    /// Bubblegum merkle tree of compressed assets
+    pub merkle_tree: Option<Pubkey>,
+    /// Number of compressed assets currently in the merkle tree
+    pub compressed_supply: u32,
+    /// Number of assets locked in the vault
+    pub vault_size: u32,
+    /// Optional link to a higher tier fusion
+    pub evolution: Option<EvolutionDataV1>,
+    /// Additional tokens of the recipe, each with its own escrow
+    #[max_len(INGREDIENTS_MAX)]
+    pub ingredients: Vec<IngredientV1>,
+    /// Optional denomination tiers, each with its own fees, supply and templates
+    #[max_len(TIERS_MAX)]
+    pub tiers: Vec<TierV1>,
+    /// Optional variable backing, replaces the fixed escrow amount
+    pub backing: Option<BackingDataV1>,
+    /// Number of assets minted by the first version of the program still waiting for a record
+    pub legacy_supply: u32,
+    /// Next index of the fusion at the migration, legacy assets have a lower metadata index
+    pub legacy_next_index: u64,
 }
 
 impl FusionDataV1 {
     pub fn validate(&self) -> Result<()> {
-        require!(self.paused == false, FusionError::FusionPaused);
+        require!(!self.paused, FusionError::FusionPaused);
         Ok(())
     }
+
+    /// Whether minted assets are backed by their recorded amount, with a tier or a variable backing
+    pub fn has_recorded_backing(&self) -> bool {
+        !self.tiers.is_empty() || self.backing.is_some()
+    }
 }
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::FusionError;
+
+/// Ingredient Struct, an additional token of the fusion recipe
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct IngredientV1 {
+    /// Token mint
+    pub mint: Pubkey,
+    /// How many spl tokens can be recovered from the asset
+    pub escrow_amount: u64,
+    /// SPL fee, when fusing SPL into asset, take this SPL amount from user
+    pub fee_amount: u64,
+    /// SPL fee burned, when fusing SPL into asset, burn this SPL amount
+    pub burn_amount: u64,
+}
+
+impl IngredientV1 {
+    /// Number of remaining accounts of the ingredient in fusion_into,
+    /// `[mint, user token account, escrow ata]` and the fee recipient ata if spl fee is set
+    pub fn accounts_len(&self) -> usize {
+        match self.fee_amount > 0 {
+            true => 4,
+            false => 3,
+        }
+    }
+
+    pub fn validate(&self, fee_recipient: Option<&Pubkey>) -> Result<()> {
+        // If fees are being charged, a fee recipient must be provided
+        if self.fee_amount > 0 {
+            require!(fee_recipient.is_some(), FusionError::InvalidFeeRecipient);
+        }
+
+        Ok(())
+    }
+}
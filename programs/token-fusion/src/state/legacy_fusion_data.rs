@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{AssetDataV1, FeeDataV1, FusionDataV1};
+
+/// Legacy Fusion Data Account, layout of the single fusion data account of the first version
+/// of the program (seeds `[b"fusion_data"]`), shares the discriminator of `FusionDataV1`
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyFusionDataV1 {
+    /// Authority address, is able to update settings
+    pub authority: Pubkey,
+    /// The collection account
+    pub collection: Pubkey,
+    /// Token mint
+    pub token_mint: Pubkey,
+    /// Paused status
+    pub paused: bool,
+    /// Asset specific data
+    pub asset_data: LegacyAssetDataV1,
+    /// Token specific data
+    pub fee_data: FeeDataV1,
+}
+
+/// Legacy Asset Data Struct
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyAssetDataV1 {
+    /// Optional asset mint limit
+    pub max_supply: Option<u32>,
+    /// Asset next index to mint
+    pub next_index: u64,
+    /// Asset name prefix
+    pub name_prefix: String,
+    /// Asset URI prefix
+    pub uri_prefix: String,
+    /// Asset URI suffix
+    pub uri_suffix: String,
+}
+
+impl From<LegacyFusionDataV1> for FusionDataV1 {
+    fn from(legacy: LegacyFusionDataV1) -> Self {
+        Self {
+            authority: legacy.authority,
+            collection: legacy.collection,
+            token_mint: legacy.token_mint,
+            paused: legacy.paused,
+            // defaults keep the legacy names, uris and hashes of minted assets
+            asset_data: AssetDataV1 {
+                max_supply: legacy.asset_data.max_supply,
+                next_index: legacy.asset_data.next_index,
+                name_prefix: legacy.asset_data.name_prefix,
+                uri_prefix: legacy.asset_data.uri_prefix,
+                uri_suffix: legacy.asset_data.uri_suffix,
+                ..Default::default()
+            },
+            fee_data: legacy.fee_data,
+            ..Default::default()
+        }
+    }
+}
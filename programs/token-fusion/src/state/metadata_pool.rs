@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::FusionError;
+
+/// Metadata Pool Account, tracks metadata indexes already assigned in random and recycled modes
+#[account]
+#[derive(Default, Debug)]
+pub struct MetadataPoolV1 {
+    /// First metadata index of the pool
+    pub start_index: u64,
+    /// Number of metadata indexes in the pool
+    pub size: u32,
+    /// Number of metadata indexes already assigned
+    pub used: u32,
+    /// Number of metadata indexes assigned since the pool was initialized, released ones included
+    pub assigned: u64,
+    /// Bitmap of assigned metadata indexes, bits past `size` are always set
+    pub bitmap: Vec<u8>,
+}
+
+impl MetadataPoolV1 {
+    pub fn space(size: u32) -> usize {
+        8 + 8 + 4 + 4 + 8 + 4 + Self::bitmap_len(size)
+    }
+
+    pub fn bitmap_len(size: u32) -> usize {
+        (size as usize).div_ceil(8)
+    }
+
+    pub fn new(start_index: u64, size: u32) -> Self {
+        let mut bitmap = vec![0u8; Self::bitmap_len(size)];
+
+        // mark the padding bits of the last byte as used
+        let padding = bitmap.len() * 8 - size as usize;
+        if padding > 0 {
+            if let Some(last) = bitmap.last_mut() {
+                *last = u8::MAX >> (8 - padding);
+            }
+        }
+
+        Self {
+            start_index,
+            size,
+            used: 0,
+            assigned: 0,
+            bitmap,
+        }
+    }
+
+    pub fn remaining(&self) -> u32 {
+        self.size - self.used
+    }
+
+    /// Draws one of the remaining metadata indexes using the provided random value
+    /// and marks it as used.
+    pub fn draw(&mut self, random: u64) -> Result<u64> {
+        let remaining = self.remaining();
+        require!(remaining > 0, FusionError::MaxSupplyReached);
+
+        // position of the drawn index among the free ones
+        let mut position = (random % remaining as u64) as u32;
+
+        for (byte_index, byte) in self.bitmap.iter_mut().enumerate() {
+            let free = byte.count_zeros();
+            if position >= free {
+                position -= free;
+                continue;
+            }
+
+            for bit in 0..8 {
+                let mask = 0x80 >> bit;
+                if *byte & mask != 0 {
+                    continue;
+                }
+                if position == 0 {
+                    *byte |= mask;
+                    self.used += 1;
+                    self.assigned += 1;
+                    return Ok(self.start_index + (byte_index * 8 + bit) as u64);
+                }
+                position -= 1;
+            }
+        }
+
+        err!(FusionError::MaxSupplyReached)
+    }
+
+    /// Takes the lowest remaining metadata index and marks it as used.
+    pub fn take(&mut self) -> Result<u64> {
+        for (byte_index, byte) in self.bitmap.iter_mut().enumerate() {
+            if *byte == u8::MAX {
+                continue;
+            }
+
+            let bit = byte.leading_ones() as usize;
+            *byte |= 0x80 >> bit;
+            self.used += 1;
+            self.assigned += 1;
+            return Ok(self.start_index + (byte_index * 8 + bit) as u64);
+        }
+
+        err!(FusionError::MaxSupplyReached)
+    }
+
+    /// Releases a used metadata index back to the pool, indexes assigned before
+    /// the pool was initialized or outside of it are ignored.
+    pub fn release(&mut self, index: u64) -> Result<()> {
+        let position = match index.checked_sub(self.start_index) {
+            Some(position) if position < self.size as u64 => position as usize,
+            _ => return Ok(()),
+        };
+
+        let mask = 0x80 >> (position % 8);
+        let byte = &mut self.bitmap[position / 8];
+        if *byte & mask == 0 {
+            return Ok(());
+        }
+
+        *byte &= !mask;
+        self.used -= 1;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_marks_padding_bits() {
+        let pool = MetadataPoolV1::new(1, 10);
+
+        assert_eq!(pool.bitmap, vec![0b0000_0000, 0b0011_1111]);
+        assert_eq!(pool.remaining(), 10);
+    }
+
+    #[test]
+    fn new_without_padding() {
+        let pool = MetadataPoolV1::new(1, 16);
+
+        assert_eq!(pool.bitmap, vec![0, 0]);
+    }
+
+    #[test]
+    fn draw_exhausts_the_pool() {
+        let mut pool = MetadataPoolV1::new(5, 10);
+        let mut drawn = Vec::new();
+
+        for random in [7, 0, 123, 9, 4, 4, 1, 88, 2, 0] {
+            drawn.push(pool.draw(random).unwrap());
+        }
+
+        drawn.sort();
+        assert_eq!(drawn, (5..15).collect::<Vec<u64>>());
+        assert_eq!(pool.remaining(), 0);
+        assert_eq!(pool.bitmap, vec![u8::MAX, u8::MAX]);
+
+        assert_eq!(
+            pool.draw(0).unwrap_err(),
+            FusionError::MaxSupplyReached.into()
+        );
+    }
+
+    #[test]
+    fn draw_skips_used_indexes() {
+        let mut pool = MetadataPoolV1::new(1, 8);
+
+        assert_eq!(pool.draw(0).unwrap(), 1);
+        // position 0 among the free indexes is now the second index
+        assert_eq!(pool.draw(0).unwrap(), 2);
+        assert_eq!(pool.draw(5).unwrap(), 8);
+        assert_eq!(pool.remaining(), 5);
+    }
+
+    #[test]
+    fn take_release_round_trip() {
+        let mut pool = MetadataPoolV1::new(3, 10);
+
+        assert_eq!(pool.take().unwrap(), 3);
+        assert_eq!(pool.take().unwrap(), 4);
+        assert_eq!(pool.take().unwrap(), 5);
+
+        // released indexes are taken again, lowest first
+        pool.release(4).unwrap();
+        pool.release(3).unwrap();
+        assert_eq!(pool.remaining(), 9);
+        assert_eq!(pool.take().unwrap(), 3);
+        assert_eq!(pool.take().unwrap(), 4);
+        assert_eq!(pool.take().unwrap(), 6);
+
+        assert_eq!(pool.remaining(), 6);
+        assert_eq!(pool.assigned, 6);
+    }
+
+    #[test]
+    fn take_exhausts_and_release_refills() {
+        let mut pool = MetadataPoolV1::new(0, 10);
+
+        for index in 0..10 {
+            assert_eq!(pool.take().unwrap(), index);
+        }
+        assert_eq!(
+            pool.take().unwrap_err(),
+            FusionError::MaxSupplyReached.into()
+        );
+
+        pool.release(9).unwrap();
+        assert_eq!(pool.take().unwrap(), 9);
+        assert_eq!(pool.bitmap, vec![u8::MAX, u8::MAX]);
+    }
+
+    #[test]
+    fn release_ignores_unmarked_indexes() {
+        let mut pool = MetadataPoolV1::new(5, 10);
+
+        assert_eq!(pool.take().unwrap(), 5);
+
+        // before the pool, past the pool, and never assigned
+        pool.release(4).unwrap();
+        pool.release(15).unwrap();
+        pool.release(6).unwrap();
+        assert_eq!(pool.used, 1);
+
+        // released twice
+        pool.release(5).unwrap();
+        pool.release(5).unwrap();
+        assert_eq!(pool.used, 0);
+        assert_eq!(pool.bitmap, vec![0b0000_0000, 0b0011_1111]);
+    }
+}
@@ -1,7 +1,25 @@
 pub use asset_data::*;
+pub use asset_index::*;
+pub use asset_record::*;
+pub use backing_data::*;
+pub use evolution_data::*;
 pub use fee_data::*;
 pub use fusion_data::*;
+pub use ingredient_data::*;
+pub use legacy_fusion_data::*;
+pub use metadata_pool::*;
+pub use plugin_data::*;
+pub use tier_data::*;
 
 pub mod asset_data;
+pub mod asset_index;
+pub mod asset_record;
+pub mod backing_data;
+pub mod evolution_data;
 pub mod fee_data;
 pub mod fusion_data;
+pub mod ingredient_data;
+pub mod legacy_fusion_data;
+pub mod metadata_pool;
+pub mod plugin_data;
+pub mod tier_data;
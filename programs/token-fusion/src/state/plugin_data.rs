@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use mpl_core::types::{Attribute, Attributes, Creator, Plugin, PluginType, Royalties, RuleSet};
+
+use crate::{
+    constants::{MAX_BASIS_POINTS, ROYALTIES_CREATORS_MAX},
+    errors::FusionError,
+};
+
+/// Royalties Creator Struct
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct CreatorV1 {
+    /// Creator address
+    pub address: Pubkey,
+    /// Creator share of the royalties in percents
+    pub percentage: u8,
+}
+
+/// Royalties Struct
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct RoyaltiesV1 {
+    /// Royalties in basis points, i.e. `500` for 5%
+    pub basis_points: u16,
+
+    #[max_len(ROYALTIES_CREATORS_MAX)]
+    /// Royalties creators, 1 to 4 creators with percentages adding up to 100
+    pub creators: Vec<CreatorV1>,
+}
+
+impl RoyaltiesV1 {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.basis_points <= MAX_BASIS_POINTS,
+            FusionError::InvalidRoyalties
+        );
+
+        require!(!self.creators.is_empty(), FusionError::MissingCreators);
+
+        require!(
+            self.creators.len() <= ROYALTIES_CREATORS_MAX,
+            FusionError::TooManyCreators
+        );
+
+        let total_percentage = self
+            .creators
+            .iter()
+            .map(|creator| creator.percentage as u16)
+            .sum::<u16>();
+
+        require!(total_percentage == 100, FusionError::InvalidRoyalties);
+
+        Ok(())
+    }
+}
+
+impl From<&RoyaltiesV1> for Royalties {
+    fn from(royalties: &RoyaltiesV1) -> Self {
+        Self {
+            basis_points: royalties.basis_points,
+            creators: royalties
+                .creators
+                .iter()
+                .map(|creator| Creator {
+                    address: creator.address,
+                    percentage: creator.percentage,
+                })
+                .collect(),
+            rule_set: RuleSet::None,
+        }
+    }
+}
+
+/// Plugin Data Struct, plugins applied to every minted asset
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct PluginDataV1 {
+    /// Optional Royalties plugin
+    pub royalties: Option<RoyaltiesV1>,
+    /// Attributes plugin with asset index, backing amount and mint timestamp
+    pub attributes: bool,
+    /// PermanentBurnDelegate plugin with the authority pda as delegate
+    pub permanent_burn_delegate: bool,
+    /// FreezeDelegate plugin with the authority pda as delegate
+    pub freeze_delegate: bool,
+}
+
+impl PluginDataV1 {
+    pub fn validate(&self) -> Result<()> {
+        if let Some(royalties) = &self.royalties {
+            royalties.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Attribute Struct
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AttributeV1 {
+    /// Attribute key
+    pub key: String,
+    /// Attribute value
+    pub value: String,
+}
+
+/// Collection Plugin Enum, plugins managed by the fusion authority on the collection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum CollectionPluginV1 {
+    /// Royalties plugin
+    Royalties(RoyaltiesV1),
+    /// Attributes plugin
+    Attributes(Vec<AttributeV1>),
+}
+
+impl CollectionPluginV1 {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Royalties(royalties) => royalties.validate(),
+            Self::Attributes(_) => Ok(()),
+        }
+    }
+}
+
+impl From<&CollectionPluginV1> for Plugin {
+    fn from(plugin: &CollectionPluginV1) -> Self {
+        match plugin {
+            CollectionPluginV1::Royalties(royalties) => Plugin::Royalties(royalties.into()),
+            CollectionPluginV1::Attributes(attributes) => Plugin::Attributes(Attributes {
+                attribute_list: attributes
+                    .iter()
+                    .map(|attribute| Attribute {
+                        key: attribute.key.clone(),
+                        value: attribute.value.clone(),
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
+/// Collection Plugin Type Enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub enum CollectionPluginTypeV1 {
+    /// Royalties plugin
+    Royalties,
+    /// Attributes plugin
+    Attributes,
+}
+
+impl From<CollectionPluginTypeV1> for PluginType {
+    fn from(plugin_type: CollectionPluginTypeV1) -> Self {
+        match plugin_type {
+            CollectionPluginTypeV1::Royalties => PluginType::Royalties,
+            CollectionPluginTypeV1::Attributes => PluginType::Attributes,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ASSET_NAME_TEMPLATE_MAX, ASSET_URI_TEMPLATE_MAX},
+    errors::FusionError,
+    AssetDataV1, FeeDataV1,
+};
+
+/// Tier Struct, a denomination of the assets in the collection
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct TierV1 {
+    /// Escrow amount and fees of the tier, replace the fusion fee data
+    pub fee_data: FeeDataV1,
+
+    /// Optional tier mint limit
+    pub max_supply: Option<u32>,
+
+    /// Number of assets of the tier currently in circulation, managed by the program
+    pub supply: u32,
+
+    #[max_len(ASSET_NAME_TEMPLATE_MAX)]
+    /// Optional asset name template of the tier, overrides the asset data name
+    pub name_template: Option<String>,
+
+    #[max_len(ASSET_URI_TEMPLATE_MAX)]
+    /// Optional asset URI template of the tier, overrides the asset data uri
+    pub uri_template: Option<String>,
+}
+
+impl TierV1 {
+    pub fn validate(&self, asset_data: &AssetDataV1) -> Result<()> {
+        self.fee_data.validate()?;
+
+        // tier of the asset is recorded
+        require!(asset_data.is_recordable(), FusionError::InvalidTier);
+
+        asset_data.validate_templates(self.name_template.as_ref(), self.uri_template.as_ref())
+    }
+}
@@ -1,16 +1,39 @@
 use anchor_lang::{
     prelude::*,
     solana_program::{
-        entrypoint::ProgramResult, hash::hash, program::invoke, program_memory::sol_memcmp,
-        pubkey::PUBKEY_BYTES, system_instruction,
+        entrypoint::ProgramResult,
+        hash::{hash, hashv},
+        program::{invoke, invoke_signed},
+        program_memory::sol_memcmp,
+        program_option::COption,
+        pubkey::PUBKEY_BYTES,
+        system_instruction,
     },
 };
+use anchor_spl::token::TokenAccount;
+use mpl_bubblegum::{
+    instructions::{BurnCpiBuilder, CreateTreeConfigCpiBuilder, MintV1CpiBuilder},
+    types::MetadataArgs,
+};
 use mpl_core::{
+    accounts::BaseAssetV1,
+    fetch_plugin,
     instructions::{
-        ApproveCollectionPluginAuthorityV1CpiBuilder, BurnV1CpiBuilder, CreateV1CpiBuilder,
-        RevokeCollectionPluginAuthorityV1CpiBuilder,
+        AddCollectionPluginV1CpiBuilder, ApproveCollectionPluginAuthorityV1CpiBuilder,
+        BurnV1CpiBuilder, CreateV1CpiBuilder, RemoveCollectionPluginV1CpiBuilder,
+        RevokeCollectionPluginAuthorityV1CpiBuilder, TransferV1CpiBuilder,
+        UpdateCollectionPluginV1CpiBuilder, UpdatePluginV1CpiBuilder, UpdateV1CpiBuilder,
+    },
+    types::{
+        BurnDelegate, DataState, Plugin, PluginAuthority, PluginAuthorityPair, PluginType,
+        TransferDelegate,
     },
-    types::{DataState, PluginAuthority, PluginType},
+};
+
+use crate::{
+    constants::{ASSET_SEED, INDEX_SEED, PROTOCOL_FEE_WALLET, RECORD_SEED},
+    errors::FusionError,
+    AssetRedeemModeV1,
 };
 
 pub fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
@@ -33,19 +56,99 @@ pub fn get_pubkey_opt_from_account_info<'a>(acc: &Option<AccountInfo<'a>>) -> Op
     }
 }
 
-// hash collection mint, token id and secret key and return string representation limited to 8 symbols
-pub fn get_asset_hash(id: &u64, mint: &Pubkey, key: &Pubkey) -> String {
-    let mint_bytes = mint.to_string().as_bytes().to_vec();
-    let id_bytes = id.to_string().as_bytes().to_vec();
-    let key_bytes = key.to_string().as_bytes().to_vec();
+// check that the authority is the owner or the approved delegate of the token account
+pub fn is_token_authority(account: &TokenAccount, authority: &Pubkey) -> bool {
+    cmp_pubkeys(&account.owner, authority)
+        || matches!(account.delegate, COption::Some(delegate) if cmp_pubkeys(&delegate, authority))
+}
+
+// check that the authority is the owner of the asset, or the delegate able to redeem it,
+// the burn delegate when burned or the transfer delegate when vaulted
+pub fn is_asset_authority(
+    asset: &AccountInfo,
+    owner: &Pubkey,
+    authority: &Pubkey,
+    redeem_mode: AssetRedeemModeV1,
+) -> bool {
+    let is_delegate = |plugin_authority: PluginAuthority| match plugin_authority {
+        PluginAuthority::Address { address } => cmp_pubkeys(&address, authority),
+        _ => false,
+    };
 
-    let data = vec![mint_bytes, id_bytes, key_bytes]
-        .iter()
-        .flatten()
-        .cloned()
-        .collect::<Vec<u8>>();
+    cmp_pubkeys(owner, authority)
+        || match redeem_mode {
+            AssetRedeemModeV1::Burn => {
+                fetch_plugin::<BaseAssetV1, BurnDelegate>(asset, PluginType::BurnDelegate)
+                    .is_ok_and(|(plugin_authority, _, _)| is_delegate(plugin_authority))
+            }
+            AssetRedeemModeV1::Vault => {
+                fetch_plugin::<BaseAssetV1, TransferDelegate>(asset, PluginType::TransferDelegate)
+                    .is_ok_and(|(plugin_authority, _, _)| is_delegate(plugin_authority))
+            }
+        }
+}
+
+// hash the base58 collection, the asset index and the protocol fee wallet strings
+// and return the base58 representation, as assets were hashed before the hash modes
+pub fn get_legacy_asset_hash(index: u64, collection: &Pubkey) -> String {
+    let data = [
+        collection.to_string(),
+        index.to_string(),
+        PROTOCOL_FEE_WALLET.to_string(),
+    ]
+    .concat();
+
+    hash(data.as_bytes()).to_string()
+}
+
+// hash collection, asset index and optional salt bytes and return the base58 representation
+pub fn get_asset_hash(index: u64, collection: &Pubkey, salt: Option<&[u8; 32]>) -> String {
+    let index_bytes = index.to_le_bytes();
+
+    match salt {
+        Some(salt) => hashv(&[collection.as_ref(), &index_bytes, salt]),
+        None => hashv(&[collection.as_ref(), &index_bytes]),
+    }
+    .to_string()
+}
+
+// hash the most recent slot hash with the seeds and return the first 8 bytes as a random value
+pub fn get_random_value(slot_hashes: &AccountInfo, seeds: &[&[u8]]) -> Result<u64> {
+    let data = slot_hashes.try_borrow_data()?;
+    // skip the entries count and the slot of the most recent entry
+    let recent_hash = data
+        .get(16..48)
+        .ok_or(FusionError::MissingSlotHashesSysvar)?;
+
+    let hash = hashv(&[&[recent_hash], seeds].concat()).to_bytes();
+    let mut random = [0u8; 8];
+    random.copy_from_slice(&hash[0..8]);
+
+    Ok(u64::from_le_bytes(random))
+}
 
-    hash(&data).to_string()[0..8].to_string()
+// derive the asset PDA address for the given fusion data account and asset index
+pub fn find_asset_address(fusion: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ASSET_SEED.as_bytes(), fusion.as_ref(), &index.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+// derive the asset index PDA address for the given fusion data account and mint index
+pub fn find_asset_index_address(fusion: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[INDEX_SEED.as_bytes(), fusion.as_ref(), &index.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+// derive the asset record PDA address for the given fusion data account and asset
+pub fn find_asset_record_address(fusion: &Pubkey, asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RECORD_SEED.as_bytes(), fusion.as_ref(), asset.as_ref()],
+        &crate::ID,
+    )
 }
 
 pub struct CollectionPluginAuthorityV1Accounts<'info> {
@@ -85,7 +188,7 @@ pub fn approve_collection_authority_v1(
 pub fn revoke_collection_authority_v1(
     acc: CollectionPluginAuthorityV1Accounts,
     args: RevokeCollectionPluginAuthorityV1Args,
-    signer_seeds: [&[u8]; 2],
+    signer_seeds: [&[u8]; 3],
 ) -> Result<()> {
     RevokeCollectionPluginAuthorityV1CpiBuilder::new(&acc.core_program)
         .collection(&acc.collection.to_account_info())
@@ -98,7 +201,67 @@ pub fn revoke_collection_authority_v1(
         .map_err(|error| error.into())
 }
 
-#[derive(Debug)]
+pub struct AddCollectionPluginV1Args {
+    pub plugin: Plugin,
+}
+
+pub struct UpdateCollectionPluginV1Args {
+    pub plugin: Plugin,
+}
+
+pub struct RemoveCollectionPluginV1Args {
+    pub plugin_type: PluginType,
+}
+
+pub fn add_collection_plugin_v1(
+    acc: CollectionPluginAuthorityV1Accounts,
+    args: AddCollectionPluginV1Args,
+    signer_seeds: [&[u8]; 3],
+) -> Result<()> {
+    AddCollectionPluginV1CpiBuilder::new(&acc.core_program)
+        .collection(&acc.collection.to_account_info())
+        .payer(&acc.payer.to_account_info())
+        .authority(acc.authority.as_ref())
+        .system_program(&acc.system_program.to_account_info())
+        .log_wrapper(acc.log_wrapper.as_ref())
+        .plugin(args.plugin)
+        .invoke_signed(&[&signer_seeds])
+        .map_err(|error| error.into())
+}
+
+pub fn update_collection_plugin_v1(
+    acc: CollectionPluginAuthorityV1Accounts,
+    args: UpdateCollectionPluginV1Args,
+    signer_seeds: [&[u8]; 3],
+) -> Result<()> {
+    UpdateCollectionPluginV1CpiBuilder::new(&acc.core_program)
+        .collection(&acc.collection.to_account_info())
+        .payer(&acc.payer.to_account_info())
+        .authority(acc.authority.as_ref())
+        .system_program(&acc.system_program.to_account_info())
+        .log_wrapper(acc.log_wrapper.as_ref())
+        .plugin(args.plugin)
+        .invoke_signed(&[&signer_seeds])
+        .map_err(|error| error.into())
+}
+
+pub fn remove_collection_plugin_v1(
+    acc: CollectionPluginAuthorityV1Accounts,
+    args: RemoveCollectionPluginV1Args,
+    signer_seeds: [&[u8]; 3],
+) -> Result<()> {
+    RemoveCollectionPluginV1CpiBuilder::new(&acc.core_program)
+        .collection(&acc.collection.to_account_info())
+        .payer(&acc.payer.to_account_info())
+        .authority(acc.authority.as_ref())
+        .system_program(&acc.system_program.to_account_info())
+        .log_wrapper(acc.log_wrapper.as_ref())
+        .plugin_type(args.plugin_type)
+        .invoke_signed(&[&signer_seeds])
+        .map_err(|error| error.into())
+}
+
+#[derive(Clone, Debug)]
 pub struct AssetV1Accounts<'info> {
     /// The address of the new asset.
     pub asset: AccountInfo<'info>,
@@ -106,6 +269,8 @@ pub struct AssetV1Accounts<'info> {
     pub collection: Option<AccountInfo<'info>>,
     /// The account paying for the storage fees.
     pub payer: AccountInfo<'info>,
+    /// The collection authority or delegate, defaults to the payer.
+    pub authority: Option<AccountInfo<'info>>,
     /// The MPL Core program.
     pub core_program: AccountInfo<'info>,
     /// The system program.
@@ -118,23 +283,78 @@ pub struct AssetV1Accounts<'info> {
 pub struct CreateV1Args {
     pub name: String,
     pub uri: String,
-    // TODO: Add ability to add plugins
+    pub plugins: Vec<PluginAuthorityPair>,
 }
 
-pub fn create_asset_v1(
-    acc: AssetV1Accounts,
+pub fn create_asset_v1<'info>(
+    acc: AssetV1Accounts<'info>,
+    owner: Option<AccountInfo<'info>>,
     args: CreateV1Args,
-    signer_seeds: [&[u8]; 2],
+    signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
     CreateV1CpiBuilder::new(&acc.core_program)
         .asset(&acc.asset.to_account_info())
         .collection(acc.collection.as_ref())
         .payer(&acc.payer.to_account_info())
+        .authority(acc.authority.as_ref())
+        .owner(owner.as_ref())
         .system_program(&acc.system_program.to_account_info())
         .log_wrapper(acc.log_wrapper.as_ref())
         .data_state(DataState::AccountState)
         .name(args.name)
         .uri(args.uri)
+        .plugins(args.plugins)
+        .invoke_signed(signer_seeds)
+        .map_err(|error| error.into())
+}
+
+#[derive(Debug)]
+pub struct UpdateV1Args {
+    pub name: Option<String>,
+    pub uri: Option<String>,
+}
+
+pub fn update_asset_v1(
+    acc: AssetV1Accounts,
+    args: UpdateV1Args,
+    signer_seeds: [&[u8]; 3],
+) -> Result<()> {
+    let mut builder = UpdateV1CpiBuilder::new(&acc.core_program);
+
+    builder
+        .asset(&acc.asset)
+        .collection(acc.collection.as_ref())
+        .payer(&acc.payer)
+        .authority(acc.authority.as_ref())
+        .system_program(&acc.system_program)
+        .log_wrapper(acc.log_wrapper.as_ref());
+
+    if let Some(name) = args.name {
+        builder.new_name(name);
+    }
+
+    if let Some(uri) = args.uri {
+        builder.new_uri(uri);
+    }
+
+    builder
+        .invoke_signed(&[&signer_seeds])
+        .map_err(|error| error.into())
+}
+
+pub fn update_asset_plugin_v1(
+    acc: AssetV1Accounts,
+    plugin: Plugin,
+    signer_seeds: [&[u8]; 3],
+) -> Result<()> {
+    UpdatePluginV1CpiBuilder::new(&acc.core_program)
+        .asset(&acc.asset)
+        .collection(acc.collection.as_ref())
+        .payer(&acc.payer)
+        .authority(acc.authority.as_ref())
+        .system_program(&acc.system_program)
+        .log_wrapper(acc.log_wrapper.as_ref())
+        .plugin(plugin)
         .invoke_signed(&[&signer_seeds])
         .map_err(|error| error.into())
 }
@@ -144,12 +364,140 @@ pub fn burn_asset_v1(acc: AssetV1Accounts) -> Result<()> {
         .asset(&acc.asset.to_account_info())
         .collection(acc.collection.as_ref())
         .payer(&acc.payer.to_account_info())
+        .authority(acc.authority.as_ref())
         .system_program(Some(&acc.system_program.to_account_info()))
         .log_wrapper(acc.log_wrapper.as_ref())
         .invoke()
         .map_err(|error| error.into())
 }
 
+pub fn transfer_asset_v1<'info>(
+    acc: AssetV1Accounts<'info>,
+    new_owner: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    TransferV1CpiBuilder::new(&acc.core_program)
+        .asset(&acc.asset)
+        .collection(acc.collection.as_ref())
+        .payer(&acc.payer)
+        .authority(acc.authority.as_ref())
+        .new_owner(&new_owner)
+        .system_program(Some(&acc.system_program))
+        .log_wrapper(acc.log_wrapper.as_ref())
+        .invoke_signed(signer_seeds)
+        .map_err(|error| error.into())
+}
+
+pub struct TreeV1Accounts<'info> {
+    /// The bubblegum tree config account.
+    pub tree_config: AccountInfo<'info>,
+    /// The merkle tree account.
+    pub merkle_tree: AccountInfo<'info>,
+    /// The account paying for the storage fees.
+    pub payer: AccountInfo<'info>,
+    /// The tree creator or delegate.
+    pub tree_creator: AccountInfo<'info>,
+    /// The Bubblegum program.
+    pub bubblegum_program: AccountInfo<'info>,
+    /// The SPL Account Compression program.
+    pub compression_program: AccountInfo<'info>,
+    /// The SPL Noop program.
+    pub log_wrapper: AccountInfo<'info>,
+    /// The system program.
+    pub system_program: AccountInfo<'info>,
+}
+
+pub struct CreateTreeV1Args {
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+}
+
+pub fn create_tree_v1(
+    acc: TreeV1Accounts,
+    args: CreateTreeV1Args,
+    signer_seeds: [&[u8]; 3],
+) -> Result<()> {
+    CreateTreeConfigCpiBuilder::new(&acc.bubblegum_program)
+        .tree_config(&acc.tree_config)
+        .merkle_tree(&acc.merkle_tree)
+        .payer(&acc.payer)
+        .tree_creator(&acc.tree_creator)
+        .log_wrapper(&acc.log_wrapper)
+        .compression_program(&acc.compression_program)
+        .system_program(&acc.system_program)
+        .max_depth(args.max_depth)
+        .max_buffer_size(args.max_buffer_size)
+        .public(false)
+        .invoke_signed(&[&signer_seeds])
+        .map_err(|error| error.into())
+}
+
+pub struct LeafV1Accounts<'info> {
+    /// The owner of the leaf.
+    pub leaf_owner: AccountInfo<'info>,
+    /// The delegate of the leaf.
+    pub leaf_delegate: AccountInfo<'info>,
+}
+
+pub fn mint_compressed_v1<'info>(
+    acc: TreeV1Accounts<'info>,
+    leaf: LeafV1Accounts<'info>,
+    metadata: MetadataArgs,
+    signer_seeds: [&[u8]; 3],
+) -> Result<()> {
+    MintV1CpiBuilder::new(&acc.bubblegum_program)
+        .tree_config(&acc.tree_config)
+        .leaf_owner(&leaf.leaf_owner)
+        .leaf_delegate(&leaf.leaf_delegate)
+        .merkle_tree(&acc.merkle_tree)
+        .payer(&acc.payer)
+        .tree_creator_or_delegate(&acc.tree_creator)
+        .log_wrapper(&acc.log_wrapper)
+        .compression_program(&acc.compression_program)
+        .system_program(&acc.system_program)
+        .metadata(metadata)
+        .invoke_signed(&[&signer_seeds])
+        .map_err(|error| error.into())
+}
+
+#[derive(Debug)]
+pub struct BurnCompressedV1Args {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+pub fn burn_compressed_v1<'info>(
+    acc: TreeV1Accounts<'info>,
+    leaf: LeafV1Accounts<'info>,
+    args: BurnCompressedV1Args,
+    proof: &[AccountInfo<'info>],
+) -> Result<()> {
+    let proof = proof
+        .iter()
+        .map(|node| (node, false, false))
+        .collect::<Vec<_>>();
+
+    BurnCpiBuilder::new(&acc.bubblegum_program)
+        .tree_config(&acc.tree_config)
+        .leaf_owner(&leaf.leaf_owner, leaf.leaf_owner.is_signer)
+        .leaf_delegate(&leaf.leaf_delegate, leaf.leaf_delegate.is_signer)
+        .merkle_tree(&acc.merkle_tree)
+        .log_wrapper(&acc.log_wrapper)
+        .compression_program(&acc.compression_program)
+        .system_program(&acc.system_program)
+        .root(args.root)
+        .data_hash(args.data_hash)
+        .creator_hash(args.creator_hash)
+        .nonce(args.nonce)
+        .index(args.index)
+        .add_remaining_accounts(&proof)
+        .invoke()
+        .map_err(|error| error.into())
+}
+
 pub fn sol_transfer<'a>(
     source: AccountInfo<'a>,
     destination: AccountInfo<'a>,
@@ -158,3 +506,57 @@ pub fn sol_transfer<'a>(
     let ix = system_instruction::transfer(source.key, destination.key, amount);
     invoke(&ix, &[source, destination])
 }
+
+// create a program account at a PDA address, lamports already sent to the address
+// are kept and topped up to the rent exemption instead of failing the creation
+pub fn create_pda_account<'a>(
+    payer: AccountInfo<'a>,
+    account: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+
+    if lamports == 0 {
+        let ix = system_instruction::create_account(
+            payer.key,
+            account.key,
+            rent_lamports,
+            space as u64,
+            &crate::ID,
+        );
+        invoke_signed(&ix, &[payer, account, system_program], &[signer_seeds])?;
+
+        return Ok(());
+    }
+
+    if lamports < rent_lamports {
+        sol_transfer(payer, account.clone(), rent_lamports - lamports)?;
+    }
+
+    let ix = system_instruction::allocate(account.key, space as u64);
+    invoke_signed(
+        &ix,
+        &[account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+
+    let ix = system_instruction::assign(account.key, &crate::ID);
+    invoke_signed(&ix, &[account, system_program], &[signer_seeds]).map_err(Into::into)
+}
+
+// close the program account and withdraw its rent to the destination
+pub fn close_account<'a>(account: AccountInfo<'a>, destination: AccountInfo<'a>) -> Result<()> {
+    let lamports = destination
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(FusionError::NumericalOverflowError)?;
+
+    **destination.lamports.borrow_mut() = lamports;
+    **account.lamports.borrow_mut() = 0;
+
+    account.assign(&System::id());
+    account.realloc(0, false).map_err(Into::into)
+}
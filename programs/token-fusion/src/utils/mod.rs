@@ -1,3 +1,5 @@
 pub mod helpers;
+pub mod template;
 
 pub use helpers::*;
+pub use template::*;
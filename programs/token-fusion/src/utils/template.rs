@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::FusionError;
+
+// placeholders supported in asset name and uri templates
+pub const PLACEHOLDER_INDEX: &str = "index";
+pub const PLACEHOLDER_HASH: &str = "hash";
+pub const PLACEHOLDER_COLLECTION: &str = "collection";
+pub const PLACEHOLDER_BRACKET: &str = "bracket";
+
+// max rendered length of the placeholders
+const INDEX_MAX_LEN: usize = 20;
+const COLLECTION_MAX_LEN: usize = 44;
+const BRACKET_MAX_LEN: usize = 3;
+
+/// Values substituted into a template.
+pub struct TemplateValues<'a> {
+    pub index: u64,
+    pub hash: &'a str,
+    pub collection: &'a Pubkey,
+    pub bracket: u8,
+}
+
+enum Placeholder {
+    Index { width: usize },
+    Hash,
+    Collection,
+    Bracket,
+}
+
+// parse placeholder name, i.e. `index`, `index:05`, `hash`, `collection` or `bracket`
+fn parse_placeholder(name: &str) -> Option<Placeholder> {
+    match name {
+        PLACEHOLDER_INDEX => Some(Placeholder::Index { width: 0 }),
+        PLACEHOLDER_HASH => Some(Placeholder::Hash),
+        PLACEHOLDER_COLLECTION => Some(Placeholder::Collection),
+        PLACEHOLDER_BRACKET => Some(Placeholder::Bracket),
+        _ => {
+            let width = name
+                .strip_prefix(PLACEHOLDER_INDEX)?
+                .strip_prefix(":0")?
+                .parse::<usize>()
+                .ok()?;
+            (width > 0 && width <= INDEX_MAX_LEN).then_some(Placeholder::Index { width })
+        }
+    }
+}
+
+/// Validates the template placeholders and returns the max length of the rendered template.
+pub fn validate_template(template: &str, hash_len: usize) -> Result<usize> {
+    let mut rendered_len = 0;
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .ok_or(FusionError::InvalidTemplate)?;
+
+        require!(!rest[..start].contains('}'), FusionError::InvalidTemplate);
+
+        rendered_len += start;
+        rendered_len += match parse_placeholder(&rest[start + 1..start + end]) {
+            Some(Placeholder::Index { width }) => width.max(INDEX_MAX_LEN),
+            Some(Placeholder::Hash) => hash_len,
+            Some(Placeholder::Collection) => COLLECTION_MAX_LEN,
+            Some(Placeholder::Bracket) => BRACKET_MAX_LEN,
+            None => return err!(FusionError::InvalidTemplate),
+        };
+        rest = &rest[start + end + 1..];
+    }
+
+    require!(!rest.contains('}'), FusionError::InvalidTemplate);
+
+    Ok(rendered_len + rest.len())
+}
+
+/// Renders the template, templates are validated when set so unknown placeholders are kept as is.
+pub fn render_template(template: &str, values: &TemplateValues) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+
+        rendered.push_str(&rest[..start]);
+        match parse_placeholder(&rest[start + 1..start + end]) {
+            Some(Placeholder::Index { width }) => {
+                rendered.push_str(&format!("{:0width$}", values.index, width = width))
+            }
+            Some(Placeholder::Hash) => rendered.push_str(values.hash),
+            Some(Placeholder::Collection) => rendered.push_str(&values.collection.to_string()),
+            Some(Placeholder::Bracket) => rendered.push_str(&values.bracket.to_string()),
+            None => rendered.push_str(&rest[start..start + end + 1]),
+        }
+        rest = &rest[start + end + 1..];
+    }
+
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLLECTION: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    fn values(index: u64) -> TemplateValues<'static> {
+        TemplateValues {
+            index,
+            hash: "abcdefgh",
+            collection: &COLLECTION,
+            bracket: 2,
+        }
+    }
+
+    #[test]
+    fn render_pads_the_index_to_the_width() {
+        assert_eq!(
+            render_template("Token #{index:05}", &values(42)),
+            "Token #00042"
+        );
+        // wider indexes are not truncated
+        assert_eq!(render_template("{index:02}", &values(12345)), "12345");
+        assert_eq!(render_template("{index}", &values(7)), "7");
+    }
+
+    #[test]
+    fn render_substitutes_all_placeholders() {
+        assert_eq!(
+            render_template("{collection}/{index}-{bracket}.json?h={hash}", &values(3)),
+            format!("{}/3-2.json?h=abcdefgh", COLLECTION)
+        );
+    }
+
+    #[test]
+    fn render_keeps_unknown_placeholders() {
+        assert_eq!(
+            render_template("{name} #{index} {", &values(1)),
+            "{name} #1 {"
+        );
+    }
+
+    #[test]
+    fn validate_returns_the_max_rendered_length() {
+        assert_eq!(validate_template("Token #{index:05}", 8).unwrap(), 7 + 20);
+        assert!(validate_template("{index:030}", 8).is_err());
+        assert_eq!(validate_template("h={hash}", 44).unwrap(), 2 + 44);
+        assert_eq!(
+            validate_template("{collection}/{bracket}", 8).unwrap(),
+            44 + 1 + 3
+        );
+        assert_eq!(validate_template("plain", 8).unwrap(), 5);
+    }
+
+    #[test]
+    fn validate_rejects_unknown_placeholders() {
+        for template in ["{name}", "{index:5}", "{index:00}", "{index:0x}", "{}"] {
+            assert_eq!(
+                validate_template(template, 8).unwrap_err(),
+                FusionError::InvalidTemplate.into()
+            );
+        }
+    }
+
+    #[test]
+    fn validate_rejects_unbalanced_braces() {
+        for template in ["Token #{index", "Token #index}", "}{index}", "{index}}"] {
+            assert_eq!(
+                validate_template(template, 8).unwrap_err(),
+                FusionError::InvalidTemplate.into()
+            );
+        }
+    }
+}
@@ -36,8 +36,8 @@ export const initFusion = async ({
 
   AppLogger.info('Token Fusion Program', explorerAddressLink(stfProgram.publicKey, { cluster }));
 
-  const [dataPda] = findFusionDataPda(umi);
-  const [escrowPda] = findEscrowAtaPda(umi, tokenMint || token.publicKey);
+  const [dataPda] = findFusionDataPda(umi, collectionMint || collection.publicKey);
+  const [escrowPda] = findEscrowAtaPda(umi, dataPda, tokenMint || token.publicKey);
 
   const accountExists = await umi.rpc.accountExists(dataPda);
 
@@ -97,11 +97,11 @@ export const updateFusionData = async ({
   updateAssetData,
   updateFeeData,
 }: FusionDataUpdateCliOptions) => {
-  const { umi, clusterSettings, stfProgram } = await createUmi(cluster);
+  const { umi, clusterSettings, stfProgram, collection } = await createUmi(cluster);
 
   AppLogger.info('Token Fusion Program', explorerAddressLink(stfProgram.publicKey, { cluster }));
 
-  const [dataPda] = findFusionDataPda(umi);
+  const [dataPda] = findFusionDataPda(umi, collection.publicKey);
 
   const accountExists = await umi.rpc.accountExists(dataPda);
 
@@ -169,11 +169,11 @@ export const updateFusionData = async ({
 };
 
 export const setPauseFusion = async ({ cluster, pause }: FusionPauseCliOptions) => {
-  const { umi, clusterSettings, stfProgram } = await createUmi(cluster);
+  const { umi, clusterSettings, stfProgram, collection } = await createUmi(cluster);
 
   AppLogger.info('Token Fusion Program', explorerAddressLink(stfProgram.publicKey, { cluster }));
 
-  const [dataPda] = findFusionDataPda(umi);
+  const [dataPda] = findFusionDataPda(umi, collection.publicKey);
 
   const accountExists = await umi.rpc.accountExists(dataPda);
 
@@ -205,11 +205,11 @@ export const setPauseFusion = async ({ cluster, pause }: FusionPauseCliOptions)
 };
 
 export const showFusionData = async ({ cluster }: BaseCliOptions) => {
-  const { umi, stfProgram } = await createUmi(cluster);
+  const { umi, stfProgram, collection } = await createUmi(cluster);
 
   AppLogger.info('Token Fusion Program', explorerAddressLink(stfProgram.publicKey, { cluster }));
 
-  const [dataPda] = findFusionDataPda(umi);
+  const [dataPda] = findFusionDataPda(umi, collection.publicKey);
 
   const accountExists = await umi.rpc.accountExists(dataPda);
 
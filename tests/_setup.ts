@@ -1,14 +1,22 @@
 import { createCollection as createCollectionCore } from '@metaplex-foundation/mpl-core';
 import { createFungible, mintV1, TokenStandard } from '@metaplex-foundation/mpl-token-metadata';
-import { createAssociatedToken, findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
 import {
+  createAccount,
+  createAssociatedToken,
+  fetchToken,
+  findAssociatedTokenPda,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  Pda,
   PublicKey,
   Signer,
   Umi,
   generateSigner,
   percentAmount,
+  publicKey,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
+import { publicKey as publicKeySerializer } from '@metaplex-foundation/umi/serializers';
 
 import bs58 from 'bs58';
 import crypto from 'crypto';
@@ -168,6 +176,46 @@ export const createAta = async (umi: Umi, { mint, owner, payer }: AtaAccounts):
   return authorityAta;
 };
 
+export const BUBBLEGUM_PROGRAM_ID = publicKey('BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY');
+export const COMPRESSION_PROGRAM_ID = publicKey('cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK');
+
+// header, sequence number, active index and buffer size, change logs and rightmost path of the tree
+export const getMerkleTreeSize = (maxDepth: number, maxBufferSize: number): number =>
+  56 + 24 + maxBufferSize * (32 + maxDepth * 32 + 8) + (maxDepth * 32 + 32 + 8);
+
+export const findTreeConfigPda = (umi: Umi, merkleTree: PublicKey): Pda =>
+  umi.eddsa.findPda(BUBBLEGUM_PROGRAM_ID, [publicKeySerializer().serialize(merkleTree)]);
+
+// allocate the merkle tree account, initialized by the compression program
+export const createMerkleTree = async (
+  umi: Umi,
+  maxDepth: number,
+  maxBufferSize: number
+): Promise<Signer> => {
+  const merkleTree = generateSigner(umi);
+  const space = getMerkleTreeSize(maxDepth, maxBufferSize);
+
+  await createAccount(umi, {
+    newAccount: merkleTree,
+    lamports: await umi.rpc.getRent(space),
+    space,
+    programId: COMPRESSION_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  return merkleTree;
+};
+
+export const getTokenBalance = async (umi: Umi, mint: PublicKey, owner: PublicKey): Promise<bigint> => {
+  const [ata] = findAssociatedTokenPda(umi, {
+    mint,
+    owner,
+  });
+
+  const token = await fetchToken(umi, ata);
+
+  return token.amount;
+};
+
 export const getAssetURI = (index: number, collection: PublicKey, secret: PublicKey): string => {
   const shasum = crypto.createHash('sha256');
   shasum.update(collection.toString());
@@ -177,3 +225,16 @@ export const getAssetURI = (index: number, collection: PublicKey, secret: Public
 
   return `https://stf.org/metadata/${index}/${res.slice(0, 8)}`;
 };
+
+// hash of the collection, the metadata index (u64 le) and the revealed salt, in its short form
+export const getSaltedAssetHash = (index: bigint, collection: PublicKey, salt: Uint8Array): string => {
+  const indexBytes = Buffer.alloc(8);
+  indexBytes.writeBigUInt64LE(index);
+
+  const shasum = crypto.createHash('sha256');
+  shasum.update(publicKeySerializer().serialize(collection));
+  shasum.update(indexBytes);
+  shasum.update(salt);
+
+  return bs58.encode(shasum.digest()).slice(0, 8);
+};
@@ -1,8 +1,23 @@
 import { expect } from 'chai';
-import { generateSigner, none, publicKey, some } from '@metaplex-foundation/umi';
+import crypto from 'crypto';
+import {
+  PublicKey,
+  Signer,
+  Umi,
+  generateSigner,
+  none,
+  publicKey,
+  some,
+  unwrapOption,
+} from '@metaplex-foundation/umi';
 
-import { fetchToken, safeFetchToken } from '@metaplex-foundation/mpl-toolbox';
-import { fetchAsset, fetchCollection } from '@metaplex-foundation/mpl-core';
+import {
+  approveTokenDelegate,
+  fetchToken,
+  findAssociatedTokenPda,
+  safeFetchToken,
+} from '@metaplex-foundation/mpl-toolbox';
+import { addPlugin, fetchAsset, fetchCollection } from '@metaplex-foundation/mpl-core';
 
 import { explorerTxLink } from '../src/utils/explorer';
 import { AppLogger } from '../src/utils/logger';
@@ -10,22 +25,62 @@ import { AppLogger } from '../src/utils/logger';
 import { createUmi } from '../src/utils/umi';
 
 import {
+  AssetAddressModeV1,
   AssetDataV1,
+  AssetOutputModeV1,
+  AssetRedeemModeV1,
+  assetHashModeV1,
+  BackingDataV1,
   destroyV1,
+  fetchAssetIndexV1,
+  fetchAssetRecordV1,
   fetchFusionDataV1,
+  fetchMetadataPoolV1,
+  findAssetIndexPda,
+  findAssetRecordPda,
   findEscrowAtaPda,
+  findFusionAuthorityPda,
   findFusionDataPda,
+  findMetadataPoolPda,
   fusionFromV1,
+  fusionIntoCompressedV1,
   fusionIntoV1,
+  initMetadataPoolV1,
+  initTreeV1,
   initV1,
+  mergeAssetsV1,
+  MetadataModeV1,
+  PluginDataV1,
+  recordAssetsV1,
+  revealV1,
+  safeFetchAssetIndexV1,
+  safeFetchAssetRecordV1,
   safeFetchFusionDataV1,
   setAuthorityV1,
+  setBackingV1,
   setPauseV1,
+  setTiersV1,
+  splitAssetV1,
+  topUpV1,
   FeeDataV1,
+  TierV1,
+  TOKEN_FUSION_PROGRAM_ID,
+  updateAssetsV1,
   updateV1,
+  withdrawPartialV1,
 } from '../packages/client';
 
-import { generateAsset, createCollection, createToken, createAta, getAssetURI } from './_setup';
+import {
+  generateAsset,
+  createCollection,
+  createToken,
+  createAta,
+  createMerkleTree,
+  findTreeConfigPda,
+  getAssetURI,
+  getSaltedAssetHash,
+  getTokenBalance,
+} from './_setup';
 
 const AUTH_ERROR_MESSAGE = 'Error Number: 2001. Error Message: A has one constraint was violated.';
 
@@ -43,7 +98,7 @@ const setupContext = async () => {
     treasure,
   } = await createUmi();
 
-  const [dataPda] = findFusionDataPda(umi);
+  const [dataPda] = findFusionDataPda(umi, collectionSigner.publicKey);
 
   const token = await createToken(umi, { mint: tokenSigner });
   const collection = await createCollection(umi, { collection: collectionSigner });
@@ -71,12 +126,62 @@ const setupContext = async () => {
   };
 };
 
+// fresh fusion with its own token and collection, umi.identity = deployer is the authority
+const setupFusionContext = async (assetData: AssetDataV1, feeData: FeeDataV1) => {
+  const { umi, deployer, user } = await createUmi();
+
+  const token = await createToken(umi);
+  const collection = await createCollection(umi);
+
+  const [dataPda] = findFusionDataPda(umi, collection.collection.publicKey);
+
+  await initV1(umi, {
+    tokenMint: token.mint.publicKey,
+    collection: collection.collection.publicKey,
+    assetData,
+    feeData,
+  }).sendAndConfirm(umi);
+
+  return {
+    umi,
+    deployer,
+    user,
+    dataPda,
+    token,
+    collection,
+  };
+};
+
+// remaining accounts of the assets to reveal or update, each followed by its record
+const assetRecordAccounts = (umi: Umi, dataPda: PublicKey, assets: PublicKey[]) =>
+  assets
+    .flatMap((asset) => [asset, findAssetRecordPda(umi, dataPda, asset)[0]])
+    .map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }));
+
+const PLUGIN_DATA_V1: PluginDataV1 = {
+  royalties: none(),
+  attributes: false,
+  permanentBurnDelegate: false,
+  freezeDelegate: false,
+};
+
 const ASSET_DATA_V1: AssetDataV1 = {
   maxSupply: some(3),
   nextIndex: 1n,
   namePrefix: 'STF #',
   uriPrefix: 'https://stf.org/metadata/',
   uriSuffix: '',
+  addressMode: AssetAddressModeV1.Keypair,
+  plugins: PLUGIN_DATA_V1,
+  metadataMode: MetadataModeV1.Sequential,
+  placeholderUri: none(),
+  nameTemplate: none(),
+  uriTemplate: none(),
+  hashMode: assetHashModeV1('Legacy'),
+  fullHash: false,
+  hashSalt: none(),
+  outputMode: AssetOutputModeV1.Core,
+  redeemMode: AssetRedeemModeV1.Burn,
 };
 
 const FEE_DATA_V1: FeeDataV1 = {
@@ -88,11 +193,9 @@ const FEE_DATA_V1: FeeDataV1 = {
 };
 
 const ASSET_DATA_V2: AssetDataV1 = {
+  ...ASSET_DATA_V1,
   maxSupply: some(1),
   nextIndex: 11n,
-  namePrefix: 'STF #',
-  uriPrefix: 'https://stf.org/metadata/',
-  uriSuffix: '',
 };
 
 const FEE_DATA_V2: FeeDataV1 = {
@@ -103,6 +206,54 @@ const FEE_DATA_V2: FeeDataV1 = {
   feeRecipient: none(),
 };
 
+// escrow only, no fees
+const escrowFeeData = (escrowAmount: bigint): FeeDataV1 => ({
+  escrowAmount,
+  feeAmount: 0n,
+  burnAmount: 0n,
+  solFeeAmount: 0n,
+  feeRecipient: none(),
+});
+
+// tiered assets are recorded, the attributes plugin shows the tier and the backing
+const ASSET_DATA_TIERS: AssetDataV1 = {
+  ...ASSET_DATA_V1,
+  maxSupply: none(),
+  plugins: { ...PLUGIN_DATA_V1, attributes: true },
+};
+
+const TIERS_V1: TierV1[] = [
+  {
+    feeData: escrowFeeData(100n * 10n ** 9n),
+    maxSupply: some(1),
+    supply: 0,
+    nameTemplate: some('Note 100 #{index}'),
+    uriTemplate: some('https://stf.org/notes/100/{index}.json'),
+  },
+  {
+    feeData: escrowFeeData(1_000n * 10n ** 9n),
+    maxSupply: none(),
+    supply: 0,
+    nameTemplate: some('Note 1000 #{index}'),
+    uriTemplate: some('https://stf.org/notes/1000/{index}.json'),
+  },
+];
+
+// variable backed assets are named after the bracket of their amount
+const ASSET_DATA_BACKING: AssetDataV1 = {
+  ...ASSET_DATA_TIERS,
+  nameTemplate: some('Bar {bracket} #{index}'),
+  uriTemplate: some('https://stf.org/bars/{bracket}/{index}.json'),
+};
+
+const BACKING_V1: BackingDataV1 = {
+  minAmount: 10n * 10n ** 9n,
+  maxAmount: 10_000n * 10n ** 9n,
+  brackets: [100n * 10n ** 9n, 1_000n * 10n ** 9n],
+  minRemaining: 10n ** 9n,
+  supply: 0,
+};
+
 describe('Solana Token Fusion Protocol', () => {
   let context: TestContext;
 
@@ -154,12 +305,16 @@ describe('Solana Token Fusion Protocol', () => {
     const { umi, dataPda, token, collection, asset, feeRecipient, feeRecipientAta } = context;
 
     const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
       user: umi.identity,
       asset: asset.asset,
       collection: collection.collection.publicKey,
       tokenMint: token.mint.publicKey,
       feeRecipient,
       feeRecipientAta,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex),
     }).sendAndConfirm(umi, { send: { skipPreflight: true } });
 
     DEBUG && AppLogger.info('Fusion Into TX', explorerTxLink(res.signature));
@@ -182,8 +337,15 @@ describe('Solana Token Fusion Protocol', () => {
     expect(dataAccount.assetData.nextIndex).to.equal(2n);
     expect(assetData.uri).to.equal(assetURI);
 
+    // the index account of the mint index registers the asset and its metadata index
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex);
+    const indexAccount = await fetchAssetIndexV1(umi, assetIndex);
+    expect(indexAccount.asset).to.equal(asset.asset.publicKey);
+    expect(indexAccount.minter).to.equal(umi.identity.publicKey);
+    expect(indexAccount.index).to.equal(ASSET_DATA_V1.nextIndex);
+
     // check escrow balance
-    const [escrowAta] = findEscrowAtaPda(umi, token.mint.publicKey);
+    const [escrowAta] = findEscrowAtaPda(umi, dataPda, token.mint.publicKey);
     const escrowData = await fetchToken(umi, escrowAta);
     expect(escrowData.amount).to.equal(FEE_DATA_V1.escrowAmount);
   });
@@ -194,6 +356,7 @@ describe('Solana Token Fusion Protocol', () => {
     // umi.identity = deployer
 
     const res = await setPauseV1(umi, {
+      fusionData: dataPda,
       authority: deployer,
       paused: true,
     }).sendAndConfirm(umi, { send: { skipPreflight: true } });
@@ -205,9 +368,13 @@ describe('Solana Token Fusion Protocol', () => {
   });
 
   it('[Error] FusionFromV1 - Fusion Paused', async () => {
-    const { umi, token, collection, asset } = context;
+    const { umi, dataPda, token, collection, asset } = context;
+
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex);
 
     const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex,
       user: umi.identity,
       asset: asset.asset.publicKey,
       collection: collection.collection.publicKey,
@@ -242,7 +409,13 @@ describe('Solana Token Fusion Protocol', () => {
   it('[Success] FusionFromV1', async () => {
     const { umi, dataPda, token, collection, asset } = context;
 
+    const [assetRecord] = findAssetRecordPda(umi, dataPda, asset.asset.publicKey);
+    const record = await fetchAssetRecordV1(umi, assetRecord);
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, record.mintIndex);
+
     const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex,
       user: umi.identity,
       asset: asset.asset.publicKey,
       collection: collection.collection.publicKey,
@@ -263,9 +436,12 @@ describe('Solana Token Fusion Protocol', () => {
     expect(dataAccount.assetData.nextIndex).to.equal(2n);
 
     // check escrow balance
-    const [escrowAta] = findEscrowAtaPda(umi, token.mint.publicKey);
+    const [escrowAta] = findEscrowAtaPda(umi, dataPda, token.mint.publicKey);
     const escrowData = await fetchToken(umi, escrowAta);
     expect(escrowData.amount).to.equal(0n);
+
+    // the index account of the burned asset is closed
+    expect(await safeFetchAssetIndexV1(umi, assetIndex)).to.equal(null);
   });
 
   it('[Success] SetAuthorityV1', async () => {
@@ -274,6 +450,7 @@ describe('Solana Token Fusion Protocol', () => {
     // umi.identity = deployer
 
     const res = await setAuthorityV1(umi, {
+      fusionData: dataPda,
       authority: deployer,
       newAuthority: user.publicKey,
     }).sendAndConfirm(umi, { send: { skipPreflight: true } });
@@ -285,11 +462,12 @@ describe('Solana Token Fusion Protocol', () => {
   });
 
   it('[Error] SetAuthorityV1 - authority constrains', async () => {
-    const { umi, deployer, user } = context;
+    const { umi, deployer, user, dataPda } = context;
 
     // umi.identity = deployer
 
     const res = await setAuthorityV1(umi, {
+      fusionData: dataPda,
       authority: deployer,
       newAuthority: user.publicKey,
     }).sendAndConfirm(umi, { send: { skipPreflight: true } });
@@ -299,11 +477,12 @@ describe('Solana Token Fusion Protocol', () => {
   });
 
   it('[Error] SetPauseV1 - authority constrains', async () => {
-    const { umi, deployer } = context;
+    const { umi, deployer, dataPda } = context;
 
     // umi.identity = deployer
 
     const res = await setPauseV1(umi, {
+      fusionData: dataPda,
       authority: deployer,
       paused: false,
     }).sendAndConfirm(umi, { send: { skipPreflight: true } });
@@ -313,11 +492,12 @@ describe('Solana Token Fusion Protocol', () => {
   });
 
   it('[Error] UpdateV1 - authority constrains', async () => {
-    const { umi, deployer } = context;
+    const { umi, deployer, dataPda } = context;
 
     // umi.identity = deployer
 
     const res = await updateV1(umi, {
+      fusionData: dataPda,
       authority: deployer,
       assetData: ASSET_DATA_V1,
       feeData: FEE_DATA_V1,
@@ -328,11 +508,12 @@ describe('Solana Token Fusion Protocol', () => {
   });
 
   it('[Error] DestroyV1 - authority constrains', async () => {
-    const { umi, deployer, token, collection } = context;
+    const { umi, deployer, dataPda, token, collection } = context;
 
     // umi.identity = deployer
 
     const res = await destroyV1(umi, {
+      fusionData: dataPda,
       authority: deployer,
       tokenMint: token.mint.publicKey,
       collection: collection.collection.publicKey,
@@ -348,6 +529,7 @@ describe('Solana Token Fusion Protocol', () => {
     umi.identity = user;
 
     const res = await updateV1(umi, {
+      fusionData: dataPda,
       assetData: ASSET_DATA_V2,
       feeData: FEE_DATA_V2,
     }).sendAndConfirm(umi, { send: { skipPreflight: true } });
@@ -365,12 +547,16 @@ describe('Solana Token Fusion Protocol', () => {
     const asset = generateSigner(umi);
 
     const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
       user: deployer,
       asset: asset,
       collection: collection.collection.publicKey,
       tokenMint: token.mint.publicKey,
       feeRecipient,
       feeRecipientAta,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_V2.nextIndex),
     }).sendAndConfirm(umi, { send: { skipPreflight: true } });
 
     DEBUG && AppLogger.info('Fusion Into TX#1', explorerTxLink(res.signature));
@@ -387,23 +573,27 @@ describe('Solana Token Fusion Protocol', () => {
     expect(assetData.name).to.equal('STF #11');
 
     // check escrow balance
-    const [escrowAta] = findEscrowAtaPda(umi, token.mint.publicKey);
+    const [escrowAta] = findEscrowAtaPda(umi, dataPda, token.mint.publicKey);
     const escrowData = await fetchToken(umi, escrowAta);
     expect(escrowData.amount).to.equal(FEE_DATA_V2.escrowAmount);
   });
 
   it('[Error] FusionIntoV1 - asset limit constrains', async () => {
-    const { umi, deployer, token, collection, feeRecipient, feeRecipientAta } = context;
+    const { umi, dataPda, deployer, token, collection, feeRecipient, feeRecipientAta } = context;
 
     const asset = generateSigner(umi);
 
     const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
       user: deployer,
       asset: asset,
       collection: collection.collection.publicKey,
       tokenMint: token.mint.publicKey,
       feeRecipient,
       feeRecipientAta,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_V2.nextIndex + 1n),
     }).sendAndConfirm(umi, { send: { skipPreflight: true } });
 
     DEBUG && AppLogger.info('Fusion Into TX#2', explorerTxLink(res.signature));
@@ -420,6 +610,7 @@ describe('Solana Token Fusion Protocol', () => {
     umi.identity = user;
 
     const res = await destroyV1(umi, {
+      fusionData: dataPda,
       tokenMint: token.mint.publicKey,
       collection: collection.collection.publicKey,
     }).sendAndConfirm(umi, { send: { skipPreflight: true } });
@@ -430,8 +621,1546 @@ describe('Solana Token Fusion Protocol', () => {
     expect(dataAccount).to.eq(null);
 
     // check escrow account
-    const [escrowAta] = findEscrowAtaPda(umi, token.mint.publicKey);
+    const [escrowAta] = findEscrowAtaPda(umi, dataPda, token.mint.publicKey);
     const escrowData = await safeFetchToken(umi, escrowAta);
     expect(escrowData).to.equal(null);
   });
 });
+
+describe('Solana Token Fusion Protocol - Tiers', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+
+  // asset of the 1000 tokens tier
+  let tieredAsset: Signer;
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_TIERS, escrowFeeData(10n * 10n ** 9n));
+  });
+
+  it('[Success] SetTiersV1', async () => {
+    const { umi, dataPda } = context;
+
+    const res = await setTiersV1(umi, {
+      fusionData: dataPda,
+      tiers: TIERS_V1,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Set Tiers TX', explorerTxLink(res.signature));
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.tiers).to.deep.equal(TIERS_V1);
+  });
+
+  it('[Error] FusionIntoV1 - tier required', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: generateSigner(umi),
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_TIERS.nextIndex),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(
+      receipt?.meta.logs.some((l) => l.includes('Error Number: 6046. Error Message: Invalid tier.'))
+    ).eq(true);
+  });
+
+  it('[Success] FusionIntoV1 - tier 1', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    tieredAsset = generateSigner(umi);
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: tieredAsset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: 1,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, 1n),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion Into Tier TX', explorerTxLink(res.signature));
+
+    const assetData = await fetchAsset(umi, tieredAsset.publicKey);
+    expect(assetData.name).to.equal('Note 1000 #1');
+    expect(assetData.uri).to.equal('https://stf.org/notes/1000/1.json');
+    expect(assetData.attributes?.attributeList).to.deep.include({ key: 'tier', value: '1' });
+    expect(assetData.attributes?.attributeList).to.deep.include({
+      key: 'backing',
+      value: TIERS_V1[1].feeData.escrowAmount.toString(),
+    });
+
+    // the tier is recorded with its escrow amount
+    const [assetRecord] = findAssetRecordPda(umi, dataPda, tieredAsset.publicKey);
+    const record = await fetchAssetRecordV1(umi, assetRecord);
+    expect(record.tier).to.deep.equal(some(1));
+    expect(record.amount).to.equal(TIERS_V1[1].feeData.escrowAmount);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.tiers[1].supply).to.equal(1);
+
+    const [escrowAta] = findEscrowAtaPda(umi, dataPda, token.mint.publicKey);
+    const escrowData = await fetchToken(umi, escrowAta);
+    expect(escrowData.amount).to.equal(TIERS_V1[1].feeData.escrowAmount);
+  });
+
+  it('[Success] FusionIntoV1 - tier 0', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const asset = generateSigner(umi);
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: 0,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, 2n),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion Into Tier TX', explorerTxLink(res.signature));
+
+    const assetData = await fetchAsset(umi, asset.publicKey);
+    expect(assetData.name).to.equal('Note 100 #2');
+
+    const [escrowAta] = findEscrowAtaPda(umi, dataPda, token.mint.publicKey);
+    const escrowData = await fetchToken(umi, escrowAta);
+    expect(escrowData.amount).to.equal(TIERS_V1[0].feeData.escrowAmount + TIERS_V1[1].feeData.escrowAmount);
+  });
+
+  it('[Error] FusionIntoV1 - tier max supply constrains', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: generateSigner(umi),
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: 0,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, 3n),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(
+      receipt?.meta.logs.some((l) => l.includes('Error Number: 6027. Error Message: Max supply reached.'))
+    ).eq(true);
+  });
+
+  it('[Success] FusionFromV1 - tier 1', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const balance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+
+    const [assetRecord] = findAssetRecordPda(umi, dataPda, tieredAsset.publicKey);
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, 1n);
+
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex,
+      user: umi.identity,
+      asset: tieredAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion From Tier TX', explorerTxLink(res.signature));
+
+    // the recorded tier amount is paid back
+    const newBalance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+    expect(newBalance - balance).to.equal(TIERS_V1[1].feeData.escrowAmount);
+
+    const record = await safeFetchAssetRecordV1(umi, assetRecord);
+    expect(record).to.eq(null);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.tiers[1].supply).to.equal(0);
+
+    const [escrowAta] = findEscrowAtaPda(umi, dataPda, token.mint.publicKey);
+    const escrowData = await fetchToken(umi, escrowAta);
+    expect(escrowData.amount).to.equal(TIERS_V1[0].feeData.escrowAmount);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Variable Backing', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+
+  // asset backed by 250 tokens
+  let backedAsset: Signer;
+  // asset split from the backed asset
+  let splitAsset: Signer;
+
+  const BACKED_AMOUNT = 250n * 10n ** 9n;
+  const BACKING_ERROR_MESSAGE = 'Error Number: 6048. Error Message: Invalid variable backing.';
+
+  // the backing attribute of the asset follows its recorded amount
+  const expectBackingAttribute = async (asset: PublicKey, amount: bigint) => {
+    const [assetRecord] = findAssetRecordPda(context.umi, context.dataPda, asset);
+    const record = await fetchAssetRecordV1(context.umi, assetRecord);
+    expect(record.amount).to.equal(amount);
+
+    const assetData = await fetchAsset(context.umi, asset);
+    expect(assetData.attributes?.attributeList).to.deep.include({
+      key: 'backing',
+      value: amount.toString(),
+    });
+  };
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_BACKING, escrowFeeData(10n * 10n ** 9n));
+  });
+
+  it('[Success] SetBackingV1', async () => {
+    const { umi, dataPda } = context;
+
+    const res = await setBackingV1(umi, {
+      fusionData: dataPda,
+      backing: some(BACKING_V1),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Set Backing TX', explorerTxLink(res.signature));
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.backing).to.deep.equal(some(BACKING_V1));
+  });
+
+  it('[Error] FusionIntoV1 - amount required', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: generateSigner(umi),
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_BACKING.nextIndex),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(BACKING_ERROR_MESSAGE))).eq(true);
+  });
+
+  it('[Error] FusionIntoV1 - amount below the min amount', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: generateSigner(umi),
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: 5n * 10n ** 9n,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_BACKING.nextIndex),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(BACKING_ERROR_MESSAGE))).eq(true);
+  });
+
+  it('[Success] FusionIntoV1 - variable amount', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    backedAsset = generateSigner(umi);
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: backedAsset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: BACKED_AMOUNT,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_BACKING.nextIndex),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion Into Backing TX', explorerTxLink(res.signature));
+
+    // 250 tokens fall in the second bracket
+    const assetData = await fetchAsset(umi, backedAsset.publicKey);
+    expect(assetData.name).to.equal('Bar 1 #1');
+    expect(assetData.uri).to.equal('https://stf.org/bars/1/1.json');
+    expect(assetData.attributes?.attributeList).to.deep.include({
+      key: 'backing',
+      value: BACKED_AMOUNT.toString(),
+    });
+
+    const [assetRecord] = findAssetRecordPda(umi, dataPda, backedAsset.publicKey);
+    const record = await fetchAssetRecordV1(umi, assetRecord);
+    expect(record.tier).to.deep.equal(none());
+    expect(record.amount).to.equal(BACKED_AMOUNT);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(unwrapOption(dataAccount.backing)?.supply).to.equal(1);
+
+    // the chosen amount replaces the escrow amount of the fusion
+    const [escrowAta] = findEscrowAtaPda(umi, dataPda, token.mint.publicKey);
+    const escrowData = await fetchToken(umi, escrowAta);
+    expect(escrowData.amount).to.equal(BACKED_AMOUNT);
+  });
+
+  it('[Success] WithdrawPartialV1 - backing attribute updated', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const balance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+
+    const res = await withdrawPartialV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: backedAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      amount: 200n * 10n ** 9n,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Withdraw Partial TX', explorerTxLink(res.signature));
+
+    const newBalance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+    expect(newBalance - balance).to.equal(200n * 10n ** 9n);
+
+    // 50 tokens fall in the first bracket
+    const assetData = await fetchAsset(umi, backedAsset.publicKey);
+    expect(assetData.name).to.equal('Bar 0 #1');
+    await expectBackingAttribute(backedAsset.publicKey, 50n * 10n ** 9n);
+  });
+
+  it('[Success] TopUpV1 - backing attribute updated', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const balance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+
+    const res = await topUpV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: backedAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      amount: 1_000n * 10n ** 9n,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Top Up TX', explorerTxLink(res.signature));
+
+    const newBalance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+    expect(balance - newBalance).to.equal(1_000n * 10n ** 9n);
+
+    // 1050 tokens fall in the third bracket
+    const assetData = await fetchAsset(umi, backedAsset.publicKey);
+    expect(assetData.name).to.equal('Bar 2 #1');
+    expect(assetData.uri).to.equal('https://stf.org/bars/2/1.json');
+    await expectBackingAttribute(backedAsset.publicKey, 1_050n * 10n ** 9n);
+  });
+
+  it('[Error] TopUpV1 - above the max amount', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const res = await topUpV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: backedAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      amount: BACKING_V1.maxAmount,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(BACKING_ERROR_MESSAGE))).eq(true);
+  });
+
+  it('[Success] SplitAssetV1 - backing attributes updated', async () => {
+    const { umi, dataPda, collection } = context;
+
+    splitAsset = generateSigner(umi);
+
+    const res = await splitAssetV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: backedAsset.publicKey,
+      newAsset: splitAsset,
+      newAssetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_BACKING.nextIndex + 1n),
+      collection: collection.collection.publicKey,
+      amount: 300n * 10n ** 9n,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Split Asset TX', explorerTxLink(res.signature));
+
+    // 750 tokens stay in the second bracket
+    const assetData = await fetchAsset(umi, backedAsset.publicKey);
+    expect(assetData.name).to.equal('Bar 1 #1');
+    await expectBackingAttribute(backedAsset.publicKey, 750n * 10n ** 9n);
+
+    // the new asset is minted with the split amount
+    const splitAssetData = await fetchAsset(umi, splitAsset.publicKey);
+    expect(splitAssetData.name).to.equal('Bar 1 #2');
+    await expectBackingAttribute(splitAsset.publicKey, 300n * 10n ** 9n);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(unwrapOption(dataAccount.backing)?.supply).to.equal(2);
+  });
+
+  it('[Success] MergeAssetsV1 - backing attribute updated', async () => {
+    const { umi, dataPda, collection } = context;
+
+    const res = await mergeAssetsV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: backedAsset.publicKey,
+      burnedAsset: splitAsset.publicKey,
+      burnedAssetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_BACKING.nextIndex + 1n),
+      collection: collection.collection.publicKey,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Merge Assets TX', explorerTxLink(res.signature));
+
+    // 1050 tokens are back in the third bracket
+    const assetData = await fetchAsset(umi, backedAsset.publicKey);
+    expect(assetData.name).to.equal('Bar 2 #1');
+    await expectBackingAttribute(backedAsset.publicKey, 1_050n * 10n ** 9n);
+
+    // the burned asset and its record are gone
+    const [burnedAssetRecord] = findAssetRecordPda(umi, dataPda, splitAsset.publicKey);
+    expect(await safeFetchAssetRecordV1(umi, burnedAssetRecord)).to.equal(null);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(unwrapOption(dataAccount.backing)?.supply).to.equal(1);
+  });
+
+  it('[Success] FusionFromV1 - variable amount', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const balance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+
+    const [assetRecord] = findAssetRecordPda(umi, dataPda, backedAsset.publicKey);
+    const { amount } = await fetchAssetRecordV1(umi, assetRecord);
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_BACKING.nextIndex);
+
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex,
+      user: umi.identity,
+      asset: backedAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion From Backing TX', explorerTxLink(res.signature));
+
+    // the recorded amount is redeemed
+    const newBalance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+    expect(newBalance - balance).to.equal(amount);
+
+    const record = await safeFetchAssetRecordV1(umi, assetRecord);
+    expect(record).to.eq(null);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(unwrapOption(dataAccount.backing)?.supply).to.equal(0);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Delegates', () => {
+  // assets are owned by the deployer, the user is their delegate
+  let burnContext: Awaited<ReturnType<typeof setupFusionContext>>;
+  let vaultContext: Awaited<ReturnType<typeof setupFusionContext>>;
+
+  let burnAsset: Signer;
+  let vaultAsset: Signer;
+
+  const DELEGATE_FEE_DATA = escrowFeeData(100n * 10n ** 9n);
+  const ASSET_DATA_VAULT: AssetDataV1 = { ...ASSET_DATA_V1, redeemMode: AssetRedeemModeV1.Vault };
+
+  const DELEGATE_ERROR_MESSAGE =
+    'Error Number: 6051. Error Message: User is neither the owner nor a delegate of the asset.';
+  const RECIPIENT_ERROR_MESSAGE =
+    'Error Number: 6049. Error Message: Recipient and recipient ata must be set together.';
+
+  // mint the first asset of the fusion to the deployer
+  const mintAsset = async ({ umi, dataPda, token, collection }: typeof burnContext) => {
+    const asset = generateSigner(umi);
+
+    await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex),
+    }).sendAndConfirm(umi);
+
+    return asset;
+  };
+
+  before(async () => {
+    burnContext = await setupFusionContext(ASSET_DATA_V1, DELEGATE_FEE_DATA);
+    vaultContext = await setupFusionContext(ASSET_DATA_VAULT, DELEGATE_FEE_DATA);
+
+    burnAsset = await mintAsset(burnContext);
+    vaultAsset = await mintAsset(vaultContext);
+  });
+
+  it('[Error] FusionFromV1 - transfer delegate in burn redeem mode', async () => {
+    const { umi, dataPda, user, deployer, token, collection } = burnContext;
+
+    await addPlugin(umi, {
+      asset: burnAsset.publicKey,
+      collection: collection.collection.publicKey,
+      plugin: { type: 'TransferDelegate', authority: { type: 'Address', address: user.publicKey } },
+    }).sendAndConfirm(umi);
+
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex);
+
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex,
+      user,
+      asset: burnAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      recipient: deployer.publicKey,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(DELEGATE_ERROR_MESSAGE))).eq(true);
+  });
+
+  it('[Error] FusionFromV1 - burn delegate without the owner as recipient', async () => {
+    const { umi, dataPda, user, token, collection } = burnContext;
+
+    await addPlugin(umi, {
+      asset: burnAsset.publicKey,
+      collection: collection.collection.publicKey,
+      plugin: { type: 'BurnDelegate', authority: { type: 'Address', address: user.publicKey } },
+    }).sendAndConfirm(umi);
+
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex);
+
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex,
+      user,
+      asset: burnAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(RECIPIENT_ERROR_MESSAGE))).eq(true);
+  });
+
+  it('[Success] FusionFromV1 - burn delegate', async () => {
+    const { umi, dataPda, user, deployer, token, collection } = burnContext;
+
+    const balance = await getTokenBalance(umi, token.mint.publicKey, deployer.publicKey);
+
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex);
+
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex,
+      user,
+      asset: burnAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      recipient: deployer.publicKey,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion From Burn Delegate TX', explorerTxLink(res.signature));
+
+    const assetExists = await umi.rpc.accountExists(burnAsset.publicKey);
+    expect(assetExists).to.eq(false);
+
+    // the escrow goes to the owner
+    const newBalance = await getTokenBalance(umi, token.mint.publicKey, deployer.publicKey);
+    expect(newBalance - balance).to.equal(DELEGATE_FEE_DATA.escrowAmount);
+  });
+
+  it('[Success] FusionFromV1 - transfer delegate in vault redeem mode', async () => {
+    const { umi, dataPda, user, deployer, token, collection } = vaultContext;
+
+    await addPlugin(umi, {
+      asset: vaultAsset.publicKey,
+      collection: collection.collection.publicKey,
+      plugin: { type: 'TransferDelegate', authority: { type: 'Address', address: user.publicKey } },
+    }).sendAndConfirm(umi);
+
+    const balance = await getTokenBalance(umi, token.mint.publicKey, deployer.publicKey);
+
+    // vaulted assets keep their index
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      user,
+      asset: vaultAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      recipient: deployer.publicKey,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion From Transfer Delegate TX', explorerTxLink(res.signature));
+
+    const [authorityPda] = findFusionAuthorityPda(umi, dataPda);
+    const assetData = await fetchAsset(umi, vaultAsset.publicKey);
+    expect(assetData.owner).to.equal(authorityPda);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.vaultSize).to.equal(1);
+
+    // the escrow goes to the owner
+    const newBalance = await getTokenBalance(umi, token.mint.publicKey, deployer.publicKey);
+    expect(newBalance - balance).to.equal(DELEGATE_FEE_DATA.escrowAmount);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Recipients', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+
+  // asset fused by the deployer for the user
+  let giftedAsset: Signer;
+
+  const RECIPIENT_FEE_DATA = escrowFeeData(100n * 10n ** 9n);
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_V1, RECIPIENT_FEE_DATA);
+  });
+
+  it('[Success] FusionIntoV1 - asset minted to a recipient', async () => {
+    const { umi, dataPda, user, token, collection } = context;
+
+    giftedAsset = generateSigner(umi);
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: giftedAsset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      recipient: user.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion Into Recipient TX', explorerTxLink(res.signature));
+
+    // the recipient owns the asset, the user fused the tokens
+    const assetData = await fetchAsset(umi, giftedAsset.publicKey);
+    expect(assetData.owner).to.equal(user.publicKey);
+
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex);
+    const indexAccount = await fetchAssetIndexV1(umi, assetIndex);
+    expect(indexAccount.minter).to.equal(umi.identity.publicKey);
+  });
+
+  it('[Success] FusionFromV1 - tokens redeemed to a recipient', async () => {
+    const { umi, dataPda, user, token, collection } = context;
+
+    // new wallet without a token account, its ata is created by the redeem
+    const recipient = generateSigner(umi).publicKey;
+    const [recipientAta] = findAssociatedTokenPda(umi, { mint: token.mint.publicKey, owner: recipient });
+
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex);
+
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex,
+      user,
+      asset: giftedAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      recipient,
+      recipientAta,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion From Recipient TX', explorerTxLink(res.signature));
+
+    const assetExists = await umi.rpc.accountExists(giftedAsset.publicKey);
+    expect(assetExists).to.eq(false);
+
+    const recipientData = await fetchToken(umi, recipientAta);
+    expect(recipientData.amount).to.equal(RECIPIENT_FEE_DATA.escrowAmount);
+
+    // nothing goes to the user
+    const [userAta] = findAssociatedTokenPda(umi, { mint: token.mint.publicKey, owner: user.publicKey });
+    const userData = await safeFetchToken(umi, userAta);
+    expect(userData?.amount ?? 0n).to.equal(0n);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Sponsored Transactions', () => {
+  // the deployer signs for its tokens, the user pays every lamport
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+
+  let sponsoredAsset: Signer;
+
+  const SPONSORED_FEE_DATA = escrowFeeData(100n * 10n ** 9n);
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_V1, SPONSORED_FEE_DATA);
+  });
+
+  it('[Success] FusionIntoV1 - sponsored by a payer', async () => {
+    const { umi, dataPda, user, token, collection } = context;
+
+    sponsoredAsset = generateSigner(umi);
+
+    const balance = await umi.rpc.getBalance(umi.identity.publicKey);
+    const payerBalance = await umi.rpc.getBalance(user.publicKey);
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      payer: user,
+      asset: sponsoredAsset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex),
+    })
+      .setFeePayer(user)
+      .sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion Into Sponsored TX', explorerTxLink(res.signature));
+
+    const assetData = await fetchAsset(umi, sponsoredAsset.publicKey);
+    expect(assetData.owner).to.equal(umi.identity.publicKey);
+
+    // rent, protocol fee and transaction fee are paid by the payer
+    const newBalance = await umi.rpc.getBalance(umi.identity.publicKey);
+    expect(newBalance.basisPoints).to.equal(balance.basisPoints);
+
+    const newPayerBalance = await umi.rpc.getBalance(user.publicKey);
+    expect(newPayerBalance.basisPoints < payerBalance.basisPoints).eq(true);
+  });
+
+  it('[Success] FusionFromV1 - sponsored by a payer', async () => {
+    const { umi, dataPda, user, token, collection } = context;
+
+    const balance = await umi.rpc.getBalance(umi.identity.publicKey);
+    const tokenBalance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex);
+
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex,
+      user: umi.identity,
+      payer: user,
+      asset: sponsoredAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+    })
+      .setFeePayer(user)
+      .sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion From Sponsored TX', explorerTxLink(res.signature));
+
+    const newTokenBalance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+    expect(newTokenBalance - tokenBalance).to.equal(SPONSORED_FEE_DATA.escrowAmount);
+
+    // the user only gets the rent of the closed accounts back
+    const newBalance = await umi.rpc.getBalance(umi.identity.publicKey);
+    expect(newBalance.basisPoints >= balance.basisPoints).eq(true);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Delegated Token Accounts', () => {
+  // the deployer holds the tokens, the user fuses them as its delegate
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+
+  const TOKEN_DELEGATE_FEE_DATA = escrowFeeData(100n * 10n ** 9n);
+
+  const TOKEN_AUTHORITY_ERROR_MESSAGE =
+    'Error Number: 6050. Error Message: User is neither the owner nor the delegate of the token account.';
+
+  // fuse the tokens of the deployer ata, signed by the user
+  const fuseDelegated = (asset: Signer) => {
+    const { umi, dataPda, user, token, collection } = context;
+
+    const [userAta] = findAssociatedTokenPda(umi, {
+      mint: token.mint.publicKey,
+      owner: umi.identity.publicKey,
+    });
+
+    return fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user,
+      asset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      userAta,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+  };
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_V1, TOKEN_DELEGATE_FEE_DATA);
+  });
+
+  it('[Error] FusionIntoV1 - token account neither owned nor delegated', async () => {
+    const { umi } = context;
+
+    const res = await fuseDelegated(generateSigner(umi));
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(TOKEN_AUTHORITY_ERROR_MESSAGE))).eq(true);
+  });
+
+  it('[Success] FusionIntoV1 - delegated token account', async () => {
+    const { umi, user, token } = context;
+
+    const [deployerAta] = findAssociatedTokenPda(umi, {
+      mint: token.mint.publicKey,
+      owner: umi.identity.publicKey,
+    });
+
+    await approveTokenDelegate(umi, {
+      source: deployerAta,
+      delegate: user.publicKey,
+      amount: TOKEN_DELEGATE_FEE_DATA.escrowAmount,
+    }).sendAndConfirm(umi);
+
+    const balance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+
+    const asset = generateSigner(umi);
+    const res = await fuseDelegated(asset);
+
+    DEBUG && AppLogger.info('Fusion Into Delegated TX', explorerTxLink(res.signature));
+
+    // the tokens come from the delegated account, the asset goes to the user
+    const newBalance = await getTokenBalance(umi, token.mint.publicKey, umi.identity.publicKey);
+    expect(balance - newBalance).to.equal(TOKEN_DELEGATE_FEE_DATA.escrowAmount);
+
+    const assetData = await fetchAsset(umi, asset.publicKey);
+    expect(assetData.owner).to.equal(user.publicKey);
+
+    // the delegated amount is used up
+    const deployerAtaData = await fetchToken(umi, deployerAta);
+    expect(deployerAtaData.delegatedAmount).to.equal(0n);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Vault', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+  let vaultedAsset: Signer;
+
+  const ASSET_DATA_VAULT: AssetDataV1 = { ...ASSET_DATA_V1, redeemMode: AssetRedeemModeV1.Vault };
+  const VAULT_FEE_DATA = escrowFeeData(100n * 10n ** 9n);
+
+  const MODE_LOCKED_ERROR_MESSAGE =
+    'Error Number: 6058. Error Message: Asset modes can not change while assets are vaulted or compressed.';
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_VAULT, VAULT_FEE_DATA);
+  });
+
+  it('[Success] FusionFromV1 - asset locked in the vault', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    vaultedAsset = generateSigner(umi);
+
+    await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: vaultedAsset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_VAULT.nextIndex),
+    }).sendAndConfirm(umi);
+
+    // vaulted assets keep their index
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: vaultedAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion From Vault TX', explorerTxLink(res.signature));
+
+    const [authorityPda] = findFusionAuthorityPda(umi, dataPda);
+    const assetData = await fetchAsset(umi, vaultedAsset.publicKey);
+    expect(assetData.owner).to.equal(authorityPda);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.vaultSize).to.equal(1);
+  });
+
+  it('[Error] UpdateV1 - redeem mode locked while assets are vaulted', async () => {
+    const { umi, dataPda } = context;
+
+    const res = await updateV1(umi, {
+      fusionData: dataPda,
+      assetData: { ...ASSET_DATA_VAULT, redeemMode: AssetRedeemModeV1.Burn },
+      feeData: VAULT_FEE_DATA,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(MODE_LOCKED_ERROR_MESSAGE))).eq(true);
+  });
+
+  it('[Success] FusionIntoV1 - vaulted asset handed out', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    // the vaulted asset keeps its record, no new record nor index is created
+    const [vaultedAssetRecord] = findAssetRecordPda(umi, dataPda, vaultedAsset.publicKey);
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: vaultedAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      assetRecord: TOKEN_FUSION_PROGRAM_ID,
+      vaultedAssetRecord,
+      tier: null,
+      amount: null,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion Into Vault TX', explorerTxLink(res.signature));
+
+    const assetData = await fetchAsset(umi, vaultedAsset.publicKey);
+    expect(assetData.owner).to.equal(umi.identity.publicKey);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.vaultSize).to.equal(0);
+    expect(dataAccount.assetData.nextIndex).to.equal(ASSET_DATA_VAULT.nextIndex + 1n);
+  });
+
+  it('[Success] UpdateV1 - redeem mode once the vault is empty', async () => {
+    const { umi, dataPda } = context;
+
+    await updateV1(umi, {
+      fusionData: dataPda,
+      assetData: { ...ASSET_DATA_VAULT, redeemMode: AssetRedeemModeV1.Burn },
+      feeData: VAULT_FEE_DATA,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.assetData.redeemMode).to.equal(AssetRedeemModeV1.Burn);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Reveal', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+  let hiddenAsset: Signer;
+
+  // revealed uris are the uri prefix followed by the metadata index, without hash
+  const ASSET_DATA_HIDDEN: AssetDataV1 = {
+    ...ASSET_DATA_V1,
+    uriSuffix: '.json',
+    hashMode: assetHashModeV1('None'),
+    placeholderUri: some('https://stf.org/placeholder.json'),
+  };
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_HIDDEN, escrowFeeData(100n * 10n ** 9n));
+  });
+
+  it('[Success] FusionIntoV1 - placeholder uri', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    hiddenAsset = generateSigner(umi);
+
+    await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: hiddenAsset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_HIDDEN.nextIndex),
+    }).sendAndConfirm(umi);
+
+    const assetData = await fetchAsset(umi, hiddenAsset.publicKey);
+    expect(assetData.name).to.equal('STF #1');
+    expect(assetData.uri).to.equal('https://stf.org/placeholder.json');
+  });
+
+  it('[Success] RevealV1', async () => {
+    const { umi, dataPda, collection } = context;
+
+    const res = await revealV1(umi, {
+      fusionData: dataPda,
+      collection: collection.collection.publicKey,
+      uriPrefix: 'https://stf.org/revealed/',
+      salt: null,
+    })
+      .addRemainingAccounts(assetRecordAccounts(umi, dataPda, [hiddenAsset.publicKey]))
+      .sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Reveal TX', explorerTxLink(res.signature));
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.assetData.placeholderUri).to.deep.equal(none());
+    expect(dataAccount.assetData.uriPrefix).to.equal('https://stf.org/revealed/');
+
+    const assetData = await fetchAsset(umi, hiddenAsset.publicKey);
+    expect(assetData.uri).to.equal('https://stf.org/revealed/1.json');
+  });
+
+  it('[Success] FusionIntoV1 - revealed uri', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const asset = generateSigner(umi);
+
+    await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_HIDDEN.nextIndex + 1n),
+    }).sendAndConfirm(umi);
+
+    const assetData = await fetchAsset(umi, asset.publicKey);
+    expect(assetData.uri).to.equal('https://stf.org/revealed/2.json');
+  });
+});
+
+describe('Solana Token Fusion Protocol - Salted Hash', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+  let hiddenAsset: Signer;
+
+  const SALT = crypto.randomBytes(32);
+  const SALT_ERROR_MESSAGE = 'Error Number: 6038. Error Message: Hash salt does not match the commitment';
+
+  // only the commitment of the salt is published until the reveal
+  const ASSET_DATA_SALTED: AssetDataV1 = {
+    ...ASSET_DATA_V1,
+    hashMode: assetHashModeV1('Salted', {
+      commitment: Array.from(crypto.createHash('sha256').update(SALT).digest()),
+    }),
+    placeholderUri: some('https://stf.org/placeholder.json'),
+  };
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_SALTED, escrowFeeData(100n * 10n ** 9n));
+
+    const { umi, dataPda, token, collection } = context;
+
+    hiddenAsset = generateSigner(umi);
+
+    await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: hiddenAsset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_SALTED.nextIndex),
+    }).sendAndConfirm(umi);
+  });
+
+  it('[Error] RevealV1 - salt does not match the commitment', async () => {
+    const { umi, dataPda, collection } = context;
+
+    const res = await revealV1(umi, {
+      fusionData: dataPda,
+      collection: collection.collection.publicKey,
+      uriPrefix: null,
+      salt: Array.from(crypto.randomBytes(32)),
+    })
+      .addRemainingAccounts(assetRecordAccounts(umi, dataPda, [hiddenAsset.publicKey]))
+      .sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(SALT_ERROR_MESSAGE))).eq(true);
+
+    const assetData = await fetchAsset(umi, hiddenAsset.publicKey);
+    expect(assetData.uri).to.equal('https://stf.org/placeholder.json');
+  });
+
+  it('[Success] RevealV1 - salted hash', async () => {
+    const { umi, dataPda, collection } = context;
+
+    const res = await revealV1(umi, {
+      fusionData: dataPda,
+      collection: collection.collection.publicKey,
+      uriPrefix: null,
+      salt: Array.from(SALT),
+    })
+      .addRemainingAccounts(assetRecordAccounts(umi, dataPda, [hiddenAsset.publicKey]))
+      .sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Reveal TX', explorerTxLink(res.signature));
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.assetData.hashSalt).to.deep.equal(some(Array.from(SALT)));
+    expect(dataAccount.assetData.placeholderUri).to.deep.equal(none());
+
+    const hash = getSaltedAssetHash(1n, collection.collection.publicKey, SALT);
+    const assetData = await fetchAsset(umi, hiddenAsset.publicKey);
+    expect(assetData.uri).to.equal(`https://stf.org/metadata/1/${hash}`);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Update Assets', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+  let asset: Signer;
+
+  const ASSET_DATA_PLAIN: AssetDataV1 = {
+    ...ASSET_DATA_V1,
+    uriSuffix: '.json',
+    hashMode: assetHashModeV1('None'),
+  };
+  const FEE_DATA_PLAIN = escrowFeeData(100n * 10n ** 9n);
+  const REMAINING_ERROR_MESSAGE =
+    'Error Number: 6036. Error Message: Remaining accounts do not match the instruction arguments';
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_PLAIN, FEE_DATA_PLAIN);
+
+    const { umi, dataPda, token, collection } = context;
+
+    asset = generateSigner(umi);
+
+    await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_PLAIN.nextIndex),
+    }).sendAndConfirm(umi);
+  });
+
+  it('[Success] UpdateAssetsV1 - current asset data scheme', async () => {
+    const { umi, dataPda, collection } = context;
+
+    await updateV1(umi, {
+      fusionData: dataPda,
+      assetData: { ...ASSET_DATA_PLAIN, namePrefix: 'Fused #', uriPrefix: 'https://stf.org/v2/' },
+      feeData: FEE_DATA_PLAIN,
+    }).sendAndConfirm(umi);
+
+    // assets keep their metadata until they are updated
+    expect((await fetchAsset(umi, asset.publicKey)).uri).to.equal('https://stf.org/metadata/1.json');
+
+    const res = await updateAssetsV1(umi, {
+      fusionData: dataPda,
+      collection: collection.collection.publicKey,
+      assets: [{ name: null, uri: null }],
+    })
+      .addRemainingAccounts(assetRecordAccounts(umi, dataPda, [asset.publicKey]))
+      .sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Update Assets TX', explorerTxLink(res.signature));
+
+    const assetData = await fetchAsset(umi, asset.publicKey);
+    expect(assetData.name).to.equal('Fused #1');
+    expect(assetData.uri).to.equal('https://stf.org/v2/1.json');
+  });
+
+  it('[Success] UpdateAssetsV1 - explicit name and uri', async () => {
+    const { umi, dataPda, collection } = context;
+
+    await updateAssetsV1(umi, {
+      fusionData: dataPda,
+      collection: collection.collection.publicKey,
+      assets: [{ name: 'Golden STF', uri: some('https://stf.org/golden.json') }],
+    })
+      .addRemainingAccounts(assetRecordAccounts(umi, dataPda, [asset.publicKey]))
+      .sendAndConfirm(umi);
+
+    const assetData = await fetchAsset(umi, asset.publicKey);
+    expect(assetData.name).to.equal('Golden STF');
+    expect(assetData.uri).to.equal('https://stf.org/golden.json');
+  });
+
+  it('[Error] UpdateAssetsV1 - missing asset record', async () => {
+    const { umi, dataPda, collection } = context;
+
+    const res = await updateAssetsV1(umi, {
+      fusionData: dataPda,
+      collection: collection.collection.publicKey,
+      assets: [{ name: null, uri: null }],
+    })
+      .addRemainingAccounts({ pubkey: asset.publicKey, isSigner: false, isWritable: true })
+      .sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(REMAINING_ERROR_MESSAGE))).eq(true);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Metadata Pool', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+
+  const ASSET_DATA_RANDOM: AssetDataV1 = { ...ASSET_DATA_V1, metadataMode: MetadataModeV1.Random };
+  const POOL_FEE_DATA = escrowFeeData(100n * 10n ** 9n);
+
+  const MODE_LOCKED_ERROR_MESSAGE =
+    'Error Number: 6057. Error Message: Metadata mode can not change once assets were minted with the metadata pool.';
+
+  // update the metadata mode of the fusion, keeping the rest of the asset data
+  const updateMetadataMode = (metadataMode: MetadataModeV1) => {
+    const { umi, dataPda } = context;
+
+    return updateV1(umi, {
+      fusionData: dataPda,
+      assetData: { ...ASSET_DATA_RANDOM, metadataMode },
+      feeData: POOL_FEE_DATA,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+  };
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_RANDOM, POOL_FEE_DATA);
+  });
+
+  it('[Success] InitMetadataPoolV1', async () => {
+    const { umi, dataPda } = context;
+
+    const res = await initMetadataPoolV1(umi, {
+      fusionData: dataPda,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Init Metadata Pool TX', explorerTxLink(res.signature));
+
+    const [metadataPool] = findMetadataPoolPda(umi, dataPda);
+    const pool = await fetchMetadataPoolV1(umi, metadataPool);
+    expect(pool.startIndex).to.equal(ASSET_DATA_RANDOM.nextIndex);
+    expect(pool.size).to.equal(3);
+    expect(pool.used).to.equal(0);
+  });
+
+  it('[Success] UpdateV1 - metadata mode before any mint', async () => {
+    const { umi, dataPda } = context;
+
+    await updateMetadataMode(MetadataModeV1.Recycled);
+    expect((await fetchFusionDataV1(umi, dataPda)).assetData.metadataMode).to.equal(MetadataModeV1.Recycled);
+
+    await updateMetadataMode(MetadataModeV1.Random);
+    expect((await fetchFusionDataV1(umi, dataPda)).assetData.metadataMode).to.equal(MetadataModeV1.Random);
+  });
+
+  it('[Success] FusionIntoV1 - random metadata index', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const asset = generateSigner(umi);
+    const [metadataPool] = findMetadataPoolPda(umi, dataPda);
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      metadataPool,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_RANDOM.nextIndex),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion Into Random TX', explorerTxLink(res.signature));
+
+    const pool = await fetchMetadataPoolV1(umi, metadataPool);
+    expect(pool.used).to.equal(1);
+  });
+
+  it('[Success] FusionIntoV1 - random metadata indexes drawn once', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const [metadataPool] = findMetadataPoolPda(umi, dataPda);
+
+    // mint the rest of the pool, the next index keys the index account
+    for (const mintIndex of [ASSET_DATA_RANDOM.nextIndex + 1n, ASSET_DATA_RANDOM.nextIndex + 2n]) {
+      await fusionIntoV1(umi, {
+        fusionData: dataPda,
+        user: umi.identity,
+        asset: generateSigner(umi),
+        collection: collection.collection.publicKey,
+        tokenMint: token.mint.publicKey,
+        metadataPool,
+        tier: null,
+        amount: null,
+        assetIndex: findAssetIndexPda(umi, dataPda, mintIndex),
+      }).sendAndConfirm(umi);
+    }
+
+    const pool = await fetchMetadataPoolV1(umi, metadataPool);
+    expect(pool.used).to.equal(3);
+
+    // every metadata index of the pool is assigned to exactly one asset, named after it
+    const indexes: bigint[] = [];
+    for (const offset of [0n, 1n, 2n]) {
+      const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_RANDOM.nextIndex + offset);
+      const indexAccount = await fetchAssetIndexV1(umi, assetIndex);
+
+      const assetData = await fetchAsset(umi, indexAccount.asset);
+      expect(assetData.name).to.equal(`${ASSET_DATA_RANDOM.namePrefix}${indexAccount.index}`);
+
+      indexes.push(indexAccount.index);
+    }
+
+    expect(indexes.sort()).to.deep.equal([1n, 2n, 3n]);
+  });
+
+  it('[Error] FusionIntoV1 - metadata pool exhausted', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const [metadataPool] = findMetadataPoolPda(umi, dataPda);
+
+    const res = await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset: generateSigner(umi),
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      metadataPool,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_RANDOM.nextIndex + 3n),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(
+      receipt?.meta.logs.some((l) => l.includes('Error Number: 6027. Error Message: Max supply reached.'))
+    ).eq(true);
+  });
+
+  it('[Error] UpdateV1 - metadata mode locked once assets were minted', async () => {
+    const { umi } = context;
+
+    for (const metadataMode of [MetadataModeV1.Sequential, MetadataModeV1.Recycled]) {
+      const res = await updateMetadataMode(metadataMode);
+
+      const receipt = await umi.rpc.getTransaction(res.signature);
+      expect(receipt?.meta.logs.some((l) => l.includes(MODE_LOCKED_ERROR_MESSAGE))).eq(true);
+    }
+  });
+});
+
+describe('Solana Token Fusion Protocol - Recycled Metadata', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+  let firstAsset: Signer;
+
+  const ASSET_DATA_RECYCLED: AssetDataV1 = {
+    ...ASSET_DATA_V1,
+    maxSupply: some(2),
+    metadataMode: MetadataModeV1.Recycled,
+  };
+
+  // mint an asset with the given mint index, the metadata index is taken from the pool
+  const mintRecycled = async (mintIndex: bigint) => {
+    const { umi, dataPda, token, collection } = context;
+
+    const asset = generateSigner(umi);
+    const [metadataPool] = findMetadataPoolPda(umi, dataPda);
+
+    await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      metadataPool,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, mintIndex),
+    }).sendAndConfirm(umi);
+
+    return asset;
+  };
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_RECYCLED, escrowFeeData(100n * 10n ** 9n));
+
+    await initMetadataPoolV1(context.umi, { fusionData: context.dataPda }).sendAndConfirm(context.umi);
+  });
+
+  it('[Success] FusionIntoV1 - lowest metadata indexes taken', async () => {
+    const { umi } = context;
+
+    firstAsset = await mintRecycled(1n);
+    const secondAsset = await mintRecycled(2n);
+
+    expect((await fetchAsset(umi, firstAsset.publicKey)).name).to.equal('STF #1');
+    expect((await fetchAsset(umi, secondAsset.publicKey)).name).to.equal('STF #2');
+  });
+
+  it('[Success] FusionFromV1 - metadata index released', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const [metadataPool] = findMetadataPoolPda(umi, dataPda);
+
+    const res = await fusionFromV1(umi, {
+      fusionData: dataPda,
+      assetIndex: findAssetIndexPda(umi, dataPda, 1n),
+      metadataPool,
+      user: umi.identity,
+      asset: firstAsset.publicKey,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion From Recycled TX', explorerTxLink(res.signature));
+
+    const pool = await fetchMetadataPoolV1(umi, metadataPool);
+    expect(pool.used).to.equal(1);
+  });
+
+  it('[Success] FusionIntoV1 - released metadata index reused', async () => {
+    const { umi, dataPda } = context;
+
+    // the mint index keeps growing while the burned metadata index is handed out again
+    const asset = await mintRecycled(3n);
+
+    const assetData = await fetchAsset(umi, asset.publicKey);
+    expect(assetData.name).to.equal('STF #1');
+
+    const indexAccount = await fetchAssetIndexV1(umi, findAssetIndexPda(umi, dataPda, 3n));
+    expect(indexAccount.asset).to.equal(asset.publicKey);
+    expect(indexAccount.index).to.equal(1n);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Compressed Assets', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+  let merkleTree: Signer;
+
+  const ASSET_DATA_COMPRESSED: AssetDataV1 = {
+    ...ASSET_DATA_V1,
+    maxSupply: none(),
+    outputMode: AssetOutputModeV1.Compressed,
+  };
+  const COMPRESSED_FEE_DATA = escrowFeeData(100n * 10n ** 9n);
+
+  const MODE_LOCKED_ERROR_MESSAGE =
+    'Error Number: 6058. Error Message: Asset modes can not change while assets are vaulted or compressed.';
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_COMPRESSED, COMPRESSED_FEE_DATA);
+  });
+
+  it('[Success] InitTreeV1', async () => {
+    const { umi, dataPda } = context;
+
+    merkleTree = await createMerkleTree(umi, 3, 8);
+
+    const res = await initTreeV1(umi, {
+      fusionData: dataPda,
+      merkleTree: merkleTree.publicKey,
+      treeConfig: findTreeConfigPda(umi, merkleTree.publicKey),
+      maxDepth: 3,
+      maxBufferSize: 8,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Init Tree TX', explorerTxLink(res.signature));
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.merkleTree).to.deep.equal(some(merkleTree.publicKey));
+  });
+
+  it('[Success] FusionIntoCompressedV1', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const res = await fusionIntoCompressedV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      merkleTree: merkleTree.publicKey,
+      treeConfig: findTreeConfigPda(umi, merkleTree.publicKey),
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Fusion Into Compressed TX', explorerTxLink(res.signature));
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.compressedSupply).to.equal(1);
+    expect(dataAccount.assetData.nextIndex).to.equal(ASSET_DATA_COMPRESSED.nextIndex + 1n);
+
+    // the compressed leaf is backed by the escrow amount
+    const [escrowAta] = findEscrowAtaPda(umi, dataPda, token.mint.publicKey);
+    const escrowData = await fetchToken(umi, escrowAta);
+    expect(escrowData.amount).to.equal(COMPRESSED_FEE_DATA.escrowAmount);
+  });
+
+  it('[Error] UpdateV1 - output mode locked while compressed assets are outstanding', async () => {
+    const { umi, dataPda } = context;
+
+    const res = await updateV1(umi, {
+      fusionData: dataPda,
+      assetData: { ...ASSET_DATA_COMPRESSED, outputMode: AssetOutputModeV1.Core },
+      feeData: COMPRESSED_FEE_DATA,
+    }).sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(MODE_LOCKED_ERROR_MESSAGE))).eq(true);
+  });
+});
+
+describe('Solana Token Fusion Protocol - Legacy Assets', () => {
+  let context: Awaited<ReturnType<typeof setupFusionContext>>;
+
+  const RECORD_ERROR_MESSAGE = 'Error Number: 6047. Error Message: Asset record does not match the asset.';
+
+  before(async () => {
+    context = await setupFusionContext(ASSET_DATA_V1, escrowFeeData(100n * 10n ** 9n));
+  });
+
+  it('[Error] RecordAssetsV1 - asset minted after the migration', async () => {
+    const { umi, dataPda, token, collection } = context;
+
+    const asset = generateSigner(umi);
+
+    await fusionIntoV1(umi, {
+      fusionData: dataPda,
+      user: umi.identity,
+      asset,
+      collection: collection.collection.publicKey,
+      tokenMint: token.mint.publicKey,
+      tier: null,
+      amount: null,
+      assetIndex: findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex),
+    }).sendAndConfirm(umi);
+
+    // the asset already has its record and its index, the fusion has no legacy assets
+    const [assetRecord] = findAssetRecordPda(umi, dataPda, asset.publicKey);
+    const [assetIndex] = findAssetIndexPda(umi, dataPda, ASSET_DATA_V1.nextIndex);
+
+    const res = await recordAssetsV1(umi, {
+      fusionData: dataPda,
+    })
+      .addRemainingAccounts(
+        [asset.publicKey, assetRecord, assetIndex].map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      )
+      .sendAndConfirm(umi, { send: { skipPreflight: true } });
+
+    DEBUG && AppLogger.info('Record Assets TX', explorerTxLink(res.signature));
+
+    const receipt = await umi.rpc.getTransaction(res.signature);
+    expect(receipt?.meta.logs.some((l) => l.includes(RECORD_ERROR_MESSAGE))).eq(true);
+
+    const dataAccount = await fetchFusionDataV1(umi, dataPda);
+    expect(dataAccount.legacySupply).to.equal(0);
+    expect(dataAccount.legacyNextIndex).to.equal(0n);
+  });
+});